@@ -38,7 +38,9 @@ if [[ -z $es_node_name ]]; then
   export moniker=$(echo "$elasticsearch_container" | tr -C "[:alnum:]" '-')
   export network_name=${moniker}${suffix}
 
-  export ssl_cert="${script_path}/certs/testnode.crt"
+  # testnode.crt followed by ca.crt, so that the CA is sent in the chain as it is when
+  # Elasticsearch configures security automatically
+  export ssl_cert="${script_path}/certs/testnode-chain.crt"
   export ssl_key="${script_path}/certs/testnode.key"
   export ssl_ca="${script_path}/certs/ca.crt"
 
@@ -41,7 +41,7 @@ pub fn use_declarations() -> Tokens {
                 Method,
                 request::{Body, NdBody, JsonBody, PARTS_ENCODED},
                 response::Response,
-                transport::Transport,
+                transport::{NodeSelector, RequestOptions, Transport},
             },
         };
         use std::{
@@ -53,6 +53,26 @@ pub fn use_declarations() -> Tokens {
     )
 }
 
+/// use declarations common across blocking builders
+pub fn blocking_use_declarations() -> Tokens {
+    quote!(
+        #![allow(unused_imports)]
+
+        use crate::{
+            blocking::{Elasticsearch, Response},
+            params::*,
+            error::Error,
+            http::{
+                headers::{HeaderName, HeaderValue},
+                request::{Body, NdBody, JsonBody},
+                transport::{NodeSelector, Transport},
+            },
+        };
+        use std::time::Duration;
+        use serde::Serialize;
+    )
+}
+
 /// AST for a string literal
 fn lit<I: Into<String>>(lit: I) -> syn::Lit {
     syn::Lit::Str(lit.into(), syn::StrStyle::Cooked)
@@ -41,16 +41,8 @@ pub fn generate(api: &Api, docs_dir: &PathBuf) -> anyhow::Result<Vec<(String, St
         tokens.append(use_declarations());
 
         let namespace_pascal_case = namespace_name.to_pascal_case();
-        let namespace_replaced_pascal_case = namespace_name.replace("_", " ").to_pascal_case();
         let namespace_client_name = ident(&namespace_pascal_case);
-        let name_for_docs = match namespace_replaced_pascal_case.as_ref() {
-            "Ccr" => "Cross Cluster Replication",
-            "Ilm" => "Index Lifecycle Management",
-            "Slm" => "Snapshot Lifecycle Management",
-            "Ml" => "Machine Learning",
-            "Xpack" => "X-Pack",
-            name => name,
-        };
+        let name_for_docs = name_for_docs(namespace_name);
 
         let namespace_doc = doc(format!("Namespace client for {} APIs", &name_for_docs));
         let namespace_fn_doc = doc(format!(
@@ -124,3 +116,109 @@ pub fn generate(api: &Api, docs_dir: &PathBuf) -> anyhow::Result<Vec<(String, St
 
     Ok(output)
 }
+
+/// Generates the source code for a blocking namespaced client
+pub fn generate_blocking(api: &Api, docs_dir: &PathBuf) -> anyhow::Result<Vec<(String, String)>> {
+    let mut output = Vec::new();
+
+    for (namespace_name, namespace) in &api.namespaces {
+        let mut tokens = Tokens::new();
+        if let Some(attr) = namespace.stability.inner_cfg_attr() {
+            tokens.append(attr);
+        }
+        if let Some(mut attr) = stability_doc(namespace.stability) {
+            attr.style = syn::AttrStyle::Inner;
+            tokens.append(quote! { #attr });
+        }
+
+        tokens.append(blocking_use_declarations());
+
+        let namespace_pascal_case = namespace_name.to_pascal_case();
+        let namespace_client_name = ident(&namespace_pascal_case);
+        let name_for_docs = name_for_docs(namespace_name);
+
+        let namespace_doc = doc(format!("Namespace client for {} APIs", &name_for_docs));
+        let namespace_fn_doc = doc(format!(
+            "Creates a namespace client for {} APIs",
+            &name_for_docs
+        ));
+        let new_namespace_client_doc = doc(format!(
+            "Creates a new instance of [{}]",
+            &namespace_pascal_case
+        ));
+        let module = namespace_name.to_string();
+        let namespace_name = ident(namespace_name.to_string());
+
+        let (builders, methods): (Vec<Tokens>, Vec<Tokens>) = namespace
+            .endpoints()
+            .iter()
+            .map(|(name, endpoint)| {
+                let builder_name = format!("{}{}", &namespace_pascal_case, name.to_pascal_case());
+                RequestBuilder::new(
+                    docs_dir,
+                    &namespace_pascal_case,
+                    name,
+                    &builder_name,
+                    &api.common_params,
+                    &endpoint,
+                    false,
+                )
+                .build_blocking(&module)
+            })
+            .unzip();
+
+        let cfg_attr = namespace.stability.outer_cfg_attr();
+        let cfg_doc = stability_doc(namespace.stability);
+        tokens.append(quote!(
+            #(#builders)*
+
+            #namespace_doc
+            #cfg_doc
+            #cfg_attr
+            pub struct #namespace_client_name<'a> {
+                client: &'a Elasticsearch
+            }
+
+            #cfg_attr
+            impl<'a> #namespace_client_name<'a> {
+                #new_namespace_client_doc
+                pub fn new(client: &'a Elasticsearch) -> Self {
+                    Self {
+                        client
+                    }
+                }
+
+                pub fn transport(&self) -> &Transport {
+                    self.client.transport()
+                }
+
+                #(#methods)*
+            }
+
+            #cfg_attr
+            impl Elasticsearch {
+                #namespace_fn_doc
+                pub fn #namespace_name(&self) -> #namespace_client_name {
+                    #namespace_client_name::new(self)
+                }
+            }
+        ));
+
+        let generated = tokens.to_string();
+        output.push((namespace_name.to_string(), generated));
+    }
+
+    Ok(output)
+}
+
+/// The name of a namespace in docs
+fn name_for_docs(namespace_name: &str) -> String {
+    match namespace_name.replace("_", " ").to_pascal_case().as_ref() {
+        "Ccr" => "Cross Cluster Replication".to_string(),
+        "Ilm" => "Index Lifecycle Management".to_string(),
+        "Slm" => "Snapshot Lifecycle Management".to_string(),
+        "Ml" => "Machine Learning".to_string(),
+        "Xpack" => "X-Pack".to_string(),
+        name => name.to_string(),
+    }
+}
@@ -399,6 +399,62 @@ impl<'a> RequestBuilder<'a> {
         }
     }
 
+    /// Creates the AST for a builder fn to set a node selector
+    fn create_node_selector_fn(field: &syn::Ident) -> syn::ImplItem {
+        let doc_attr = doc("Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster");
+        let ty_param = syn::TyParam {
+            ident: ident("S"),
+            default: None,
+            attrs: vec![],
+            bounds: vec![
+                TyParamBound::Trait(
+                    syn::PolyTraitRef {
+                        trait_ref: path_none("NodeSelector"),
+                        bound_lifetimes: vec![],
+                    },
+                    TraitBoundModifier::None,
+                ),
+                TyParamBound::Region(syn::Lifetime {
+                    ident: syn::Ident::new("'static"),
+                }),
+            ],
+        };
+
+        syn::ImplItem {
+            ident: ident("node_selector"),
+            vis: syn::Visibility::Public,
+            defaultness: syn::Defaultness::Final,
+            attrs: vec![doc_attr],
+            node: syn::ImplItemKind::Method(
+                syn::MethodSig {
+                    unsafety: syn::Unsafety::Normal,
+                    constness: syn::Constness::NotConst,
+                    abi: None,
+                    decl: syn::FnDecl {
+                        inputs: vec![
+                            syn::FnArg::SelfValue(syn::Mutability::Mutable),
+                            syn::FnArg::Captured(
+                                syn::Pat::Path(None, path_none("selector")),
+                                syn::parse_type("S").unwrap(),
+                            ),
+                        ],
+                        output: syn::FunctionRetTy::Ty(code_gen::ty("Self")),
+                        variadic: false,
+                    },
+                    generics: generics(vec![], vec![ty_param]),
+                },
+                syn::Block {
+                    stmts: vec![
+                        syn::Stmt::Semi(Box::new(parse_expr(
+                            quote!(self.#field = Some(Box::new(selector))),
+                        ))),
+                        syn::Stmt::Expr(Box::new(parse_expr(quote!(self)))),
+                    ],
+                },
+            ),
+        }
+    }
+
     /// Creates the AST for a builder fn for a builder impl
     fn create_impl_fn(f: (&String, &Type)) -> syn::ImplItem {
         let name = valid_name(&f.0).to_lowercase();
@@ -477,6 +533,30 @@ impl<'a> RequestBuilder<'a> {
         }
     }
 
+    /// Creates the doc literal for a builder struct
+    fn create_builder_doc(builder_name: &str, endpoint: &ApiEndpoint) -> syn::Lit {
+        let api_name_for_docs = split_on_pascal_case(builder_name);
+
+        match (
+            endpoint.documentation.description.as_ref(),
+            endpoint.documentation.url.as_ref(),
+        ) {
+            (Some(d), Some(u)) if Url::parse(u).is_ok() => lit(format!(
+                "Builder for the [{} API]({})\n\n{}",
+                api_name_for_docs, u, d
+            )),
+            (Some(d), None) => lit(format!(
+                "Builder for the {} API\n\n{}",
+                api_name_for_docs, d
+            )),
+            (None, Some(u)) if Url::parse(u).is_ok() => lit(format!(
+                "Builder for the [{} API]({})",
+                api_name_for_docs, u
+            )),
+            _ => lit(format!("Builder for the {} API", api_name_for_docs)),
+        }
+    }
+
     /// creates the AST for a builder struct
     fn create_builder_struct(
         builder_name: &str,
@@ -506,6 +586,7 @@ impl<'a> RequestBuilder<'a> {
 
         let headers_field_ident = ident("headers");
         let request_timeout_ident = ident("request_timeout");
+        let node_selector_ident = ident("node_selector");
 
         // add a field for HTTP headers
         fields.push(syn::Field {
@@ -520,6 +601,12 @@ impl<'a> RequestBuilder<'a> {
             attrs: vec![],
             ty: syn::parse_type("Option<Duration>").unwrap(),
         });
+        fields.push(syn::Field {
+            ident: Some(node_selector_ident.clone()),
+            vis: syn::Visibility::Inherited,
+            attrs: vec![],
+            ty: code_gen::ty("Option<Box<dyn NodeSelector>>"),
+        });
 
         if supports_body {
             fields.push(syn::Field {
@@ -548,6 +635,7 @@ impl<'a> RequestBuilder<'a> {
 
         builder_fns.push(Self::create_header_fn(&headers_field_ident));
         builder_fns.push(Self::create_request_timeout_fn(&request_timeout_ident));
+        builder_fns.push(Self::create_node_selector_fn(&node_selector_ident));
 
         // add a body impl if supported
         if supports_body {
@@ -601,31 +689,15 @@ impl<'a> RequestBuilder<'a> {
         };
 
         let api_name_for_docs = split_on_pascal_case(builder_name);
-
-        let builder_doc = match (
-            endpoint.documentation.description.as_ref(),
-            endpoint.documentation.url.as_ref(),
-        ) {
-            (Some(d), Some(u)) if Url::parse(u).is_ok() => lit(format!(
-                "Builder for the [{} API]({})\n\n{}",
-                api_name_for_docs, u, d
-            )),
-            (Some(d), None) => lit(format!(
-                "Builder for the {} API\n\n{}",
-                api_name_for_docs, d
-            )),
-            (None, Some(u)) if Url::parse(u).is_ok() => lit(format!(
-                "Builder for the [{} API]({})",
-                api_name_for_docs, u
-            )),
-            _ => lit(format!("Builder for the {} API", api_name_for_docs)),
-        };
+        let builder_doc = Self::create_builder_doc(builder_name, endpoint);
 
         let send_doc = lit(format!(
             "Creates an asynchronous call to the {} API that can be awaited",
             api_name_for_docs
         ));
 
+        let endpoint_id = lit(endpoint.full_name.as_ref().unwrap().as_str());
+
         let cfg_attr = endpoint.stability.outer_cfg_attr();
         let cfg_doc = stability_doc(endpoint.stability);
 
@@ -659,15 +731,174 @@ impl<'a> RequestBuilder<'a> {
                       let timeout = self.request_timeout;
                       let query_string = #query_string_expr;
                       let body = #body_expr;
-                      let response = self.transport.send(method, &path, headers, query_string.as_ref(), body, timeout).await?;
+                      let options = RequestOptions::new().endpoint(#endpoint_id).with_node_selector(self.node_selector);
+                      let response = self.transport.send_with_options(method, &path, headers, query_string.as_ref(), body, timeout, options).await?;
                       Ok(response)
                 }
             }
         }
     }
 
+    /// Creates the AST for a blocking builder fn that forwards its arguments to the
+    /// same builder fn of the wrapped asynchronous builder
+    fn create_blocking_fn(mut item: ImplItem) -> ImplItem {
+        let fn_ident = item.ident.clone();
+        if let syn::ImplItemKind::Method(ref sig, ref mut block) = item.node {
+            let args: Vec<&syn::Ident> = sig
+                .decl
+                .inputs
+                .iter()
+                .filter_map(|arg| match *arg {
+                    syn::FnArg::Captured(syn::Pat::Path(_, ref p), _) => Some(p.get_ident()),
+                    _ => None,
+                })
+                .collect();
+
+            // generates a fn body of the form
+            // --------
+            // self.builder = self.builder.<fn>(<args>);
+            // self
+            // ---------
+            *block = syn::Block {
+                stmts: vec![
+                    syn::Stmt::Semi(Box::new(parse_expr(
+                        quote!(self.builder = self.builder.#fn_ident(#(#args),*)),
+                    ))),
+                    syn::Stmt::Expr(Box::new(parse_expr(quote!(self)))),
+                ],
+            };
+        }
+        item
+    }
+
+    /// creates the AST for a blocking builder struct, that wraps the builder struct
+    /// in the given module and blocks on sending it
+    fn create_blocking_builder_struct(
+        module: &str,
+        builder_name: &str,
+        endpoint: &ApiEndpoint,
+        common_params: &BTreeMap<String, Type>,
+        enum_builder: &EnumBuilder,
+        accepts_nd_body: bool,
+    ) -> Tokens {
+        let supports_body = endpoint.supports_body();
+        let builder_ident = ident(builder_name);
+        let async_builder_ident = ident(format!("crate::{}::{}", module, builder_name));
+        let enum_path = ident(format!("crate::{}::{}Parts", module, builder_name));
+        let (enum_ty, _, _) = enum_builder.clone().build();
+
+        // the same builder fns as the asynchronous builder, forwarding to it
+        let mut builder_fns: Vec<ImplItem> =
+            endpoint.params.iter().map(Self::create_impl_fn).collect();
+        builder_fns.push(Self::create_header_fn(&ident("headers")));
+        builder_fns.push(Self::create_request_timeout_fn(&ident("request_timeout")));
+        builder_fns.push(Self::create_node_selector_fn(&ident("node_selector")));
+        builder_fns.extend(common_params.iter().map(Self::create_impl_fn));
+        let mut builder_fns: Vec<ImplItem> = builder_fns
+            .into_iter()
+            .map(Self::create_blocking_fn)
+            .collect();
+
+        if supports_body {
+            let mut body_fn =
+                Self::create_body_fn(builder_name, &builder_ident, &[], accepts_nd_body);
+            if let syn::ImplItemKind::Method(_, ref mut block) = body_fn.node {
+                *block = syn::Block {
+                    stmts: vec![syn::Stmt::Expr(Box::new(parse_expr(quote!(
+                        #builder_ident {
+                            client: self.client,
+                            builder: self.builder.body(body),
+                        }
+                    ))))],
+                };
+            }
+            builder_fns.push(body_fn);
+        }
+
+        builder_fns.sort_by(|a, b| a.ident.cmp(&b.ident));
+        builder_fns.dedup_by(|a, b| a.ident.eq(&b.ident));
+
+        let new_fn = if enum_builder.contains_single_parameterless_part() {
+            let doc = doc(format!("Creates a new instance of [{}]", &builder_name));
+            quote!(
+                #doc
+                pub fn new(client: &'a Elasticsearch) -> Self {
+                    #builder_ident {
+                        client,
+                        builder: #async_builder_ident::new(client.transport()),
+                    }
+                }
+            )
+        } else {
+            let doc = doc(format!(
+                "Creates a new instance of [{}] with the specified API parts",
+                &builder_name
+            ));
+            quote!(
+                #doc
+                pub fn new(client: &'a Elasticsearch, parts: #enum_ty) -> Self {
+                    #builder_ident {
+                        client,
+                        builder: #async_builder_ident::new(client.transport(), parts),
+                    }
+                }
+            )
+        };
+
+        let (builder_expr, async_builder_expr, builder_impl) = {
+            if supports_body {
+                (
+                    quote!(#builder_ident<'a, 'b, B>),
+                    quote!(#async_builder_ident<'a, 'b, B>),
+                    quote!(impl<'a, 'b, B> #builder_ident<'a, 'b, B> where B: Body),
+                )
+            } else {
+                (
+                    quote!(#builder_ident<'a, 'b>),
+                    quote!(#async_builder_ident<'a, 'b>),
+                    quote!(impl<'a, 'b> #builder_ident<'a, 'b>),
+                )
+            }
+        };
+
+        let builder_doc = Self::create_builder_doc(builder_name, endpoint);
+        let send_doc = lit(format!(
+            "Creates a synchronous call to the {} API that blocks until the response is returned",
+            split_on_pascal_case(builder_name)
+        ));
+
+        let cfg_attr = endpoint.stability.outer_cfg_attr();
+        let cfg_doc = stability_doc(endpoint.stability);
+
+        quote! {
+            #cfg_attr
+            pub use #enum_path;
+
+            #[doc = #builder_doc]
+            #cfg_doc
+            #cfg_attr
+            #[derive(Clone, Debug)]
+            pub struct #builder_expr {
+                client: &'a Elasticsearch,
+                builder: #async_builder_expr,
+            }
+
+            #cfg_attr
+            #builder_impl {
+                #new_fn
+                #(#builder_fns)*
+
+                #[doc = #send_doc]
+                pub fn send(self) -> Result<Response, Error> {
+                    self.client.block_on(self.builder.send())
+                }
+            }
+        }
+    }
+
     /// Creates the AST for a fn that returns a new instance of a builder struct
     /// from the root or namespace client
+    #[allow(clippy::too_many_arguments)]
     fn create_builder_struct_ctor_fns(
         docs_dir: &PathBuf,
         namespace_name: &str,
@@ -676,6 +907,7 @@ impl<'a> RequestBuilder<'a> {
         endpoint: &ApiEndpoint,
         is_root_method: bool,
         enum_builder: &EnumBuilder,
+        blocking: bool,
     ) -> Tokens {
         let cfg_attr = endpoint.stability.outer_cfg_attr();
         let cfg_doc = stability_doc(endpoint.stability);
@@ -696,11 +928,12 @@ impl<'a> RequestBuilder<'a> {
 
         let api_name_for_docs = split_on_pascal_case(builder_name);
 
+        // markdown docs contain asynchronous examples, so are omitted from blocking clients
         let markdown_doc = {
             let mut path = docs_dir.clone();
             path.push("functions");
             path.push(format!("{}.{}.md", namespace_name, name));
-            if path.exists() {
+            if !blocking && path.exists() {
                 let mut s = fs::read_to_string(&path)
                     .unwrap_or_else(|_| panic!("Could not read file at {:?}", &path));
                 s = s.replace("\r\n", "\n");
@@ -732,7 +965,11 @@ impl<'a> RequestBuilder<'a> {
             _ => doc(format!("{} API{}", api_name_for_docs, markdown_doc)),
         };
 
-        let clone_expr = quote!(self.transport());
+        let clone_expr = match (blocking, is_root_method) {
+            (false, _) => quote!(self.transport()),
+            (true, true) => quote!(self),
+            (true, false) => quote!(self.client),
+        };
 
         if enum_builder.contains_single_parameterless_part() {
             quote!(
@@ -800,6 +1037,34 @@ impl<'a> RequestBuilder<'a> {
             self.endpoint,
             self.is_root_method,
             &self.enum_builder,
+            false,
+        );
+
+        (builder_struct, ctor_fn)
+    }
+
+    /// builds the AST that represent the blocking builder structs, wrapping the builder
+    /// structs in the given module, and the ctor function for the blocking builder struct
+    /// on the blocking root/namespace client
+    pub fn build_blocking(self, module: &str) -> (Tokens, Tokens) {
+        let builder_struct = Self::create_blocking_builder_struct(
+            module,
+            self.builder_name,
+            self.endpoint,
+            self.common_params,
+            &self.enum_builder,
+            self.accepts_nd_body,
+        );
+
+        let ctor_fn = Self::create_builder_struct_ctor_fns(
+            self.docs_dir,
+            self.namespace_name,
+            self.name,
+            self.builder_name,
+            self.endpoint,
+            self.is_root_method,
+            &self.enum_builder,
+            true,
         );
 
         (builder_struct, ctor_fn)
@@ -60,3 +60,40 @@ pub fn generate(api: &Api, docs_dir: &PathBuf) -> anyhow::Result<String> {
     let generated = tokens.to_string();
     Ok(generated)
 }
+
+/// Generates the source code for the methods on the root of the blocking client
+pub fn generate_blocking(api: &Api, docs_dir: &PathBuf) -> anyhow::Result<String> {
+    let mut tokens = Tokens::new();
+    tokens.append(blocking_use_declarations());
+
+    // AST for blocking builder structs and methods
+    let (builders, methods): (Vec<Tokens>, Vec<Tokens>) = api
+        .root
+        .endpoints()
+        .iter()
+        .map(|(name, endpoint)| {
+            let builder_name = name.to_pascal_case();
+            RequestBuilder::new(
+                docs_dir,
+                "Elasticsearch",
+                name,
+                &builder_name,
+                &api.common_params,
+                endpoint,
+                true,
+            )
+            .build_blocking("root")
+        })
+        .unzip();
+
+    tokens.append(quote!(
+        #(#builders)*
+
+        impl Elasticsearch {
+            #(#methods)*
+        }
+    ));
+
+    let generated = tokens.to_string();
+    Ok(generated)
+}
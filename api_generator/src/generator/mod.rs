@@ -563,7 +563,29 @@ pub use bulk::*;
     );
     write_file(root, None, generated_dir, "root/mod.rs", &mut tracker)?;
 
-    // declare namespace modules in the top-level lib.rs
+    // generate blocking namespace client modules and functions on root of blocking client
+    let blocking_namespace_clients =
+        code_gen::namespace_clients::generate_blocking(&api, &docs_dir)?;
+    for (name, input) in blocking_namespace_clients {
+        write_file(
+            input,
+            None,
+            &generated_dir,
+            format!("blocking/{}.rs", name).as_str(),
+            &mut tracker,
+        )?;
+    }
+
+    let blocking_root = code_gen::root::generate_blocking(&api, &docs_dir)?;
+    write_file(
+        blocking_root,
+        None,
+        generated_dir,
+        "blocking/root.rs",
+        &mut tracker,
+    )?;
+
+    // declare namespace modules in the top-level lib.rs and the blocking module
     let mods = api
         .namespaces
         .keys()
@@ -571,14 +593,16 @@ pub use bulk::*;
         .collect::<Vec<_>>()
         .join("\n");
 
-    let mut sections = HashMap::new();
-    sections.insert("namespace-modules", mods);
-    merge_file(
-        |section| sections.remove(section),
-        generated_dir,
-        "lib.rs",
-        &mut tracker,
-    )?;
+    for file_name in &["lib.rs", "blocking/mod.rs"] {
+        let mut sections = HashMap::new();
+        sections.insert("namespace-modules", mods.clone());
+        merge_file(
+            |section| sections.remove(section),
+            generated_dir,
+            file_name,
+            &mut tracker,
+        )?;
+    }
 
     let mut generated = generated_dir.clone();
     generated.push(GENERATED_TOML);
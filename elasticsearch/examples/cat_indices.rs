@@ -16,20 +16,22 @@
  * specific language governing permissions and limitations
  * under the License.
  */
-#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
-use elasticsearch::cert::CertificateValidation;
-use elasticsearch::{
-    auth::Credentials,
-    cat::CatIndicesParts,
-    http::transport::{SingleNodeConnectionPool, TransportBuilder},
-    Elasticsearch, Error, DEFAULT_ADDRESS,
-};
-use sysinfo::SystemExt;
-use url::Url;
+//! Lists the indices in a cluster with the cat indices API.
+//!
+//! The client is configured from environment variables with `Elasticsearch::from_env`:
+//!
+//! - `ELASTICSEARCH_URL`: the url of the cluster. Defaults to `http://localhost:9200`
+//! - `ES_USERNAME` and `ES_PASSWORD`: credentials for basic authentication, such as
+//!   `elastic` and `changeme` for the cluster started by `.ci/run-elasticsearch.sh`
+//! - `ES_CA_CERT`: the CA certificate that signed the certificate of the cluster, such as
+//!   `.ci/certs/ca.crt`. Set `ES_VERIFY_CERTS=false` instead to skip certificate
+//!   validation against a local development cluster
+//! - `ES_PROXY`: a proxy to send requests through, such as `http://localhost:8888` for Fiddler
+use elasticsearch::{cat::CatIndicesParts, Elasticsearch};
 
 #[tokio::main]
 pub async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let client = create_client()?;
+    let client = Elasticsearch::from_env()?;
 
     let response = client
         .cat()
@@ -42,69 +44,3 @@ pub async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("{}", text);
     Ok(())
 }
-
-fn create_client() -> Result<Elasticsearch, Error> {
-    fn cluster_addr() -> String {
-        match std::env::var("ELASTICSEARCH_URL") {
-            Ok(server) => server,
-            Err(_) => DEFAULT_ADDRESS.into(),
-        }
-    }
-
-    /// Determines if Fiddler.exe proxy process is running
-    fn running_proxy() -> bool {
-        let system = sysinfo::System::new();
-        !system.get_process_by_name("Fiddler").is_empty()
-    }
-
-    let mut url = Url::parse(cluster_addr().as_ref()).unwrap();
-
-    // if the url is https and specifies a username and password, remove from the url and set credentials
-    let credentials = if url.scheme() == "https" {
-        let username = if !url.username().is_empty() {
-            let u = url.username().to_string();
-            url.set_username("").unwrap();
-            u
-        } else {
-            std::env::var("ES_USERNAME").unwrap_or_else(|_| "elastic".into())
-        };
-
-        let password = match url.password() {
-            Some(p) => {
-                let pass = p.to_string();
-                url.set_password(None).unwrap();
-                pass
-            }
-            None => std::env::var("ES_PASSWORD").unwrap_or_else(|_| "changeme".into()),
-        };
-
-        Some(Credentials::Basic(username, password))
-    } else {
-        None
-    };
-
-    let conn_pool = SingleNodeConnectionPool::new(url);
-    let mut builder = TransportBuilder::new(conn_pool);
-
-    builder = match credentials {
-        Some(c) => {
-            builder = builder.auth(c);
-
-            #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
-            {
-                builder = builder.cert_validation(CertificateValidation::None);
-            }
-
-            builder
-        }
-        None => builder,
-    };
-
-    if running_proxy() {
-        let proxy_url = Url::parse("http://localhost:8888").unwrap();
-        builder = builder.proxy(proxy_url, None, None);
-    }
-
-    let transport = builder.build()?;
-    Ok(Elasticsearch::new(transport))
-}
@@ -16,20 +16,19 @@
  * specific language governing permissions and limitations
  * under the License.
  */
+//! Searches the questions and answers in the `posts` index, matching the query given as
+//! an argument.
+//!
+//! The client is configured from environment variables with `Elasticsearch::from_env`, as
+//! described in the `cat_indices` example. For a local cluster with security enabled, set
+//! `ES_USERNAME` and `ES_PASSWORD`, and `ES_CA_CERT=.ci/certs/ca.crt` or
+//! `ES_VERIFY_CERTS=false`.
 #[macro_use]
 extern crate serde_json;
 
-#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
-use elasticsearch::cert::CertificateValidation;
-use elasticsearch::{
-    auth::Credentials,
-    http::transport::{SingleNodeConnectionPool, TransportBuilder},
-    Elasticsearch, Error, SearchParts, DEFAULT_ADDRESS,
-};
+use elasticsearch::{Elasticsearch, SearchParts};
 use serde_json::Value;
 use std::env;
-use sysinfo::SystemExt;
-use url::Url;
 mod stack_overflow;
 use stack_overflow::*;
 use textwrap::fill;
@@ -58,7 +57,7 @@ pub async fn main() -> Result<(), Box<dyn std::error::Error>> {
         })
     };
 
-    let client = create_client()?;
+    let client = Elasticsearch::from_env()?;
     let mut response = client
         .search(SearchParts::Index(&[POSTS_INDEX]))
         .body(query)
@@ -96,69 +95,3 @@ pub async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
-
-fn create_client() -> Result<Elasticsearch, Error> {
-    fn cluster_addr() -> String {
-        match std::env::var("ELASTICSEARCH_URL") {
-            Ok(server) => server,
-            Err(_) => DEFAULT_ADDRESS.into(),
-        }
-    }
-
-    /// Determines if Fiddler.exe proxy process is running
-    fn running_proxy() -> bool {
-        let system = sysinfo::System::new();
-        !system.get_process_by_name("Fiddler").is_empty()
-    }
-
-    let mut url = Url::parse(cluster_addr().as_ref()).unwrap();
-
-    // if the url is https and specifies a username and password, remove from the url and set credentials
-    let credentials = if url.scheme() == "https" {
-        let username = if !url.username().is_empty() {
-            let u = url.username().to_string();
-            url.set_username("").unwrap();
-            u
-        } else {
-            std::env::var("ES_USERNAME").unwrap_or_else(|_| "elastic".into())
-        };
-
-        let password = match url.password() {
-            Some(p) => {
-                let pass = p.to_string();
-                url.set_password(None).unwrap();
-                pass
-            }
-            None => std::env::var("ES_PASSWORD").unwrap_or_else(|_| "changeme".into()),
-        };
-
-        Some(Credentials::Basic(username, password))
-    } else {
-        None
-    };
-
-    let conn_pool = SingleNodeConnectionPool::new(url);
-    let mut builder = TransportBuilder::new(conn_pool);
-
-    builder = match credentials {
-        Some(c) => {
-            builder = builder.auth(c);
-
-            #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
-            {
-                builder = builder.cert_validation(CertificateValidation::None);
-            }
-
-            builder
-        }
-        None => builder,
-    };
-
-    if running_proxy() {
-        let proxy_url = Url::parse("http://localhost:8888").unwrap();
-        builder = builder.proxy(proxy_url, None, None);
-    }
-
-    let transport = builder.build()?;
-    Ok(Elasticsearch::new(transport))
-}
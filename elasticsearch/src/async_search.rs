@@ -38,7 +38,7 @@ use crate::{
         headers::{HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE},
         request::{Body, JsonBody, NdBody, PARTS_ENCODED},
         response::Response,
-        transport::Transport,
+        transport::{NodeSelector, RequestOptions, Transport},
         Method,
     },
     params::*,
@@ -75,6 +75,7 @@ pub struct AsyncSearchDelete<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -90,6 +91,7 @@ impl<'a, 'b> AsyncSearchDelete<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -115,6 +117,11 @@ impl<'a, 'b> AsyncSearchDelete<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -157,9 +164,20 @@ impl<'a, 'b> AsyncSearchDelete<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("async_search.delete")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -194,6 +212,7 @@ pub struct AsyncSearchGet<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     keep_alive: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -212,6 +231,7 @@ impl<'a, 'b> AsyncSearchGet<'a, 'b> {
             filter_path: None,
             human: None,
             keep_alive: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -244,6 +264,11 @@ impl<'a, 'b> AsyncSearchGet<'a, 'b> {
         self.keep_alive = Some(keep_alive);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -302,9 +327,20 @@ impl<'a, 'b> AsyncSearchGet<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("async_search.get")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -338,6 +374,7 @@ pub struct AsyncSearchStatus<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -353,6 +390,7 @@ impl<'a, 'b> AsyncSearchStatus<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -378,6 +416,11 @@ impl<'a, 'b> AsyncSearchStatus<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -420,9 +463,20 @@ impl<'a, 'b> AsyncSearchStatus<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("async_search.status")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -483,6 +537,7 @@ pub struct AsyncSearchSubmit<'a, 'b, B> {
     keep_on_completion: Option<bool>,
     lenient: Option<bool>,
     max_concurrent_shard_requests: Option<i64>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     preference: Option<&'b str>,
     pretty: Option<bool>,
     q: Option<&'b str>,
@@ -543,6 +598,7 @@ where
             keep_on_completion: None,
             lenient: None,
             max_concurrent_shard_requests: None,
+            node_selector: None,
             preference: None,
             pretty: None,
             q: None,
@@ -642,6 +698,7 @@ where
             keep_on_completion: self.keep_on_completion,
             lenient: self.lenient,
             max_concurrent_shard_requests: self.max_concurrent_shard_requests,
+            node_selector: self.node_selector,
             preference: self.preference,
             pretty: self.pretty,
             q: self.q,
@@ -748,6 +805,11 @@ where
         self.max_concurrent_shard_requests = Some(max_concurrent_shard_requests);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
     pub fn preference(mut self, preference: &'b str) -> Self {
         self.preference = Some(preference);
@@ -986,9 +1048,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("async_search.submit")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -17,6 +17,56 @@
  * under the License.
  */
 //! Authentication components
+//!
+//! Requests are authenticated with [Credentials] set with
+//! [TransportBuilder::auth](crate::http::transport::TransportBuilder::auth), or retrieved for
+//! each request from a [CredentialsProvider] set with
+//! [TransportBuilder::credentials_provider](crate::http::transport::TransportBuilder::credentials_provider).
+//! [OAuth2TokenProvider] authenticates requests with an access token from the get token API,
+//! that it refreshes before it expires.
+//!
+//! ```rust,no_run
+//! # use elasticsearch::{
+//! #     auth::OAuth2TokenProvider,
+//! #     http::transport::{SingleNodeConnectionPool, TransportBuilder},
+//! #     Elasticsearch,
+//! # };
+//! # use url::Url;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let url = Url::parse("https://localhost:9200")?;
+//! let transport = TransportBuilder::new(SingleNodeConnectionPool::new(url))
+//!     .credentials_provider(OAuth2TokenProvider::new("elastic", "changeme"))
+//!     .build()?;
+//! let client = Elasticsearch::new(transport);
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(feature = "aws-auth")]
+use crate::aws::AwsSigV4;
+use crate::{
+    error::Error,
+    http::{
+        headers::{HeaderValue, AUTHORIZATION},
+        transport::Transport,
+    },
+    security::Security,
+};
+use async_trait::async_trait;
+use base64::write::EncoderWriter as Base64Encoder;
+use dyn_clone::clone_trait_object;
+use serde::Deserialize;
+use serde_json::json;
+use std::{
+    fmt,
+    fmt::Debug,
+    io::Write,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Access tokens are refreshed when they expire within this duration
+const REFRESH_BEFORE_EXPIRATION: Duration = Duration::from_secs(60);
 
 /// Credentials for authentication
 #[derive(Debug, Clone)]
@@ -33,8 +83,259 @@ pub enum Credentials {
     Certificate(ClientCertificate),
     /// An id and api_key to use for API key authentication
     ApiKey(String, String),
+    /// AWS credentials to sign requests with AWS Signature Version 4
+    ///
+    /// # Optional
+    ///
+    /// This requires the `aws-auth` feature to be enabled.
+    #[cfg(feature = "aws-auth")]
+    AwsSigV4(AwsSigV4),
+}
+
+impl Credentials {
+    /// The value of the `Authorization` header for the credentials. Returns `None` for
+    /// credentials that are not sent in the `Authorization` header.
+    pub(crate) fn authorization(&self) -> Result<Option<HeaderValue>, Error> {
+        let header_value = match self {
+            Credentials::Basic(u, p) => {
+                let mut header_value = b"Basic ".to_vec();
+                {
+                    let mut encoder = Base64Encoder::new(&mut header_value, base64::STANDARD);
+                    write!(encoder, "{}:{}", u, p).unwrap();
+                }
+                header_value
+            }
+            Credentials::Bearer(t) => format!("Bearer {}", t).into_bytes(),
+            #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+            Credentials::Certificate(_) => return Ok(None),
+            #[cfg(feature = "aws-auth")]
+            Credentials::AwsSigV4(_) => return Ok(None),
+            Credentials::ApiKey(i, k) => {
+                let mut header_value = b"ApiKey ".to_vec();
+                {
+                    let mut encoder = Base64Encoder::new(&mut header_value, base64::STANDARD);
+                    write!(encoder, "{}:", i).unwrap();
+                    write!(encoder, "{}", k).unwrap();
+                }
+                header_value
+            }
+        };
+
+        let mut header_value =
+            HeaderValue::from_bytes(&header_value).map_err(|e| crate::error::lib(e.to_string()))?;
+        header_value.set_sensitive(true);
+        Ok(Some(header_value))
+    }
+}
+
+/// Provides the [Credentials] to authenticate each request sent by the [Transport].
+///
+/// The provider is consulted for each attempt of a request, unless the request sets
+/// its own `Authorization` header.
+#[async_trait]
+pub trait CredentialsProvider: Debug + dyn_clone::DynClone + Sync + Send {
+    /// Gets the [Credentials] for the next request. The [Transport] can be used to send
+    /// requests to obtain them, with an `Authorization` header set on each. The header is
+    /// also used for any request the [Transport] sends to ping, sniff or verify the cluster
+    /// whilst sending them, so that the provider is not called again.
+    async fn credentials(&self, transport: &Transport) -> Result<Option<Credentials>, Error>;
+
+    /// Called when a request authenticated with the [Credentials] is rejected with a
+    /// `401 Unauthorized` response. Returns whether the request should be retried with new
+    /// credentials, which happens at most once per request. Defaults to not retrying.
+    async fn unauthorized(
+        &self,
+        _transport: &Transport,
+        _credentials: &Credentials,
+    ) -> Result<bool, Error> {
+        Ok(false)
+    }
+}
+
+clone_trait_object!(CredentialsProvider);
+
+#[async_trait]
+impl CredentialsProvider for Credentials {
+    async fn credentials(&self, _transport: &Transport) -> Result<Option<Credentials>, Error> {
+        Ok(Some(self.clone()))
+    }
+}
+
+/// An access token and refresh token returned by the get token API
+#[derive(Clone)]
+struct Token {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_at: Instant,
+    /// The access token that this token replaced
+    replaces: Option<String>,
+}
+
+/// The response of the get token API
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+    refresh_token: Option<String>,
+}
+
+/// A [CredentialsProvider] that authenticates requests with an OAuth2 access token from
+/// the [get token API](https://www.elastic.co/guide/en/elasticsearch/reference/current/security-api-get-token.html).
+///
+/// A token is obtained for the user with the `password` grant type. Before it expires, the
+/// token is refreshed with its refresh token, falling back to the `password` grant type when
+/// the refresh fails. A request rejected with a `401 Unauthorized` response is retried once
+/// with a refreshed token. Concurrent requests wait for the token that a single request
+/// obtains, rather than each obtaining their own.
+#[derive(Clone)]
+pub struct OAuth2TokenProvider {
+    username: String,
+    password: String,
+    token: Arc<Mutex<Option<Token>>>,
+    /// Held whilst a new token is obtained
+    refreshing: Arc<futures_util::lock::Mutex<()>>,
+}
+
+impl OAuth2TokenProvider {
+    /// Creates a new instance of [OAuth2TokenProvider] that obtains tokens for the user
+    pub fn new(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            username: username.into(),
+            password: password.into(),
+            token: Arc::new(Mutex::new(None)),
+            refreshing: Arc::new(futures_util::lock::Mutex::new(())),
+        }
+    }
+
+    /// Gets the [Credentials] for the current token, if it does not expire soon
+    fn current_credentials(&self) -> Option<Credentials> {
+        match self.token.lock().unwrap().as_ref() {
+            Some(token) if token.expires_at > Instant::now() + REFRESH_BEFORE_EXPIRATION => {
+                Some(Credentials::Bearer(token.access_token.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Obtains a new token, refreshing the refresh token if there is one
+    async fn new_token(
+        &self,
+        transport: &Transport,
+        refresh_token: Option<String>,
+    ) -> Result<Token, Error> {
+        if let Some(refresh_token) = refresh_token {
+            let body = json!({
+                "grant_type": "refresh_token",
+                "refresh_token": refresh_token
+            });
+            if let Ok(token) = self.get_token(transport, body).await {
+                return Ok(token);
+            }
+        }
+
+        let body = json!({
+            "grant_type": "password",
+            "username": self.username,
+            "password": self.password
+        });
+        self.get_token(transport, body).await
+    }
+
+    /// Calls the get token API as the user
+    async fn get_token(
+        &self,
+        transport: &Transport,
+        body: serde_json::Value,
+    ) -> Result<Token, Error> {
+        let authorization = Credentials::Basic(self.username.clone(), self.password.clone())
+            .authorization()?
+            .ok_or_else(|| crate::error::lib("no authorization header for basic credentials"))?;
+        let response = Security::new(transport)
+            .get_token()
+            .header(AUTHORIZATION, authorization)
+            .body(body)
+            .send()
+            .await?
+            .error_for_status_code()?
+            .json::<TokenResponse>()
+            .await?;
+
+        Ok(Token {
+            access_token: response.access_token,
+            refresh_token: response.refresh_token,
+            expires_at: Instant::now() + Duration::from_secs(response.expires_in),
+            replaces: None,
+        })
+    }
+}
+
+impl Debug for OAuth2TokenProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OAuth2TokenProvider")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .finish()
+    }
 }
 
+#[async_trait]
+impl CredentialsProvider for OAuth2TokenProvider {
+    async fn credentials(&self, transport: &Transport) -> Result<Option<Credentials>, Error> {
+        if let Some(credentials) = self.current_credentials() {
+            return Ok(Some(credentials));
+        }
+
+        // the token may have been replaced whilst waiting for another request to obtain it
+        let _refreshing = self.refreshing.lock().await;
+        if let Some(credentials) = self.current_credentials() {
+            return Ok(Some(credentials));
+        }
+
+        let refresh_token = self
+            .token
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(|token| token.refresh_token.clone());
+        let mut token = self.new_token(transport, refresh_token).await?;
+        let credentials = Credentials::Bearer(token.access_token.clone());
+
+        let mut current = self.token.lock().unwrap();
+        token.replaces = current.take().map(|t| t.access_token);
+        *current = Some(token);
+        Ok(Some(credentials))
+    }
+
+    async fn unauthorized(
+        &self,
+        _transport: &Transport,
+        credentials: &Credentials,
+    ) -> Result<bool, Error> {
+        let mut token = self.token.lock().unwrap();
+        match (token.as_mut(), credentials) {
+            // expire the rejected token, so that it is refreshed for the retry
+            (Some(token), Credentials::Bearer(access_token))
+                if &token.access_token == access_token =>
+            {
+                token.expires_at = Instant::now();
+                Ok(true)
+            }
+            // the token has already been replaced by another request
+            (Some(token), Credentials::Bearer(access_token))
+                if token.replaces.as_ref() == Some(access_token) =>
+            {
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
+/// A client certificate and its private key, used to authenticate with PKI.
+///
+/// All formats are supported with both the `native-tls` and `rustls-tls` features. Private
+/// keys may be PKCS#8, RSA (PKCS#1) or EC (SEC1) encoded, and are converted to the format
+/// that the TLS backend requires.
 #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
 #[derive(Debug, Clone)]
 pub enum ClientCertificate {
@@ -44,21 +345,15 @@ pub enum ClientCertificate {
     /// as well any intermediate certificates that allow clients to build a chain to
     /// a trusted root. The chain certificates
     /// should be in order from the leaf certificate towards the root.
-    ///
-    /// # Optional
-    ///
-    /// This requires the `native-tls` feature to be enabled.
-    #[cfg(feature = "native-tls")]
     Pkcs12(Vec<u8>, Option<String>),
 
     /// Bytes of a PEM encoded private key and
     /// at least one PEM encoded certificate.
-    ///
-    /// # Optional
-    ///
-    /// This requires the `rustls-tls` feature to be enabled.
-    #[cfg(feature = "rustls-tls")]
     Pem(Vec<u8>),
+
+    /// Bytes of at least one PEM encoded certificate, and bytes of the PEM encoded
+    /// private key of the leaf certificate, as read from separate certificate and key files.
+    PemPair(Vec<u8>, Vec<u8>),
 }
 
 #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
@@ -67,3 +362,38 @@ impl From<ClientCertificate> for Credentials {
         Credentials::Certificate(cert)
     }
 }
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::http::{
+        mock::MockHttpClient,
+        transport::{SingleNodeConnectionPool, TransportBuilder},
+    };
+
+    #[tokio::test]
+    async fn oauth2_retries_only_its_own_tokens() -> Result<(), failure::Error> {
+        let transport = TransportBuilder::new(SingleNodeConnectionPool::default())
+            .http_client(MockHttpClient::new())
+            .build()?;
+        let provider = OAuth2TokenProvider::new("username", "password");
+        let bearer = |access_token: &str| Credentials::Bearer(access_token.into());
+        assert!(!provider.unauthorized(&transport, &bearer("first")).await?);
+
+        *provider.token.lock().unwrap() = Some(Token {
+            access_token: "second".into(),
+            refresh_token: None,
+            expires_at: Instant::now() + Duration::from_secs(1200),
+            replaces: Some("first".into()),
+        });
+        let basic = Credentials::Basic("username".into(), "password".into());
+        assert!(!provider.unauthorized(&transport, &basic).await?);
+        assert!(!provider.unauthorized(&transport, &bearer("other")).await?);
+
+        assert!(provider.unauthorized(&transport, &bearer("first")).await?);
+        assert!(provider.current_credentials().is_some());
+        assert!(provider.unauthorized(&transport, &bearer("second")).await?);
+        assert!(provider.current_credentials().is_none());
+        Ok(())
+    }
+}
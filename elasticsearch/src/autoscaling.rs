@@ -38,7 +38,7 @@ use crate::{
         headers::{HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE},
         request::{Body, JsonBody, NdBody, PARTS_ENCODED},
         response::Response,
-        transport::Transport,
+        transport::{NodeSelector, RequestOptions, Transport},
         Method,
     },
     params::*,
@@ -75,6 +75,7 @@ pub struct AutoscalingDeleteAutoscalingPolicy<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -93,6 +94,7 @@ impl<'a, 'b> AutoscalingDeleteAutoscalingPolicy<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -160,9 +162,20 @@ impl<'a, 'b> AutoscalingDeleteAutoscalingPolicy<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("autoscaling.delete_autoscaling_policy")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -190,6 +203,7 @@ pub struct AutoscalingGetAutoscalingCapacity<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -205,6 +219,7 @@ impl<'a, 'b> AutoscalingGetAutoscalingCapacity<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -230,6 +245,11 @@ impl<'a, 'b> AutoscalingGetAutoscalingCapacity<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -272,9 +292,20 @@ impl<'a, 'b> AutoscalingGetAutoscalingCapacity<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("autoscaling.get_autoscaling_capacity")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -308,6 +339,7 @@ pub struct AutoscalingGetAutoscalingPolicy<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -323,6 +355,7 @@ impl<'a, 'b> AutoscalingGetAutoscalingPolicy<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -348,6 +381,11 @@ impl<'a, 'b> AutoscalingGetAutoscalingPolicy<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -390,9 +428,20 @@ impl<'a, 'b> AutoscalingGetAutoscalingPolicy<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("autoscaling.get_autoscaling_policy")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -427,6 +476,7 @@ pub struct AutoscalingPutAutoscalingPolicy<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -446,6 +496,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -464,6 +515,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -489,6 +541,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -531,9 +588,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("autoscaling.put_autoscaling_policy")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
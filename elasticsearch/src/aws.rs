@@ -0,0 +1,554 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! AWS Signature Version 4 request signing, for clusters running on Amazon OpenSearch Service
+//!
+//! Requests are signed with [AwsSigV4] credentials, retrieved from an [AwsCredentialsProvider].
+//!
+//! ```rust,no_run
+//! # use elasticsearch::{
+//! #     aws::{AwsSigV4, EnvironmentCredentials},
+//! #     http::transport::{SingleNodeConnectionPool, TransportBuilder},
+//! #     Elasticsearch,
+//! # };
+//! # use url::Url;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let url = Url::parse("https://search-domain.us-east-1.es.amazonaws.com")?;
+//! let credentials = AwsSigV4::new(EnvironmentCredentials, "us-east-1");
+//! let transport = TransportBuilder::new(SingleNodeConnectionPool::new(url))
+//!     .auth(credentials.into())
+//!     .build()?;
+//! let client = Elasticsearch::new(transport);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Optional
+//!
+//! This requires the `aws-auth` feature to be enabled.
+use crate::{
+    auth::Credentials,
+    error::Error,
+    http::{
+        client::HttpRequest,
+        headers::{HeaderName, HeaderValue, AUTHORIZATION},
+        middleware::{Middleware, Next},
+        response::Response,
+        Method,
+    },
+};
+use async_trait::async_trait;
+use dyn_clone::clone_trait_object;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use std::{
+    env, fmt,
+    fmt::{Debug, Write},
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Credentials are refreshed when they expire within this duration
+const REFRESH_BEFORE_EXPIRATION: Duration = Duration::from_secs(5 * 60);
+
+/// AWS credentials used to sign requests
+#[derive(Clone)]
+pub struct AwsCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+    expiration: Option<SystemTime>,
+}
+
+impl AwsCredentials {
+    /// Creates a new instance of [AwsCredentials] from an access key id and secret access key
+    pub fn new(access_key_id: impl Into<String>, secret_access_key: impl Into<String>) -> Self {
+        Self {
+            access_key_id: access_key_id.into(),
+            secret_access_key: secret_access_key.into(),
+            session_token: None,
+            expiration: None,
+        }
+    }
+
+    /// Sets the session token of temporary credentials, sent in the `X-Amz-Security-Token` header
+    pub fn session_token(mut self, session_token: impl Into<String>) -> Self {
+        self.session_token = Some(session_token.into());
+        self
+    }
+
+    /// Sets when temporary credentials expire. Expiring credentials are
+    /// retrieved again from the [AwsCredentialsProvider] before they expire.
+    pub fn expiration(mut self, expiration: SystemTime) -> Self {
+        self.expiration = Some(expiration);
+        self
+    }
+
+    /// The access key id
+    pub fn access_key_id(&self) -> &str {
+        &self.access_key_id
+    }
+
+    /// Whether the credentials expire within the given duration from now
+    fn expires_within(&self, duration: Duration) -> bool {
+        match self.expiration {
+            Some(expiration) => expiration <= SystemTime::now() + duration,
+            None => false,
+        }
+    }
+}
+
+impl Debug for AwsCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AwsCredentials")
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &"<redacted>")
+            .field(
+                "session_token",
+                &self.session_token.as_ref().map(|_| "<redacted>"),
+            )
+            .field("expiration", &self.expiration)
+            .finish()
+    }
+}
+
+/// Provides the [AwsCredentials] used to sign requests
+#[async_trait]
+pub trait AwsCredentialsProvider: Debug + dyn_clone::DynClone + Sync + Send {
+    /// Gets the current [AwsCredentials]. Called again to refresh the credentials
+    /// before they expire.
+    async fn credentials(&self) -> Result<AwsCredentials, Error>;
+}
+
+clone_trait_object!(AwsCredentialsProvider);
+
+#[async_trait]
+impl AwsCredentialsProvider for AwsCredentials {
+    async fn credentials(&self) -> Result<AwsCredentials, Error> {
+        Ok(self.clone())
+    }
+}
+
+/// An [AwsCredentialsProvider] that reads the `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY`
+/// and optional `AWS_SESSION_TOKEN` environment variables
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnvironmentCredentials;
+
+#[async_trait]
+impl AwsCredentialsProvider for EnvironmentCredentials {
+    async fn credentials(&self) -> Result<AwsCredentials, Error> {
+        let var =
+            |name| env::var(name).map_err(|_| crate::error::lib(format!("{} is not set", name)));
+        let mut credentials =
+            AwsCredentials::new(var("AWS_ACCESS_KEY_ID")?, var("AWS_SECRET_ACCESS_KEY")?);
+        if let Ok(token) = env::var("AWS_SESSION_TOKEN") {
+            credentials = credentials.session_token(token);
+        }
+        Ok(credentials)
+    }
+}
+
+/// Credentials to sign requests with AWS Signature Version 4.
+///
+/// The method, canonical path, query string, `Host` header and a hash of the body of each
+/// request are signed, after any other [Middleware] has run.
+#[derive(Debug, Clone)]
+pub struct AwsSigV4 {
+    provider: Box<dyn AwsCredentialsProvider>,
+    region: String,
+    service: String,
+    credentials: Arc<Mutex<Option<AwsCredentials>>>,
+}
+
+impl AwsSigV4 {
+    /// Creates a new instance of [AwsSigV4] that signs requests to Amazon OpenSearch Service
+    /// in the given region, with credentials from the [AwsCredentialsProvider]
+    pub fn new<P>(provider: P, region: impl Into<String>) -> Self
+    where
+        P: AwsCredentialsProvider + 'static,
+    {
+        Self {
+            provider: Box::new(provider),
+            region: region.into(),
+            service: "es".into(),
+            credentials: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Sets the name of the service to sign requests for. Defaults to `es`,
+    /// for Amazon OpenSearch Service.
+    pub fn service(mut self, service: impl Into<String>) -> Self {
+        self.service = service.into();
+        self
+    }
+
+    /// Gets the current credentials, retrieving them from the provider if they
+    /// have not been retrieved yet or are about to expire
+    async fn credentials(&self) -> Result<AwsCredentials, Error> {
+        if let Some(credentials) = self.credentials.lock().unwrap().as_ref() {
+            if !credentials.expires_within(REFRESH_BEFORE_EXPIRATION) {
+                return Ok(credentials.clone());
+            }
+        }
+
+        let credentials = self.provider.credentials().await?;
+        *self.credentials.lock().unwrap() = Some(credentials.clone());
+        Ok(credentials)
+    }
+
+    /// Signs the request at the given time, adding the `X-Amz-Date`,
+    /// `X-Amz-Security-Token` and `Authorization` headers
+    fn sign(
+        &self,
+        request: &mut HttpRequest,
+        credentials: &AwsCredentials,
+        time: SystemTime,
+    ) -> Result<(), Error> {
+        let amz_date = amz_date(time);
+        let date = &amz_date[..8];
+
+        let url = request.url();
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(crate::error::lib("url has no host to sign")),
+        };
+
+        let mut headers = vec![("host", host), ("x-amz-date", amz_date.clone())];
+        if let Some(token) = &credentials.session_token {
+            headers.push(("x-amz-security-token", token.clone()));
+        }
+        let signed_headers = headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+
+        let method = match request.method() {
+            Method::Get => "GET",
+            Method::Put => "PUT",
+            Method::Post => "POST",
+            Method::Delete => "DELETE",
+            Method::Head => "HEAD",
+        };
+        let body = request.body().map(|b| b.as_ref()).unwrap_or_default();
+
+        let mut canonical_request = String::new();
+        canonical_request.push_str(method);
+        canonical_request.push('\n');
+        canonical_request.push_str(&encode(url.path(), false));
+        canonical_request.push('\n');
+        canonical_request.push_str(&canonical_query(request.url()));
+        canonical_request.push('\n');
+        for (name, value) in &headers {
+            let _ = writeln!(canonical_request, "{}:{}", name, value.trim());
+        }
+        canonical_request.push('\n');
+        canonical_request.push_str(&signed_headers);
+        canonical_request.push('\n');
+        canonical_request.push_str(&hex(&Sha256::digest(body)));
+
+        let scope = format!("{}/{}/{}/aws4_request", date, self.region, self.service);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex(&Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let mut key = hmac(
+            format!("AWS4{}", credentials.secret_access_key).as_bytes(),
+            date.as_bytes(),
+        );
+        for part in &[self.region.as_str(), self.service.as_str(), "aws4_request"] {
+            key = hmac(&key, part.as_bytes());
+        }
+        let signature = hex(&hmac(&key, string_to_sign.as_bytes()));
+
+        let authorization = format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            credentials.access_key_id, scope, signed_headers, signature
+        );
+
+        let request_headers = request.headers_mut();
+        for (name, value) in headers.into_iter().skip(1) {
+            let mut value =
+                HeaderValue::from_str(&value).map_err(|e| crate::error::lib(e.to_string()))?;
+            value.set_sensitive(name == "x-amz-security-token");
+            request_headers.insert(HeaderName::from_static(name), value);
+        }
+        let mut authorization =
+            HeaderValue::from_str(&authorization).map_err(|e| crate::error::lib(e.to_string()))?;
+        authorization.set_sensitive(true);
+        request_headers.insert(AUTHORIZATION, authorization);
+        Ok(())
+    }
+}
+
+impl From<AwsSigV4> for Credentials {
+    fn from(sigv4: AwsSigV4) -> Self {
+        Credentials::AwsSigV4(sigv4)
+    }
+}
+
+#[async_trait]
+impl Middleware for AwsSigV4 {
+    async fn handle(&self, mut request: HttpRequest, next: Next<'_>) -> Result<Response, Error> {
+        let credentials = self.credentials().await?;
+        self.sign(&mut request, &credentials, SystemTime::now())?;
+        next.run(request).await
+    }
+}
+
+/// Formats the time as `YYYYMMDD'T'HHMMSS'Z'`
+fn amz_date(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let (days, secs_of_day) = ((secs / 86_400) as i64, secs % 86_400);
+
+    // civil date from days since the epoch
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60
+    )
+}
+
+/// The query string parameters, encoded and sorted by name and then value
+fn canonical_query(url: &url::Url) -> String {
+    let mut pairs = url
+        .query_pairs()
+        .map(|(k, v)| (encode(&k, true), encode(&v, true)))
+        .collect::<Vec<_>>();
+    pairs.sort();
+    pairs
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// URI encodes all but unreserved characters, and `/` when not encoding a query string value
+fn encode(value: &str, encode_slash: bool) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for b in value.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(b as char)
+            }
+            b'/' if !encode_slash => encoded.push('/'),
+            _ => {
+                let _ = write!(encoded, "%{:02X}", b);
+            }
+        }
+    }
+    encoded
+}
+
+fn hmac(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut s, b| {
+        let _ = write!(s, "{:02x}", b);
+        s
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::http::{headers::HeaderMap, Url};
+    use bytes::Bytes;
+
+    // Test vectors from the AWS Signature Version 4 test suite, signed for
+    // the `service` service in `us-east-1` on 2015-08-30 at 12:36:00 UTC.
+    fn sign(
+        method: Method,
+        url: &str,
+        body: Option<&'static str>,
+        credentials: AwsCredentials,
+    ) -> HeaderMap {
+        let sigv4 = AwsSigV4::new(credentials.clone(), "us-east-1").service("service");
+        let mut request = HttpRequest::new(method, Url::parse(url).unwrap());
+        *request.body_mut() = body.map(|b| Bytes::from_static(b.as_bytes()));
+        let time = UNIX_EPOCH + Duration::from_secs(1_440_938_160);
+        sigv4.sign(&mut request, &credentials, time).unwrap();
+        request.headers().clone()
+    }
+
+    fn credentials() -> AwsCredentials {
+        AwsCredentials::new("AKIDEXAMPLE", "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY")
+    }
+
+    #[test]
+    fn amz_date_is_formatted() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_440_938_160);
+        assert_eq!(amz_date(time), "20150830T123600Z");
+        assert_eq!(amz_date(UNIX_EPOCH), "19700101T000000Z");
+        let leap_day = UNIX_EPOCH + Duration::from_secs(951_782_400 + 86_399);
+        assert_eq!(amz_date(leap_day), "20000229T235959Z");
+    }
+
+    #[test]
+    fn sign_get_vanilla() {
+        let headers = sign(
+            Method::Get,
+            "https://example.amazonaws.com/",
+            None,
+            credentials(),
+        );
+        assert_eq!(headers["x-amz-date"], "20150830T123600Z");
+        assert_eq!(
+            headers[AUTHORIZATION],
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+        );
+    }
+
+    #[test]
+    fn sign_get_vanilla_query_order_key_case() {
+        let headers = sign(
+            Method::Get,
+            "https://example.amazonaws.com/?Param2=value2&Param1=value1",
+            None,
+            credentials(),
+        );
+        assert_eq!(
+            headers[AUTHORIZATION],
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=b97d918cfa904a5beff61c982a1b6f458b799221646efd99d3219ec94cdf2500"
+        );
+    }
+
+    #[test]
+    fn sign_post_vanilla() {
+        let headers = sign(
+            Method::Post,
+            "https://example.amazonaws.com/",
+            None,
+            credentials(),
+        );
+        assert_eq!(
+            headers[AUTHORIZATION],
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5da7c1a2acd57cee7505fc6676e4e544621c30862966e37dddb68e92efbe5d6b"
+        );
+    }
+
+    #[test]
+    fn sign_with_session_token() {
+        let headers = sign(
+            Method::Post,
+            "https://example.amazonaws.com/",
+            None,
+            credentials().session_token("session-token"),
+        );
+        assert_eq!(headers["x-amz-security-token"], "session-token");
+        assert!(headers[AUTHORIZATION]
+            .to_str()
+            .unwrap()
+            .contains("SignedHeaders=host;x-amz-date;x-amz-security-token, "));
+    }
+
+    #[test]
+    fn sign_hashes_body() {
+        let without_body = sign(
+            Method::Post,
+            "https://example.amazonaws.com/",
+            None,
+            credentials(),
+        );
+        let with_body = sign(
+            Method::Post,
+            "https://example.amazonaws.com/",
+            Some("Param1=value1"),
+            credentials(),
+        );
+        assert_ne!(without_body[AUTHORIZATION], with_body[AUTHORIZATION]);
+    }
+
+    #[test]
+    fn canonical_query_is_sorted_and_encoded() {
+        let url = Url::parse("https://example.amazonaws.com/?b=2&a=x+y&a=%2Fz&c").unwrap();
+        assert_eq!(canonical_query(&url), "a=%2Fz&a=x%20y&b=2&c=");
+    }
+
+    #[derive(Debug, Clone)]
+    struct CountingProvider {
+        credentials: AwsCredentials,
+        calls: Arc<Mutex<usize>>,
+    }
+
+    #[async_trait]
+    impl AwsCredentialsProvider for CountingProvider {
+        async fn credentials(&self) -> Result<AwsCredentials, Error> {
+            *self.calls.lock().unwrap() += 1;
+            Ok(self.credentials.clone())
+        }
+    }
+
+    async fn provider_calls(credentials: AwsCredentials) -> usize {
+        let calls = Arc::new(Mutex::new(0));
+        let provider = CountingProvider {
+            credentials,
+            calls: calls.clone(),
+        };
+        let sigv4 = AwsSigV4::new(provider, "us-east-1");
+        sigv4.credentials().await.unwrap();
+        sigv4.credentials().await.unwrap();
+        let calls = *calls.lock().unwrap();
+        calls
+    }
+
+    #[tokio::test]
+    async fn credentials_are_refreshed_before_expiration() {
+        let now = SystemTime::now();
+        assert_eq!(provider_calls(credentials()).await, 1);
+        assert_eq!(
+            provider_calls(credentials().expiration(now + Duration::from_secs(3_600))).await,
+            1
+        );
+        assert_eq!(
+            provider_calls(credentials().expiration(now + Duration::from_secs(60))).await,
+            2
+        );
+    }
+}
@@ -0,0 +1,558 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+// -----------------------------------------------
+// This file is generated, Please do not edit it manually.
+// Run the following in the root of the repo to regenerate:
+//
+// cargo make generate-api
+// -----------------------------------------------
+
+#![allow(unused_imports)]
+pub use crate::async_search::AsyncSearchDeleteParts;
+use crate::{
+    blocking::{Elasticsearch, Response},
+    error::Error,
+    http::{
+        headers::{HeaderName, HeaderValue},
+        request::{Body, JsonBody, NdBody},
+        transport::{NodeSelector, Transport},
+    },
+    params::*,
+};
+use serde::Serialize;
+use std::time::Duration;
+#[doc = "Builder for the [Async Search Delete API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/async-search.html)\n\nDeletes an async search by ID. If the search is still running, the search request will be cancelled. Otherwise, the saved search results are deleted."]
+#[derive(Clone, Debug)]
+pub struct AsyncSearchDelete<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::async_search::AsyncSearchDelete<'a, 'b>,
+}
+impl<'a, 'b> AsyncSearchDelete<'a, 'b> {
+    #[doc = "Creates a new instance of [AsyncSearchDelete] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: AsyncSearchDeleteParts<'b>) -> Self {
+        AsyncSearchDelete {
+            client,
+            builder: crate::async_search::AsyncSearchDelete::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Async Search Delete API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::async_search::AsyncSearchGetParts;
+#[doc = "Builder for the [Async Search Get API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/async-search.html)\n\nRetrieves the results of a previously submitted async search request given its ID."]
+#[derive(Clone, Debug)]
+pub struct AsyncSearchGet<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::async_search::AsyncSearchGet<'a, 'b>,
+}
+impl<'a, 'b> AsyncSearchGet<'a, 'b> {
+    #[doc = "Creates a new instance of [AsyncSearchGet] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: AsyncSearchGetParts<'b>) -> Self {
+        AsyncSearchGet {
+            client,
+            builder: crate::async_search::AsyncSearchGet::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Specify the time interval in which the results (partial or final) for this search will be available"]
+    pub fn keep_alive(mut self, keep_alive: &'b str) -> Self {
+        self.builder = self.builder.keep_alive(keep_alive);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Specify whether aggregation and suggester names should be prefixed by their respective types in the response"]
+    pub fn typed_keys(mut self, typed_keys: bool) -> Self {
+        self.builder = self.builder.typed_keys(typed_keys);
+        self
+    }
+    #[doc = "Specify the time that the request should block waiting for the final response"]
+    pub fn wait_for_completion_timeout(mut self, wait_for_completion_timeout: &'b str) -> Self {
+        self.builder = self
+            .builder
+            .wait_for_completion_timeout(wait_for_completion_timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Async Search Get API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::async_search::AsyncSearchStatusParts;
+#[doc = "Builder for the [Async Search Status API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/async-search.html)\n\nRetrieves the status of a previously submitted async search request given its ID."]
+#[derive(Clone, Debug)]
+pub struct AsyncSearchStatus<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::async_search::AsyncSearchStatus<'a, 'b>,
+}
+impl<'a, 'b> AsyncSearchStatus<'a, 'b> {
+    #[doc = "Creates a new instance of [AsyncSearchStatus] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: AsyncSearchStatusParts<'b>) -> Self {
+        AsyncSearchStatus {
+            client,
+            builder: crate::async_search::AsyncSearchStatus::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Async Search Status API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::async_search::AsyncSearchSubmitParts;
+#[doc = "Builder for the [Async Search Submit API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/async-search.html)\n\nExecutes a search request asynchronously."]
+#[derive(Clone, Debug)]
+pub struct AsyncSearchSubmit<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::async_search::AsyncSearchSubmit<'a, 'b, B>,
+}
+impl<'a, 'b, B> AsyncSearchSubmit<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [AsyncSearchSubmit] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: AsyncSearchSubmitParts<'b>) -> Self {
+        AsyncSearchSubmit {
+            client,
+            builder: crate::async_search::AsyncSearchSubmit::new(client.transport(), parts),
+        }
+    }
+    #[doc = "True or false to return the _source field or not, or a list of fields to return"]
+    pub fn _source(mut self, _source: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source(_source);
+        self
+    }
+    #[doc = "A list of fields to exclude from the returned _source field"]
+    pub fn _source_excludes(mut self, _source_excludes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_excludes(_source_excludes);
+        self
+    }
+    #[doc = "A list of fields to extract and return from the _source field"]
+    pub fn _source_includes(mut self, _source_includes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_includes(_source_includes);
+        self
+    }
+    #[doc = "Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)"]
+    pub fn allow_no_indices(mut self, allow_no_indices: bool) -> Self {
+        self.builder = self.builder.allow_no_indices(allow_no_indices);
+        self
+    }
+    #[doc = "Indicate if an error should be returned if there is a partial search failure or timeout"]
+    pub fn allow_partial_search_results(mut self, allow_partial_search_results: bool) -> Self {
+        self.builder = self
+            .builder
+            .allow_partial_search_results(allow_partial_search_results);
+        self
+    }
+    #[doc = "Specify whether wildcard and prefix queries should be analyzed (default: false)"]
+    pub fn analyze_wildcard(mut self, analyze_wildcard: bool) -> Self {
+        self.builder = self.builder.analyze_wildcard(analyze_wildcard);
+        self
+    }
+    #[doc = "The analyzer to use for the query string"]
+    pub fn analyzer(mut self, analyzer: &'b str) -> Self {
+        self.builder = self.builder.analyzer(analyzer);
+        self
+    }
+    #[doc = "The number of shard results that should be reduced at once on the coordinating node. This value should be used as the granularity at which progress results will be made available."]
+    pub fn batched_reduce_size(mut self, batched_reduce_size: i64) -> Self {
+        self.builder = self.builder.batched_reduce_size(batched_reduce_size);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> AsyncSearchSubmit<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        AsyncSearchSubmit {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "The default operator for query string query (AND or OR)"]
+    pub fn default_operator(mut self, default_operator: DefaultOperator) -> Self {
+        self.builder = self.builder.default_operator(default_operator);
+        self
+    }
+    #[doc = "The field to use as default where no field prefix is given in the query string"]
+    pub fn df(mut self, df: &'b str) -> Self {
+        self.builder = self.builder.df(df);
+        self
+    }
+    #[doc = "A comma-separated list of fields to return as the docvalue representation of a field for each hit"]
+    pub fn docvalue_fields(mut self, docvalue_fields: &'b [&'b str]) -> Self {
+        self.builder = self.builder.docvalue_fields(docvalue_fields);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether to expand wildcard expression to concrete indices that are open, closed or both."]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "Specify whether to return detailed information about score computation as part of a hit"]
+    pub fn explain(mut self, explain: bool) -> Self {
+        self.builder = self.builder.explain(explain);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Starting offset (default: 0)"]
+    pub fn from(mut self, from: i64) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Whether specified concrete, expanded or aliased indices should be ignored when throttled"]
+    pub fn ignore_throttled(mut self, ignore_throttled: bool) -> Self {
+        self.builder = self.builder.ignore_throttled(ignore_throttled);
+        self
+    }
+    #[doc = "Whether specified concrete indices should be ignored when unavailable (missing or closed)"]
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.builder = self.builder.ignore_unavailable(ignore_unavailable);
+        self
+    }
+    #[doc = "Update the time interval in which the results (partial or final) for this search will be available"]
+    pub fn keep_alive(mut self, keep_alive: &'b str) -> Self {
+        self.builder = self.builder.keep_alive(keep_alive);
+        self
+    }
+    #[doc = "Control whether the response should be stored in the cluster if it completed within the provided [wait_for_completion] time (default: false)"]
+    pub fn keep_on_completion(mut self, keep_on_completion: bool) -> Self {
+        self.builder = self.builder.keep_on_completion(keep_on_completion);
+        self
+    }
+    #[doc = "Specify whether format-based query failures (such as providing text to a numeric field) should be ignored"]
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.builder = self.builder.lenient(lenient);
+        self
+    }
+    #[doc = "The number of concurrent shard requests per node this search executes concurrently. This value should be used to limit the impact of the search on the cluster in order to limit the number of concurrent shard requests"]
+    pub fn max_concurrent_shard_requests(mut self, max_concurrent_shard_requests: i64) -> Self {
+        self.builder = self
+            .builder
+            .max_concurrent_shard_requests(max_concurrent_shard_requests);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Query in the Lucene query string syntax"]
+    pub fn q(mut self, q: &'b str) -> Self {
+        self.builder = self.builder.q(q);
+        self
+    }
+    #[doc = "Specify if request cache should be used for this request or not, defaults to true"]
+    pub fn request_cache(mut self, request_cache: bool) -> Self {
+        self.builder = self.builder.request_cache(request_cache);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "A comma-separated list of specific routing values"]
+    pub fn routing(mut self, routing: &'b [&'b str]) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "Search operation type"]
+    pub fn search_type(mut self, search_type: SearchType) -> Self {
+        self.builder = self.builder.search_type(search_type);
+        self
+    }
+    #[doc = "Specify whether to return sequence number and primary term of the last modification of each hit"]
+    pub fn seq_no_primary_term(mut self, seq_no_primary_term: bool) -> Self {
+        self.builder = self.builder.seq_no_primary_term(seq_no_primary_term);
+        self
+    }
+    #[doc = "Number of hits to return (default: 10)"]
+    pub fn size(mut self, size: i64) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "A comma-separated list of <field>:<direction> pairs"]
+    pub fn sort(mut self, sort: &'b [&'b str]) -> Self {
+        self.builder = self.builder.sort(sort);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Specific 'tag' of the request for logging and statistical purposes"]
+    pub fn stats(mut self, stats: &'b [&'b str]) -> Self {
+        self.builder = self.builder.stats(stats);
+        self
+    }
+    #[doc = "A comma-separated list of stored fields to return as part of a hit"]
+    pub fn stored_fields(mut self, stored_fields: &'b [&'b str]) -> Self {
+        self.builder = self.builder.stored_fields(stored_fields);
+        self
+    }
+    #[doc = "Specify which field to use for suggestions"]
+    pub fn suggest_field(mut self, suggest_field: &'b str) -> Self {
+        self.builder = self.builder.suggest_field(suggest_field);
+        self
+    }
+    #[doc = "Specify suggest mode"]
+    pub fn suggest_mode(mut self, suggest_mode: SuggestMode) -> Self {
+        self.builder = self.builder.suggest_mode(suggest_mode);
+        self
+    }
+    #[doc = "How many suggestions to return in response"]
+    pub fn suggest_size(mut self, suggest_size: i64) -> Self {
+        self.builder = self.builder.suggest_size(suggest_size);
+        self
+    }
+    #[doc = "The source text for which the suggestions should be returned"]
+    pub fn suggest_text(mut self, suggest_text: &'b str) -> Self {
+        self.builder = self.builder.suggest_text(suggest_text);
+        self
+    }
+    #[doc = "The maximum number of documents to collect for each shard, upon reaching which the query execution will terminate early."]
+    pub fn terminate_after(mut self, terminate_after: i64) -> Self {
+        self.builder = self.builder.terminate_after(terminate_after);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Whether to calculate and return scores even if they are not used for sorting"]
+    pub fn track_scores(mut self, track_scores: bool) -> Self {
+        self.builder = self.builder.track_scores(track_scores);
+        self
+    }
+    #[doc = "Indicate if the number of documents that match the query should be tracked. A number can also be specified, to accurately track the total hit count up to the number."]
+    pub fn track_total_hits<T: Into<TrackTotalHits>>(mut self, track_total_hits: T) -> Self {
+        self.builder = self.builder.track_total_hits(track_total_hits);
+        self
+    }
+    #[doc = "Specify whether aggregation and suggester names should be prefixed by their respective types in the response"]
+    pub fn typed_keys(mut self, typed_keys: bool) -> Self {
+        self.builder = self.builder.typed_keys(typed_keys);
+        self
+    }
+    #[doc = "Specify whether to return document version as part of a hit"]
+    pub fn version(mut self, version: bool) -> Self {
+        self.builder = self.builder.version(version);
+        self
+    }
+    #[doc = "Specify the time that the request should block waiting for the final response"]
+    pub fn wait_for_completion_timeout(mut self, wait_for_completion_timeout: &'b str) -> Self {
+        self.builder = self
+            .builder
+            .wait_for_completion_timeout(wait_for_completion_timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Async Search Submit API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[doc = "Namespace client for AsyncSearch APIs"]
+pub struct AsyncSearch<'a> {
+    client: &'a Elasticsearch,
+}
+impl<'a> AsyncSearch<'a> {
+    #[doc = "Creates a new instance of [AsyncSearch]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        Self { client }
+    }
+    pub fn transport(&self) -> &Transport {
+        self.client.transport()
+    }
+    #[doc = "[Async Search Delete API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/async-search.html)\n\nDeletes an async search by ID. If the search is still running, the search request will be cancelled. Otherwise, the saved search results are deleted."]
+    pub fn delete<'b>(&'a self, parts: AsyncSearchDeleteParts<'b>) -> AsyncSearchDelete<'a, 'b> {
+        AsyncSearchDelete::new(self.client, parts)
+    }
+    #[doc = "[Async Search Get API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/async-search.html)\n\nRetrieves the results of a previously submitted async search request given its ID."]
+    pub fn get<'b>(&'a self, parts: AsyncSearchGetParts<'b>) -> AsyncSearchGet<'a, 'b> {
+        AsyncSearchGet::new(self.client, parts)
+    }
+    #[doc = "[Async Search Status API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/async-search.html)\n\nRetrieves the status of a previously submitted async search request given its ID."]
+    pub fn status<'b>(&'a self, parts: AsyncSearchStatusParts<'b>) -> AsyncSearchStatus<'a, 'b> {
+        AsyncSearchStatus::new(self.client, parts)
+    }
+    #[doc = "[Async Search Submit API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/async-search.html)\n\nExecutes a search request asynchronously."]
+    pub fn submit<'b>(
+        &'a self,
+        parts: AsyncSearchSubmitParts<'b>,
+    ) -> AsyncSearchSubmit<'a, 'b, ()> {
+        AsyncSearchSubmit::new(self.client, parts)
+    }
+}
+impl Elasticsearch {
+    #[doc = "Creates a namespace client for AsyncSearch APIs"]
+    pub fn async_search(&self) -> AsyncSearch {
+        AsyncSearch::new(self)
+    }
+}
@@ -0,0 +1,343 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+// -----------------------------------------------
+// This file is generated, Please do not edit it manually.
+// Run the following in the root of the repo to regenerate:
+//
+// cargo make generate-api
+// -----------------------------------------------
+
+#![allow(unused_imports)]
+pub use crate::autoscaling::AutoscalingDeleteAutoscalingPolicyParts;
+use crate::{
+    blocking::{Elasticsearch, Response},
+    error::Error,
+    http::{
+        headers::{HeaderName, HeaderValue},
+        request::{Body, JsonBody, NdBody},
+        transport::{NodeSelector, Transport},
+    },
+    params::*,
+};
+use serde::Serialize;
+use std::time::Duration;
+#[doc = "Builder for the [Autoscaling Delete Autoscaling Policy API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/autoscaling-delete-autoscaling-policy.html)\n\nDeletes an autoscaling policy. Designed for indirect use by ECE/ESS and ECK. Direct use is not supported."]
+#[derive(Clone, Debug)]
+pub struct AutoscalingDeleteAutoscalingPolicy<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::autoscaling::AutoscalingDeleteAutoscalingPolicy<'a, 'b>,
+}
+impl<'a, 'b> AutoscalingDeleteAutoscalingPolicy<'a, 'b> {
+    #[doc = "Creates a new instance of [AutoscalingDeleteAutoscalingPolicy] with the specified API parts"]
+    pub fn new(
+        client: &'a Elasticsearch,
+        parts: AutoscalingDeleteAutoscalingPolicyParts<'b>,
+    ) -> Self {
+        AutoscalingDeleteAutoscalingPolicy {
+            client,
+            builder: crate::autoscaling::AutoscalingDeleteAutoscalingPolicy::new(
+                client.transport(),
+                parts,
+            ),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Autoscaling Delete Autoscaling Policy API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::autoscaling::AutoscalingGetAutoscalingCapacityParts;
+#[doc = "Builder for the [Autoscaling Get Autoscaling Capacity API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/autoscaling-get-autoscaling-capacity.html)\n\nGets the current autoscaling capacity based on the configured autoscaling policy. Designed for indirect use by ECE/ESS and ECK. Direct use is not supported."]
+#[derive(Clone, Debug)]
+pub struct AutoscalingGetAutoscalingCapacity<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::autoscaling::AutoscalingGetAutoscalingCapacity<'a, 'b>,
+}
+impl<'a, 'b> AutoscalingGetAutoscalingCapacity<'a, 'b> {
+    #[doc = "Creates a new instance of [AutoscalingGetAutoscalingCapacity]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        AutoscalingGetAutoscalingCapacity {
+            client,
+            builder: crate::autoscaling::AutoscalingGetAutoscalingCapacity::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Autoscaling Get Autoscaling Capacity API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::autoscaling::AutoscalingGetAutoscalingPolicyParts;
+#[doc = "Builder for the [Autoscaling Get Autoscaling Policy API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/autoscaling-get-autoscaling-policy.html)\n\nRetrieves an autoscaling policy. Designed for indirect use by ECE/ESS and ECK. Direct use is not supported."]
+#[derive(Clone, Debug)]
+pub struct AutoscalingGetAutoscalingPolicy<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::autoscaling::AutoscalingGetAutoscalingPolicy<'a, 'b>,
+}
+impl<'a, 'b> AutoscalingGetAutoscalingPolicy<'a, 'b> {
+    #[doc = "Creates a new instance of [AutoscalingGetAutoscalingPolicy] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: AutoscalingGetAutoscalingPolicyParts<'b>) -> Self {
+        AutoscalingGetAutoscalingPolicy {
+            client,
+            builder: crate::autoscaling::AutoscalingGetAutoscalingPolicy::new(
+                client.transport(),
+                parts,
+            ),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Autoscaling Get Autoscaling Policy API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::autoscaling::AutoscalingPutAutoscalingPolicyParts;
+#[doc = "Builder for the [Autoscaling Put Autoscaling Policy API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/autoscaling-put-autoscaling-policy.html)\n\nCreates a new autoscaling policy. Designed for indirect use by ECE/ESS and ECK. Direct use is not supported."]
+#[derive(Clone, Debug)]
+pub struct AutoscalingPutAutoscalingPolicy<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::autoscaling::AutoscalingPutAutoscalingPolicy<'a, 'b, B>,
+}
+impl<'a, 'b, B> AutoscalingPutAutoscalingPolicy<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [AutoscalingPutAutoscalingPolicy] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: AutoscalingPutAutoscalingPolicyParts<'b>) -> Self {
+        AutoscalingPutAutoscalingPolicy {
+            client,
+            builder: crate::autoscaling::AutoscalingPutAutoscalingPolicy::new(
+                client.transport(),
+                parts,
+            ),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> AutoscalingPutAutoscalingPolicy<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        AutoscalingPutAutoscalingPolicy {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Autoscaling Put Autoscaling Policy API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[doc = "Namespace client for Autoscaling APIs"]
+pub struct Autoscaling<'a> {
+    client: &'a Elasticsearch,
+}
+impl<'a> Autoscaling<'a> {
+    #[doc = "Creates a new instance of [Autoscaling]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        Self { client }
+    }
+    pub fn transport(&self) -> &Transport {
+        self.client.transport()
+    }
+    #[doc = "[Autoscaling Delete Autoscaling Policy API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/autoscaling-delete-autoscaling-policy.html)\n\nDeletes an autoscaling policy. Designed for indirect use by ECE/ESS and ECK. Direct use is not supported."]
+    pub fn delete_autoscaling_policy<'b>(
+        &'a self,
+        parts: AutoscalingDeleteAutoscalingPolicyParts<'b>,
+    ) -> AutoscalingDeleteAutoscalingPolicy<'a, 'b> {
+        AutoscalingDeleteAutoscalingPolicy::new(self.client, parts)
+    }
+    #[doc = "[Autoscaling Get Autoscaling Capacity API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/autoscaling-get-autoscaling-capacity.html)\n\nGets the current autoscaling capacity based on the configured autoscaling policy. Designed for indirect use by ECE/ESS and ECK. Direct use is not supported."]
+    pub fn get_autoscaling_capacity<'b>(&'a self) -> AutoscalingGetAutoscalingCapacity<'a, 'b> {
+        AutoscalingGetAutoscalingCapacity::new(self.client)
+    }
+    #[doc = "[Autoscaling Get Autoscaling Policy API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/autoscaling-get-autoscaling-policy.html)\n\nRetrieves an autoscaling policy. Designed for indirect use by ECE/ESS and ECK. Direct use is not supported."]
+    pub fn get_autoscaling_policy<'b>(
+        &'a self,
+        parts: AutoscalingGetAutoscalingPolicyParts<'b>,
+    ) -> AutoscalingGetAutoscalingPolicy<'a, 'b> {
+        AutoscalingGetAutoscalingPolicy::new(self.client, parts)
+    }
+    #[doc = "[Autoscaling Put Autoscaling Policy API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/autoscaling-put-autoscaling-policy.html)\n\nCreates a new autoscaling policy. Designed for indirect use by ECE/ESS and ECK. Direct use is not supported."]
+    pub fn put_autoscaling_policy<'b>(
+        &'a self,
+        parts: AutoscalingPutAutoscalingPolicyParts<'b>,
+    ) -> AutoscalingPutAutoscalingPolicy<'a, 'b, ()> {
+        AutoscalingPutAutoscalingPolicy::new(self.client, parts)
+    }
+}
+impl Elasticsearch {
+    #[doc = "Creates a namespace client for Autoscaling APIs"]
+    pub fn autoscaling(&self) -> Autoscaling {
+        Autoscaling::new(self)
+    }
+}
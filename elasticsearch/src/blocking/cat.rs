@@ -0,0 +1,2654 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+// -----------------------------------------------
+// This file is generated, Please do not edit it manually.
+// Run the following in the root of the repo to regenerate:
+//
+// cargo make generate-api
+// -----------------------------------------------
+
+#![allow(unused_imports)]
+pub use crate::cat::CatAliasesParts;
+use crate::{
+    blocking::{Elasticsearch, Response},
+    error::Error,
+    http::{
+        headers::{HeaderName, HeaderValue},
+        request::{Body, JsonBody, NdBody},
+        transport::{NodeSelector, Transport},
+    },
+    params::*,
+};
+use serde::Serialize;
+use std::time::Duration;
+#[doc = "Builder for the [Cat Aliases API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-alias.html)\n\nShows information about currently configured aliases to indices including filter and routing infos."]
+#[derive(Clone, Debug)]
+pub struct CatAliases<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatAliases<'a, 'b>,
+}
+impl<'a, 'b> CatAliases<'a, 'b> {
+    #[doc = "Creates a new instance of [CatAliases] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatAliasesParts<'b>) -> Self {
+        CatAliases {
+            client,
+            builder: crate::cat::CatAliases::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether to expand wildcard expression to concrete indices that are open, closed or both."]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node (default: false)"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Aliases API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatAllocationParts;
+#[doc = "Builder for the [Cat Allocation API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-allocation.html)\n\nProvides a snapshot of how many shards are allocated to each data node and how much disk space they are using."]
+#[derive(Clone, Debug)]
+pub struct CatAllocation<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatAllocation<'a, 'b>,
+}
+impl<'a, 'b> CatAllocation<'a, 'b> {
+    #[doc = "Creates a new instance of [CatAllocation] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatAllocationParts<'b>) -> Self {
+        CatAllocation {
+            client,
+            builder: crate::cat::CatAllocation::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The unit in which to display byte values"]
+    pub fn bytes(mut self, bytes: Bytes) -> Self {
+        self.builder = self.builder.bytes(bytes);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node (default: false)"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Allocation API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatCountParts;
+#[doc = "Builder for the [Cat Count API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-count.html)\n\nProvides quick access to the document count of the entire cluster, or individual indices."]
+#[derive(Clone, Debug)]
+pub struct CatCount<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatCount<'a, 'b>,
+}
+impl<'a, 'b> CatCount<'a, 'b> {
+    #[doc = "Creates a new instance of [CatCount] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatCountParts<'b>) -> Self {
+        CatCount {
+            client,
+            builder: crate::cat::CatCount::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Count API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatFielddataParts;
+#[doc = "Builder for the [Cat Fielddata API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-fielddata.html)\n\nShows how much heap memory is currently being used by fielddata on every data node in the cluster."]
+#[derive(Clone, Debug)]
+pub struct CatFielddata<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatFielddata<'a, 'b>,
+}
+impl<'a, 'b> CatFielddata<'a, 'b> {
+    #[doc = "Creates a new instance of [CatFielddata] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatFielddataParts<'b>) -> Self {
+        CatFielddata {
+            client,
+            builder: crate::cat::CatFielddata::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The unit in which to display byte values"]
+    pub fn bytes(mut self, bytes: Bytes) -> Self {
+        self.builder = self.builder.bytes(bytes);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of fields to return in the output"]
+    pub fn fields(mut self, fields: &'b [&'b str]) -> Self {
+        self.builder = self.builder.fields(fields);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Fielddata API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatHealthParts;
+#[doc = "Builder for the [Cat Health API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-health.html)\n\nReturns a concise representation of the cluster health."]
+#[derive(Clone, Debug)]
+pub struct CatHealth<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatHealth<'a, 'b>,
+}
+impl<'a, 'b> CatHealth<'a, 'b> {
+    #[doc = "Creates a new instance of [CatHealth]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        CatHealth {
+            client,
+            builder: crate::cat::CatHealth::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The unit in which to display time values"]
+    pub fn time(mut self, time: Time) -> Self {
+        self.builder = self.builder.time(time);
+        self
+    }
+    #[doc = "Set to false to disable timestamping"]
+    pub fn ts(mut self, ts: bool) -> Self {
+        self.builder = self.builder.ts(ts);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Health API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatHelpParts;
+#[doc = "Builder for the [Cat Help API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat.html)\n\nReturns help for the Cat APIs."]
+#[derive(Clone, Debug)]
+pub struct CatHelp<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatHelp<'a, 'b>,
+}
+impl<'a, 'b> CatHelp<'a, 'b> {
+    #[doc = "Creates a new instance of [CatHelp]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        CatHelp {
+            client,
+            builder: crate::cat::CatHelp::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Help API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatIndicesParts;
+#[doc = "Builder for the [Cat Indices API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-indices.html)\n\nReturns information about indices: number of primaries and replicas, document counts, disk size, ..."]
+#[derive(Clone, Debug)]
+pub struct CatIndices<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatIndices<'a, 'b>,
+}
+impl<'a, 'b> CatIndices<'a, 'b> {
+    #[doc = "Creates a new instance of [CatIndices] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatIndicesParts<'b>) -> Self {
+        CatIndices {
+            client,
+            builder: crate::cat::CatIndices::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The unit in which to display byte values"]
+    pub fn bytes(mut self, bytes: Bytes) -> Self {
+        self.builder = self.builder.bytes(bytes);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether to expand wildcard expression to concrete indices that are open, closed or both."]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "A health status (\"green\", \"yellow\", or \"red\" to filter only indices matching the specified health status"]
+    pub fn health(mut self, health: Health) -> Self {
+        self.builder = self.builder.health(health);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "If set to true segment stats will include stats for segments that are not currently loaded into memory"]
+    pub fn include_unloaded_segments(mut self, include_unloaded_segments: bool) -> Self {
+        self.builder = self
+            .builder
+            .include_unloaded_segments(include_unloaded_segments);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Set to true to return stats only for primary shards"]
+    pub fn pri(mut self, pri: bool) -> Self {
+        self.builder = self.builder.pri(pri);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The unit in which to display time values"]
+    pub fn time(mut self, time: Time) -> Self {
+        self.builder = self.builder.time(time);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Indices API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatMasterParts;
+#[doc = "Builder for the [Cat Master API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-master.html)\n\nReturns information about the master node."]
+#[derive(Clone, Debug)]
+pub struct CatMaster<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatMaster<'a, 'b>,
+}
+impl<'a, 'b> CatMaster<'a, 'b> {
+    #[doc = "Creates a new instance of [CatMaster]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        CatMaster {
+            client,
+            builder: crate::cat::CatMaster::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node (default: false)"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Master API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatMlDataFrameAnalyticsParts;
+#[doc = "Builder for the [Cat Ml Data Frame Analytics API](http://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-dfanalytics.html)\n\nGets configuration and usage information about data frame analytics jobs."]
+#[derive(Clone, Debug)]
+pub struct CatMlDataFrameAnalytics<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatMlDataFrameAnalytics<'a, 'b>,
+}
+impl<'a, 'b> CatMlDataFrameAnalytics<'a, 'b> {
+    #[doc = "Creates a new instance of [CatMlDataFrameAnalytics] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatMlDataFrameAnalyticsParts<'b>) -> Self {
+        CatMlDataFrameAnalytics {
+            client,
+            builder: crate::cat::CatMlDataFrameAnalytics::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no configs. (This includes `_all` string or when no configs have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "The unit in which to display byte values"]
+    pub fn bytes(mut self, bytes: Bytes) -> Self {
+        self.builder = self.builder.bytes(bytes);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The unit in which to display time values"]
+    pub fn time(mut self, time: Time) -> Self {
+        self.builder = self.builder.time(time);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Ml Data Frame Analytics API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatMlDatafeedsParts;
+#[doc = "Builder for the [Cat Ml Datafeeds API](http://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-datafeeds.html)\n\nGets configuration and usage information about datafeeds."]
+#[derive(Clone, Debug)]
+pub struct CatMlDatafeeds<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatMlDatafeeds<'a, 'b>,
+}
+impl<'a, 'b> CatMlDatafeeds<'a, 'b> {
+    #[doc = "Creates a new instance of [CatMlDatafeeds] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatMlDatafeedsParts<'b>) -> Self {
+        CatMlDatafeeds {
+            client,
+            builder: crate::cat::CatMlDatafeeds::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no datafeeds. (This includes `_all` string or when no datafeeds have been specified)"]
+    pub fn allow_no_datafeeds(mut self, allow_no_datafeeds: bool) -> Self {
+        self.builder = self.builder.allow_no_datafeeds(allow_no_datafeeds);
+        self
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no datafeeds. (This includes `_all` string or when no datafeeds have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The unit in which to display time values"]
+    pub fn time(mut self, time: Time) -> Self {
+        self.builder = self.builder.time(time);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Ml Datafeeds API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatMlJobsParts;
+#[doc = "Builder for the [Cat Ml Jobs API](http://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-anomaly-detectors.html)\n\nGets configuration and usage information about anomaly detection jobs."]
+#[derive(Clone, Debug)]
+pub struct CatMlJobs<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatMlJobs<'a, 'b>,
+}
+impl<'a, 'b> CatMlJobs<'a, 'b> {
+    #[doc = "Creates a new instance of [CatMlJobs] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatMlJobsParts<'b>) -> Self {
+        CatMlJobs {
+            client,
+            builder: crate::cat::CatMlJobs::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no jobs. (This includes `_all` string or when no jobs have been specified)"]
+    pub fn allow_no_jobs(mut self, allow_no_jobs: bool) -> Self {
+        self.builder = self.builder.allow_no_jobs(allow_no_jobs);
+        self
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no jobs. (This includes `_all` string or when no jobs have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "The unit in which to display byte values"]
+    pub fn bytes(mut self, bytes: Bytes) -> Self {
+        self.builder = self.builder.bytes(bytes);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The unit in which to display time values"]
+    pub fn time(mut self, time: Time) -> Self {
+        self.builder = self.builder.time(time);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Ml Jobs API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatMlTrainedModelsParts;
+#[doc = "Builder for the [Cat Ml Trained Models API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-trained-model.html)\n\nGets configuration and usage information about inference trained models."]
+#[derive(Clone, Debug)]
+pub struct CatMlTrainedModels<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatMlTrainedModels<'a, 'b>,
+}
+impl<'a, 'b> CatMlTrainedModels<'a, 'b> {
+    #[doc = "Creates a new instance of [CatMlTrainedModels] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatMlTrainedModelsParts<'b>) -> Self {
+        CatMlTrainedModels {
+            client,
+            builder: crate::cat::CatMlTrainedModels::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no trained models. (This includes `_all` string or when no trained models have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "The unit in which to display byte values"]
+    pub fn bytes(mut self, bytes: Bytes) -> Self {
+        self.builder = self.builder.bytes(bytes);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "skips a number of trained models"]
+    pub fn from(mut self, from: i32) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "specifies a max number of trained models to get"]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The unit in which to display time values"]
+    pub fn time(mut self, time: Time) -> Self {
+        self.builder = self.builder.time(time);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Ml Trained Models API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatNodeattrsParts;
+#[doc = "Builder for the [Cat Nodeattrs API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-nodeattrs.html)\n\nReturns information about custom node attributes."]
+#[derive(Clone, Debug)]
+pub struct CatNodeattrs<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatNodeattrs<'a, 'b>,
+}
+impl<'a, 'b> CatNodeattrs<'a, 'b> {
+    #[doc = "Creates a new instance of [CatNodeattrs]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        CatNodeattrs {
+            client,
+            builder: crate::cat::CatNodeattrs::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node (default: false)"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Nodeattrs API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatNodesParts;
+#[doc = "Builder for the [Cat Nodes API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-nodes.html)\n\nReturns basic statistics about performance of cluster nodes."]
+#[derive(Clone, Debug)]
+pub struct CatNodes<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatNodes<'a, 'b>,
+}
+impl<'a, 'b> CatNodes<'a, 'b> {
+    #[doc = "Creates a new instance of [CatNodes]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        CatNodes {
+            client,
+            builder: crate::cat::CatNodes::new(client.transport()),
+        }
+    }
+    #[doc = "The unit in which to display byte values"]
+    pub fn bytes(mut self, bytes: Bytes) -> Self {
+        self.builder = self.builder.bytes(bytes);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Return the full node ID instead of the shortened version (default: false)"]
+    pub fn full_id(mut self, full_id: bool) -> Self {
+        self.builder = self.builder.full_id(full_id);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "If set to true segment stats will include stats for segments that are not currently loaded into memory"]
+    pub fn include_unloaded_segments(mut self, include_unloaded_segments: bool) -> Self {
+        self.builder = self
+            .builder
+            .include_unloaded_segments(include_unloaded_segments);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The unit in which to display time values"]
+    pub fn time(mut self, time: Time) -> Self {
+        self.builder = self.builder.time(time);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Nodes API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatPendingTasksParts;
+#[doc = "Builder for the [Cat Pending Tasks API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-pending-tasks.html)\n\nReturns a concise representation of the cluster pending tasks."]
+#[derive(Clone, Debug)]
+pub struct CatPendingTasks<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatPendingTasks<'a, 'b>,
+}
+impl<'a, 'b> CatPendingTasks<'a, 'b> {
+    #[doc = "Creates a new instance of [CatPendingTasks]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        CatPendingTasks {
+            client,
+            builder: crate::cat::CatPendingTasks::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node (default: false)"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The unit in which to display time values"]
+    pub fn time(mut self, time: Time) -> Self {
+        self.builder = self.builder.time(time);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Pending Tasks API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatPluginsParts;
+#[doc = "Builder for the [Cat Plugins API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-plugins.html)\n\nReturns information about installed plugins across nodes node."]
+#[derive(Clone, Debug)]
+pub struct CatPlugins<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatPlugins<'a, 'b>,
+}
+impl<'a, 'b> CatPlugins<'a, 'b> {
+    #[doc = "Creates a new instance of [CatPlugins]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        CatPlugins {
+            client,
+            builder: crate::cat::CatPlugins::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Include bootstrap plugins in the response"]
+    pub fn include_bootstrap(mut self, include_bootstrap: bool) -> Self {
+        self.builder = self.builder.include_bootstrap(include_bootstrap);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node (default: false)"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Plugins API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatRecoveryParts;
+#[doc = "Builder for the [Cat Recovery API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-recovery.html)\n\nReturns information about index shard recoveries, both on-going completed."]
+#[derive(Clone, Debug)]
+pub struct CatRecovery<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatRecovery<'a, 'b>,
+}
+impl<'a, 'b> CatRecovery<'a, 'b> {
+    #[doc = "Creates a new instance of [CatRecovery] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatRecoveryParts<'b>) -> Self {
+        CatRecovery {
+            client,
+            builder: crate::cat::CatRecovery::new(client.transport(), parts),
+        }
+    }
+    #[doc = "If `true`, the response only includes ongoing shard recoveries"]
+    pub fn active_only(mut self, active_only: bool) -> Self {
+        self.builder = self.builder.active_only(active_only);
+        self
+    }
+    #[doc = "The unit in which to display byte values"]
+    pub fn bytes(mut self, bytes: Bytes) -> Self {
+        self.builder = self.builder.bytes(bytes);
+        self
+    }
+    #[doc = "If `true`, the response includes detailed information about shard recoveries"]
+    pub fn detailed(mut self, detailed: bool) -> Self {
+        self.builder = self.builder.detailed(detailed);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Comma-separated list or wildcard expression of index names to limit the returned information"]
+    pub fn index(mut self, index: &'b [&'b str]) -> Self {
+        self.builder = self.builder.index(index);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The unit in which to display time values"]
+    pub fn time(mut self, time: Time) -> Self {
+        self.builder = self.builder.time(time);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Recovery API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatRepositoriesParts;
+#[doc = "Builder for the [Cat Repositories API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-repositories.html)\n\nReturns information about snapshot repositories registered in the cluster."]
+#[derive(Clone, Debug)]
+pub struct CatRepositories<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatRepositories<'a, 'b>,
+}
+impl<'a, 'b> CatRepositories<'a, 'b> {
+    #[doc = "Creates a new instance of [CatRepositories]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        CatRepositories {
+            client,
+            builder: crate::cat::CatRepositories::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Repositories API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatSegmentsParts;
+#[doc = "Builder for the [Cat Segments API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-segments.html)\n\nProvides low-level information about the segments in the shards of an index."]
+#[derive(Clone, Debug)]
+pub struct CatSegments<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatSegments<'a, 'b>,
+}
+impl<'a, 'b> CatSegments<'a, 'b> {
+    #[doc = "Creates a new instance of [CatSegments] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatSegmentsParts<'b>) -> Self {
+        CatSegments {
+            client,
+            builder: crate::cat::CatSegments::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The unit in which to display byte values"]
+    pub fn bytes(mut self, bytes: Bytes) -> Self {
+        self.builder = self.builder.bytes(bytes);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Segments API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatShardsParts;
+#[doc = "Builder for the [Cat Shards API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-shards.html)\n\nProvides a detailed view of shard allocation on nodes."]
+#[derive(Clone, Debug)]
+pub struct CatShards<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatShards<'a, 'b>,
+}
+impl<'a, 'b> CatShards<'a, 'b> {
+    #[doc = "Creates a new instance of [CatShards] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatShardsParts<'b>) -> Self {
+        CatShards {
+            client,
+            builder: crate::cat::CatShards::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The unit in which to display byte values"]
+    pub fn bytes(mut self, bytes: Bytes) -> Self {
+        self.builder = self.builder.bytes(bytes);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The unit in which to display time values"]
+    pub fn time(mut self, time: Time) -> Self {
+        self.builder = self.builder.time(time);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Shards API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatSnapshotsParts;
+#[doc = "Builder for the [Cat Snapshots API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-snapshots.html)\n\nReturns all snapshots in a specific repository."]
+#[derive(Clone, Debug)]
+pub struct CatSnapshots<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatSnapshots<'a, 'b>,
+}
+impl<'a, 'b> CatSnapshots<'a, 'b> {
+    #[doc = "Creates a new instance of [CatSnapshots] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatSnapshotsParts<'b>) -> Self {
+        CatSnapshots {
+            client,
+            builder: crate::cat::CatSnapshots::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Set to true to ignore unavailable snapshots"]
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.builder = self.builder.ignore_unavailable(ignore_unavailable);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The unit in which to display time values"]
+    pub fn time(mut self, time: Time) -> Self {
+        self.builder = self.builder.time(time);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Snapshots API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[cfg(feature = "experimental-apis")]
+pub use crate::cat::CatTasksParts;
+#[doc = "Builder for the [Cat Tasks API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/tasks.html)\n\nReturns information about the tasks currently executing on one or more nodes in the cluster."]
+#[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+#[cfg(feature = "experimental-apis")]
+#[derive(Clone, Debug)]
+pub struct CatTasks<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatTasks<'a, 'b>,
+}
+#[cfg(feature = "experimental-apis")]
+impl<'a, 'b> CatTasks<'a, 'b> {
+    #[doc = "Creates a new instance of [CatTasks]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        CatTasks {
+            client,
+            builder: crate::cat::CatTasks::new(client.transport()),
+        }
+    }
+    #[doc = "A comma-separated list of actions that should be returned. Leave empty to return all."]
+    pub fn actions(mut self, actions: &'b [&'b str]) -> Self {
+        self.builder = self.builder.actions(actions);
+        self
+    }
+    #[doc = "Return detailed task information (default: false)"]
+    pub fn detailed(mut self, detailed: bool) -> Self {
+        self.builder = self.builder.detailed(detailed);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "A comma-separated list of node IDs or names to limit the returned information; use `_local` to return information from the node you're connecting to, leave empty to get information from all nodes"]
+    pub fn nodes(mut self, nodes: &'b [&'b str]) -> Self {
+        self.builder = self.builder.nodes(nodes);
+        self
+    }
+    #[doc = "Return tasks with specified parent task id (node_id:task_number). Set to -1 to return all."]
+    pub fn parent_task_id(mut self, parent_task_id: &'b str) -> Self {
+        self.builder = self.builder.parent_task_id(parent_task_id);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The unit in which to display time values"]
+    pub fn time(mut self, time: Time) -> Self {
+        self.builder = self.builder.time(time);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Tasks API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatTemplatesParts;
+#[doc = "Builder for the [Cat Templates API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-templates.html)\n\nReturns information about existing templates."]
+#[derive(Clone, Debug)]
+pub struct CatTemplates<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatTemplates<'a, 'b>,
+}
+impl<'a, 'b> CatTemplates<'a, 'b> {
+    #[doc = "Creates a new instance of [CatTemplates] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatTemplatesParts<'b>) -> Self {
+        CatTemplates {
+            client,
+            builder: crate::cat::CatTemplates::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node (default: false)"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Templates API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatThreadPoolParts;
+#[doc = "Builder for the [Cat Thread Pool API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-thread-pool.html)\n\nReturns cluster-wide thread pool statistics per node.\nBy default the active, queue and rejected statistics are returned for all thread pools."]
+#[derive(Clone, Debug)]
+pub struct CatThreadPool<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatThreadPool<'a, 'b>,
+}
+impl<'a, 'b> CatThreadPool<'a, 'b> {
+    #[doc = "Creates a new instance of [CatThreadPool] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatThreadPoolParts<'b>) -> Self {
+        CatThreadPool {
+            client,
+            builder: crate::cat::CatThreadPool::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node (default: false)"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The unit in which to display time values"]
+    pub fn time(mut self, time: Time) -> Self {
+        self.builder = self.builder.time(time);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Thread Pool API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cat::CatTransformsParts;
+#[doc = "Builder for the [Cat Transforms API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-transforms.html)\n\nGets configuration and usage information about transforms."]
+#[derive(Clone, Debug)]
+pub struct CatTransforms<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cat::CatTransforms<'a, 'b>,
+}
+impl<'a, 'b> CatTransforms<'a, 'b> {
+    #[doc = "Creates a new instance of [CatTransforms] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CatTransformsParts<'b>) -> Self {
+        CatTransforms {
+            client,
+            builder: crate::cat::CatTransforms::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no transforms. (This includes `_all` string or when no transforms have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "a short version of the Accept header, e.g. json, yaml"]
+    pub fn format(mut self, format: &'b str) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "skips a number of transform configs, defaults to 0"]
+    pub fn from(mut self, from: i32) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Comma-separated list of column names to display"]
+    pub fn h(mut self, h: &'b [&'b str]) -> Self {
+        self.builder = self.builder.h(h);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return help information"]
+    pub fn help(mut self, help: bool) -> Self {
+        self.builder = self.builder.help(help);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Comma-separated list of column names or column aliases to sort by"]
+    pub fn s(mut self, s: &'b [&'b str]) -> Self {
+        self.builder = self.builder.s(s);
+        self
+    }
+    #[doc = "specifies a max number of transforms to get, defaults to 100"]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The unit in which to display time values"]
+    pub fn time(mut self, time: Time) -> Self {
+        self.builder = self.builder.time(time);
+        self
+    }
+    #[doc = "Verbose mode. Display column headers"]
+    pub fn v(mut self, v: bool) -> Self {
+        self.builder = self.builder.v(v);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cat Transforms API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[doc = "Namespace client for Cat APIs"]
+pub struct Cat<'a> {
+    client: &'a Elasticsearch,
+}
+impl<'a> Cat<'a> {
+    #[doc = "Creates a new instance of [Cat]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        Self { client }
+    }
+    pub fn transport(&self) -> &Transport {
+        self.client.transport()
+    }
+    #[doc = "[Cat Aliases API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-alias.html)\n\nShows information about currently configured aliases to indices including filter and routing infos."]
+    pub fn aliases<'b>(&'a self, parts: CatAliasesParts<'b>) -> CatAliases<'a, 'b> {
+        CatAliases::new(self.client, parts)
+    }
+    #[doc = "[Cat Allocation API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-allocation.html)\n\nProvides a snapshot of how many shards are allocated to each data node and how much disk space they are using."]
+    pub fn allocation<'b>(&'a self, parts: CatAllocationParts<'b>) -> CatAllocation<'a, 'b> {
+        CatAllocation::new(self.client, parts)
+    }
+    #[doc = "[Cat Count API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-count.html)\n\nProvides quick access to the document count of the entire cluster, or individual indices."]
+    pub fn count<'b>(&'a self, parts: CatCountParts<'b>) -> CatCount<'a, 'b> {
+        CatCount::new(self.client, parts)
+    }
+    #[doc = "[Cat Fielddata API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-fielddata.html)\n\nShows how much heap memory is currently being used by fielddata on every data node in the cluster."]
+    pub fn fielddata<'b>(&'a self, parts: CatFielddataParts<'b>) -> CatFielddata<'a, 'b> {
+        CatFielddata::new(self.client, parts)
+    }
+    #[doc = "[Cat Health API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-health.html)\n\nReturns a concise representation of the cluster health."]
+    pub fn health<'b>(&'a self) -> CatHealth<'a, 'b> {
+        CatHealth::new(self.client)
+    }
+    #[doc = "[Cat Help API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat.html)\n\nReturns help for the Cat APIs."]
+    pub fn help<'b>(&'a self) -> CatHelp<'a, 'b> {
+        CatHelp::new(self.client)
+    }
+    #[doc = "[Cat Indices API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-indices.html)\n\nReturns information about indices: number of primaries and replicas, document counts, disk size, ..."]
+    pub fn indices<'b>(&'a self, parts: CatIndicesParts<'b>) -> CatIndices<'a, 'b> {
+        CatIndices::new(self.client, parts)
+    }
+    #[doc = "[Cat Master API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-master.html)\n\nReturns information about the master node."]
+    pub fn master<'b>(&'a self) -> CatMaster<'a, 'b> {
+        CatMaster::new(self.client)
+    }
+    #[doc = "[Cat Ml Data Frame Analytics API](http://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-dfanalytics.html)\n\nGets configuration and usage information about data frame analytics jobs."]
+    pub fn ml_data_frame_analytics<'b>(
+        &'a self,
+        parts: CatMlDataFrameAnalyticsParts<'b>,
+    ) -> CatMlDataFrameAnalytics<'a, 'b> {
+        CatMlDataFrameAnalytics::new(self.client, parts)
+    }
+    #[doc = "[Cat Ml Datafeeds API](http://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-datafeeds.html)\n\nGets configuration and usage information about datafeeds."]
+    pub fn ml_datafeeds<'b>(&'a self, parts: CatMlDatafeedsParts<'b>) -> CatMlDatafeeds<'a, 'b> {
+        CatMlDatafeeds::new(self.client, parts)
+    }
+    #[doc = "[Cat Ml Jobs API](http://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-anomaly-detectors.html)\n\nGets configuration and usage information about anomaly detection jobs."]
+    pub fn ml_jobs<'b>(&'a self, parts: CatMlJobsParts<'b>) -> CatMlJobs<'a, 'b> {
+        CatMlJobs::new(self.client, parts)
+    }
+    #[doc = "[Cat Ml Trained Models API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-trained-model.html)\n\nGets configuration and usage information about inference trained models."]
+    pub fn ml_trained_models<'b>(
+        &'a self,
+        parts: CatMlTrainedModelsParts<'b>,
+    ) -> CatMlTrainedModels<'a, 'b> {
+        CatMlTrainedModels::new(self.client, parts)
+    }
+    #[doc = "[Cat Nodeattrs API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-nodeattrs.html)\n\nReturns information about custom node attributes."]
+    pub fn nodeattrs<'b>(&'a self) -> CatNodeattrs<'a, 'b> {
+        CatNodeattrs::new(self.client)
+    }
+    #[doc = "[Cat Nodes API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-nodes.html)\n\nReturns basic statistics about performance of cluster nodes."]
+    pub fn nodes<'b>(&'a self) -> CatNodes<'a, 'b> {
+        CatNodes::new(self.client)
+    }
+    #[doc = "[Cat Pending Tasks API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-pending-tasks.html)\n\nReturns a concise representation of the cluster pending tasks."]
+    pub fn pending_tasks<'b>(&'a self) -> CatPendingTasks<'a, 'b> {
+        CatPendingTasks::new(self.client)
+    }
+    #[doc = "[Cat Plugins API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-plugins.html)\n\nReturns information about installed plugins across nodes node."]
+    pub fn plugins<'b>(&'a self) -> CatPlugins<'a, 'b> {
+        CatPlugins::new(self.client)
+    }
+    #[doc = "[Cat Recovery API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-recovery.html)\n\nReturns information about index shard recoveries, both on-going completed."]
+    pub fn recovery<'b>(&'a self, parts: CatRecoveryParts<'b>) -> CatRecovery<'a, 'b> {
+        CatRecovery::new(self.client, parts)
+    }
+    #[doc = "[Cat Repositories API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-repositories.html)\n\nReturns information about snapshot repositories registered in the cluster."]
+    pub fn repositories<'b>(&'a self) -> CatRepositories<'a, 'b> {
+        CatRepositories::new(self.client)
+    }
+    #[doc = "[Cat Segments API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-segments.html)\n\nProvides low-level information about the segments in the shards of an index."]
+    pub fn segments<'b>(&'a self, parts: CatSegmentsParts<'b>) -> CatSegments<'a, 'b> {
+        CatSegments::new(self.client, parts)
+    }
+    #[doc = "[Cat Shards API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-shards.html)\n\nProvides a detailed view of shard allocation on nodes."]
+    pub fn shards<'b>(&'a self, parts: CatShardsParts<'b>) -> CatShards<'a, 'b> {
+        CatShards::new(self.client, parts)
+    }
+    #[doc = "[Cat Snapshots API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-snapshots.html)\n\nReturns all snapshots in a specific repository."]
+    pub fn snapshots<'b>(&'a self, parts: CatSnapshotsParts<'b>) -> CatSnapshots<'a, 'b> {
+        CatSnapshots::new(self.client, parts)
+    }
+    #[doc = "[Cat Tasks API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/tasks.html)\n\nReturns information about the tasks currently executing on one or more nodes in the cluster."]
+    #[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+    #[cfg(feature = "experimental-apis")]
+    pub fn tasks<'b>(&'a self) -> CatTasks<'a, 'b> {
+        CatTasks::new(self.client)
+    }
+    #[doc = "[Cat Templates API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-templates.html)\n\nReturns information about existing templates."]
+    pub fn templates<'b>(&'a self, parts: CatTemplatesParts<'b>) -> CatTemplates<'a, 'b> {
+        CatTemplates::new(self.client, parts)
+    }
+    #[doc = "[Cat Thread Pool API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-thread-pool.html)\n\nReturns cluster-wide thread pool statistics per node.\nBy default the active, queue and rejected statistics are returned for all thread pools."]
+    pub fn thread_pool<'b>(&'a self, parts: CatThreadPoolParts<'b>) -> CatThreadPool<'a, 'b> {
+        CatThreadPool::new(self.client, parts)
+    }
+    #[doc = "[Cat Transforms API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cat-transforms.html)\n\nGets configuration and usage information about transforms."]
+    pub fn transforms<'b>(&'a self, parts: CatTransformsParts<'b>) -> CatTransforms<'a, 'b> {
+        CatTransforms::new(self.client, parts)
+    }
+}
+impl Elasticsearch {
+    #[doc = "Creates a namespace client for Cat APIs"]
+    pub fn cat(&self) -> Cat {
+        Cat::new(self)
+    }
+}
@@ -0,0 +1,1023 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+// -----------------------------------------------
+// This file is generated, Please do not edit it manually.
+// Run the following in the root of the repo to regenerate:
+//
+// cargo make generate-api
+// -----------------------------------------------
+
+#![allow(unused_imports)]
+pub use crate::ccr::CcrDeleteAutoFollowPatternParts;
+use crate::{
+    blocking::{Elasticsearch, Response},
+    error::Error,
+    http::{
+        headers::{HeaderName, HeaderValue},
+        request::{Body, JsonBody, NdBody},
+        transport::{NodeSelector, Transport},
+    },
+    params::*,
+};
+use serde::Serialize;
+use std::time::Duration;
+#[doc = "Builder for the [Ccr Delete Auto Follow Pattern API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-delete-auto-follow-pattern.html)\n\nDeletes auto-follow patterns."]
+#[derive(Clone, Debug)]
+pub struct CcrDeleteAutoFollowPattern<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ccr::CcrDeleteAutoFollowPattern<'a, 'b>,
+}
+impl<'a, 'b> CcrDeleteAutoFollowPattern<'a, 'b> {
+    #[doc = "Creates a new instance of [CcrDeleteAutoFollowPattern] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CcrDeleteAutoFollowPatternParts<'b>) -> Self {
+        CcrDeleteAutoFollowPattern {
+            client,
+            builder: crate::ccr::CcrDeleteAutoFollowPattern::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ccr Delete Auto Follow Pattern API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ccr::CcrFollowParts;
+#[doc = "Builder for the [Ccr Follow API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-put-follow.html)\n\nCreates a new follower index configured to follow the referenced leader index."]
+#[derive(Clone, Debug)]
+pub struct CcrFollow<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ccr::CcrFollow<'a, 'b, B>,
+}
+impl<'a, 'b, B> CcrFollow<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [CcrFollow] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CcrFollowParts<'b>) -> Self {
+        CcrFollow {
+            client,
+            builder: crate::ccr::CcrFollow::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> CcrFollow<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        CcrFollow {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Sets the number of shard copies that must be active before returning. Defaults to 0. Set to `all` for all shard copies, otherwise set to any non-negative value less than or equal to the total number of copies for the shard (number of replicas + 1)"]
+    pub fn wait_for_active_shards(mut self, wait_for_active_shards: &'b str) -> Self {
+        self.builder = self.builder.wait_for_active_shards(wait_for_active_shards);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ccr Follow API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ccr::CcrFollowInfoParts;
+#[doc = "Builder for the [Ccr Follow Info API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-get-follow-info.html)\n\nRetrieves information about all follower indices, including parameters and status for each follower index"]
+#[derive(Clone, Debug)]
+pub struct CcrFollowInfo<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ccr::CcrFollowInfo<'a, 'b>,
+}
+impl<'a, 'b> CcrFollowInfo<'a, 'b> {
+    #[doc = "Creates a new instance of [CcrFollowInfo] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CcrFollowInfoParts<'b>) -> Self {
+        CcrFollowInfo {
+            client,
+            builder: crate::ccr::CcrFollowInfo::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ccr Follow Info API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ccr::CcrFollowStatsParts;
+#[doc = "Builder for the [Ccr Follow Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-get-follow-stats.html)\n\nRetrieves follower stats. return shard-level stats about the following tasks associated with each shard for the specified indices."]
+#[derive(Clone, Debug)]
+pub struct CcrFollowStats<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ccr::CcrFollowStats<'a, 'b>,
+}
+impl<'a, 'b> CcrFollowStats<'a, 'b> {
+    #[doc = "Creates a new instance of [CcrFollowStats] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CcrFollowStatsParts<'b>) -> Self {
+        CcrFollowStats {
+            client,
+            builder: crate::ccr::CcrFollowStats::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ccr Follow Stats API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ccr::CcrForgetFollowerParts;
+#[doc = "Builder for the [Ccr Forget Follower API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-post-forget-follower.html)\n\nRemoves the follower retention leases from the leader."]
+#[derive(Clone, Debug)]
+pub struct CcrForgetFollower<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ccr::CcrForgetFollower<'a, 'b, B>,
+}
+impl<'a, 'b, B> CcrForgetFollower<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [CcrForgetFollower] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CcrForgetFollowerParts<'b>) -> Self {
+        CcrForgetFollower {
+            client,
+            builder: crate::ccr::CcrForgetFollower::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> CcrForgetFollower<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        CcrForgetFollower {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ccr Forget Follower API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ccr::CcrGetAutoFollowPatternParts;
+#[doc = "Builder for the [Ccr Get Auto Follow Pattern API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-get-auto-follow-pattern.html)\n\nGets configured auto-follow patterns. Returns the specified auto-follow pattern collection."]
+#[derive(Clone, Debug)]
+pub struct CcrGetAutoFollowPattern<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ccr::CcrGetAutoFollowPattern<'a, 'b>,
+}
+impl<'a, 'b> CcrGetAutoFollowPattern<'a, 'b> {
+    #[doc = "Creates a new instance of [CcrGetAutoFollowPattern] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CcrGetAutoFollowPatternParts<'b>) -> Self {
+        CcrGetAutoFollowPattern {
+            client,
+            builder: crate::ccr::CcrGetAutoFollowPattern::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ccr Get Auto Follow Pattern API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ccr::CcrPauseAutoFollowPatternParts;
+#[doc = "Builder for the [Ccr Pause Auto Follow Pattern API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-pause-auto-follow-pattern.html)\n\nPauses an auto-follow pattern"]
+#[derive(Clone, Debug)]
+pub struct CcrPauseAutoFollowPattern<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ccr::CcrPauseAutoFollowPattern<'a, 'b, B>,
+}
+impl<'a, 'b, B> CcrPauseAutoFollowPattern<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [CcrPauseAutoFollowPattern] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CcrPauseAutoFollowPatternParts<'b>) -> Self {
+        CcrPauseAutoFollowPattern {
+            client,
+            builder: crate::ccr::CcrPauseAutoFollowPattern::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> CcrPauseAutoFollowPattern<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        CcrPauseAutoFollowPattern {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ccr Pause Auto Follow Pattern API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ccr::CcrPauseFollowParts;
+#[doc = "Builder for the [Ccr Pause Follow API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-post-pause-follow.html)\n\nPauses a follower index. The follower index will not fetch any additional operations from the leader index."]
+#[derive(Clone, Debug)]
+pub struct CcrPauseFollow<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ccr::CcrPauseFollow<'a, 'b, B>,
+}
+impl<'a, 'b, B> CcrPauseFollow<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [CcrPauseFollow] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CcrPauseFollowParts<'b>) -> Self {
+        CcrPauseFollow {
+            client,
+            builder: crate::ccr::CcrPauseFollow::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> CcrPauseFollow<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        CcrPauseFollow {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ccr Pause Follow API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ccr::CcrPutAutoFollowPatternParts;
+#[doc = "Builder for the [Ccr Put Auto Follow Pattern API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-put-auto-follow-pattern.html)\n\nCreates a new named collection of auto-follow patterns against a specified remote cluster. Newly created indices on the remote cluster matching any of the specified patterns will be automatically configured as follower indices."]
+#[derive(Clone, Debug)]
+pub struct CcrPutAutoFollowPattern<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ccr::CcrPutAutoFollowPattern<'a, 'b, B>,
+}
+impl<'a, 'b, B> CcrPutAutoFollowPattern<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [CcrPutAutoFollowPattern] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CcrPutAutoFollowPatternParts<'b>) -> Self {
+        CcrPutAutoFollowPattern {
+            client,
+            builder: crate::ccr::CcrPutAutoFollowPattern::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> CcrPutAutoFollowPattern<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        CcrPutAutoFollowPattern {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ccr Put Auto Follow Pattern API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ccr::CcrResumeAutoFollowPatternParts;
+#[doc = "Builder for the [Ccr Resume Auto Follow Pattern API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-resume-auto-follow-pattern.html)\n\nResumes an auto-follow pattern that has been paused"]
+#[derive(Clone, Debug)]
+pub struct CcrResumeAutoFollowPattern<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ccr::CcrResumeAutoFollowPattern<'a, 'b, B>,
+}
+impl<'a, 'b, B> CcrResumeAutoFollowPattern<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [CcrResumeAutoFollowPattern] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CcrResumeAutoFollowPatternParts<'b>) -> Self {
+        CcrResumeAutoFollowPattern {
+            client,
+            builder: crate::ccr::CcrResumeAutoFollowPattern::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> CcrResumeAutoFollowPattern<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        CcrResumeAutoFollowPattern {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ccr Resume Auto Follow Pattern API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ccr::CcrResumeFollowParts;
+#[doc = "Builder for the [Ccr Resume Follow API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-post-resume-follow.html)\n\nResumes a follower index that has been paused"]
+#[derive(Clone, Debug)]
+pub struct CcrResumeFollow<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ccr::CcrResumeFollow<'a, 'b, B>,
+}
+impl<'a, 'b, B> CcrResumeFollow<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [CcrResumeFollow] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CcrResumeFollowParts<'b>) -> Self {
+        CcrResumeFollow {
+            client,
+            builder: crate::ccr::CcrResumeFollow::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> CcrResumeFollow<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        CcrResumeFollow {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ccr Resume Follow API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ccr::CcrStatsParts;
+#[doc = "Builder for the [Ccr Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-get-stats.html)\n\nGets all stats related to cross-cluster replication."]
+#[derive(Clone, Debug)]
+pub struct CcrStats<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ccr::CcrStats<'a, 'b>,
+}
+impl<'a, 'b> CcrStats<'a, 'b> {
+    #[doc = "Creates a new instance of [CcrStats]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        CcrStats {
+            client,
+            builder: crate::ccr::CcrStats::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ccr Stats API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ccr::CcrUnfollowParts;
+#[doc = "Builder for the [Ccr Unfollow API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-post-unfollow.html)\n\nStops the following task associated with a follower index and removes index metadata and settings associated with cross-cluster replication."]
+#[derive(Clone, Debug)]
+pub struct CcrUnfollow<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ccr::CcrUnfollow<'a, 'b, B>,
+}
+impl<'a, 'b, B> CcrUnfollow<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [CcrUnfollow] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CcrUnfollowParts<'b>) -> Self {
+        CcrUnfollow {
+            client,
+            builder: crate::ccr::CcrUnfollow::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> CcrUnfollow<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        CcrUnfollow {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ccr Unfollow API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[doc = "Namespace client for Cross Cluster Replication APIs"]
+pub struct Ccr<'a> {
+    client: &'a Elasticsearch,
+}
+impl<'a> Ccr<'a> {
+    #[doc = "Creates a new instance of [Ccr]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        Self { client }
+    }
+    pub fn transport(&self) -> &Transport {
+        self.client.transport()
+    }
+    #[doc = "[Ccr Delete Auto Follow Pattern API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-delete-auto-follow-pattern.html)\n\nDeletes auto-follow patterns."]
+    pub fn delete_auto_follow_pattern<'b>(
+        &'a self,
+        parts: CcrDeleteAutoFollowPatternParts<'b>,
+    ) -> CcrDeleteAutoFollowPattern<'a, 'b> {
+        CcrDeleteAutoFollowPattern::new(self.client, parts)
+    }
+    #[doc = "[Ccr Follow API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-put-follow.html)\n\nCreates a new follower index configured to follow the referenced leader index."]
+    pub fn follow<'b>(&'a self, parts: CcrFollowParts<'b>) -> CcrFollow<'a, 'b, ()> {
+        CcrFollow::new(self.client, parts)
+    }
+    #[doc = "[Ccr Follow Info API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-get-follow-info.html)\n\nRetrieves information about all follower indices, including parameters and status for each follower index"]
+    pub fn follow_info<'b>(&'a self, parts: CcrFollowInfoParts<'b>) -> CcrFollowInfo<'a, 'b> {
+        CcrFollowInfo::new(self.client, parts)
+    }
+    #[doc = "[Ccr Follow Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-get-follow-stats.html)\n\nRetrieves follower stats. return shard-level stats about the following tasks associated with each shard for the specified indices."]
+    pub fn follow_stats<'b>(&'a self, parts: CcrFollowStatsParts<'b>) -> CcrFollowStats<'a, 'b> {
+        CcrFollowStats::new(self.client, parts)
+    }
+    #[doc = "[Ccr Forget Follower API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-post-forget-follower.html)\n\nRemoves the follower retention leases from the leader."]
+    pub fn forget_follower<'b>(
+        &'a self,
+        parts: CcrForgetFollowerParts<'b>,
+    ) -> CcrForgetFollower<'a, 'b, ()> {
+        CcrForgetFollower::new(self.client, parts)
+    }
+    #[doc = "[Ccr Get Auto Follow Pattern API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-get-auto-follow-pattern.html)\n\nGets configured auto-follow patterns. Returns the specified auto-follow pattern collection."]
+    pub fn get_auto_follow_pattern<'b>(
+        &'a self,
+        parts: CcrGetAutoFollowPatternParts<'b>,
+    ) -> CcrGetAutoFollowPattern<'a, 'b> {
+        CcrGetAutoFollowPattern::new(self.client, parts)
+    }
+    #[doc = "[Ccr Pause Auto Follow Pattern API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-pause-auto-follow-pattern.html)\n\nPauses an auto-follow pattern"]
+    pub fn pause_auto_follow_pattern<'b>(
+        &'a self,
+        parts: CcrPauseAutoFollowPatternParts<'b>,
+    ) -> CcrPauseAutoFollowPattern<'a, 'b, ()> {
+        CcrPauseAutoFollowPattern::new(self.client, parts)
+    }
+    #[doc = "[Ccr Pause Follow API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-post-pause-follow.html)\n\nPauses a follower index. The follower index will not fetch any additional operations from the leader index."]
+    pub fn pause_follow<'b>(
+        &'a self,
+        parts: CcrPauseFollowParts<'b>,
+    ) -> CcrPauseFollow<'a, 'b, ()> {
+        CcrPauseFollow::new(self.client, parts)
+    }
+    #[doc = "[Ccr Put Auto Follow Pattern API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-put-auto-follow-pattern.html)\n\nCreates a new named collection of auto-follow patterns against a specified remote cluster. Newly created indices on the remote cluster matching any of the specified patterns will be automatically configured as follower indices."]
+    pub fn put_auto_follow_pattern<'b>(
+        &'a self,
+        parts: CcrPutAutoFollowPatternParts<'b>,
+    ) -> CcrPutAutoFollowPattern<'a, 'b, ()> {
+        CcrPutAutoFollowPattern::new(self.client, parts)
+    }
+    #[doc = "[Ccr Resume Auto Follow Pattern API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-resume-auto-follow-pattern.html)\n\nResumes an auto-follow pattern that has been paused"]
+    pub fn resume_auto_follow_pattern<'b>(
+        &'a self,
+        parts: CcrResumeAutoFollowPatternParts<'b>,
+    ) -> CcrResumeAutoFollowPattern<'a, 'b, ()> {
+        CcrResumeAutoFollowPattern::new(self.client, parts)
+    }
+    #[doc = "[Ccr Resume Follow API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-post-resume-follow.html)\n\nResumes a follower index that has been paused"]
+    pub fn resume_follow<'b>(
+        &'a self,
+        parts: CcrResumeFollowParts<'b>,
+    ) -> CcrResumeFollow<'a, 'b, ()> {
+        CcrResumeFollow::new(self.client, parts)
+    }
+    #[doc = "[Ccr Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-get-stats.html)\n\nGets all stats related to cross-cluster replication."]
+    pub fn stats<'b>(&'a self) -> CcrStats<'a, 'b> {
+        CcrStats::new(self.client)
+    }
+    #[doc = "[Ccr Unfollow API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ccr-post-unfollow.html)\n\nStops the following task associated with a follower index and removes index metadata and settings associated with cross-cluster replication."]
+    pub fn unfollow<'b>(&'a self, parts: CcrUnfollowParts<'b>) -> CcrUnfollow<'a, 'b, ()> {
+        CcrUnfollow::new(self.client, parts)
+    }
+}
+impl Elasticsearch {
+    #[doc = "Creates a namespace client for Cross Cluster Replication APIs"]
+    pub fn ccr(&self) -> Ccr {
+        Ccr::new(self)
+    }
+}
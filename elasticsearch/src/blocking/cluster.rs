@@ -0,0 +1,1363 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+// -----------------------------------------------
+// This file is generated, Please do not edit it manually.
+// Run the following in the root of the repo to regenerate:
+//
+// cargo make generate-api
+// -----------------------------------------------
+
+#![allow(unused_imports)]
+pub use crate::cluster::ClusterAllocationExplainParts;
+use crate::{
+    blocking::{Elasticsearch, Response},
+    error::Error,
+    http::{
+        headers::{HeaderName, HeaderValue},
+        request::{Body, JsonBody, NdBody},
+        transport::{NodeSelector, Transport},
+    },
+    params::*,
+};
+use serde::Serialize;
+use std::time::Duration;
+#[doc = "Builder for the [Cluster Allocation Explain API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-allocation-explain.html)\n\nProvides explanations for shard allocations in the cluster."]
+#[derive(Clone, Debug)]
+pub struct ClusterAllocationExplain<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::cluster::ClusterAllocationExplain<'a, 'b, B>,
+}
+impl<'a, 'b, B> ClusterAllocationExplain<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [ClusterAllocationExplain]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        ClusterAllocationExplain {
+            client,
+            builder: crate::cluster::ClusterAllocationExplain::new(client.transport()),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> ClusterAllocationExplain<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        ClusterAllocationExplain {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Return information about disk usage and shard sizes (default: false)"]
+    pub fn include_disk_info(mut self, include_disk_info: bool) -> Self {
+        self.builder = self.builder.include_disk_info(include_disk_info);
+        self
+    }
+    #[doc = "Return 'YES' decisions in explanation (default: false)"]
+    pub fn include_yes_decisions(mut self, include_yes_decisions: bool) -> Self {
+        self.builder = self.builder.include_yes_decisions(include_yes_decisions);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cluster Allocation Explain API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cluster::ClusterDeleteComponentTemplateParts;
+#[doc = "Builder for the [Cluster Delete Component Template API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/indices-component-template.html)\n\nDeletes a component template"]
+#[derive(Clone, Debug)]
+pub struct ClusterDeleteComponentTemplate<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cluster::ClusterDeleteComponentTemplate<'a, 'b>,
+}
+impl<'a, 'b> ClusterDeleteComponentTemplate<'a, 'b> {
+    #[doc = "Creates a new instance of [ClusterDeleteComponentTemplate] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: ClusterDeleteComponentTemplateParts<'b>) -> Self {
+        ClusterDeleteComponentTemplate {
+            client,
+            builder: crate::cluster::ClusterDeleteComponentTemplate::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Specify timeout for connection to master"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cluster Delete Component Template API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cluster::ClusterDeleteVotingConfigExclusionsParts;
+#[doc = "Builder for the [Cluster Delete Voting Config Exclusions API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/voting-config-exclusions.html)\n\nClears cluster voting config exclusions."]
+#[derive(Clone, Debug)]
+pub struct ClusterDeleteVotingConfigExclusions<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cluster::ClusterDeleteVotingConfigExclusions<'a, 'b>,
+}
+impl<'a, 'b> ClusterDeleteVotingConfigExclusions<'a, 'b> {
+    #[doc = "Creates a new instance of [ClusterDeleteVotingConfigExclusions]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        ClusterDeleteVotingConfigExclusions {
+            client,
+            builder: crate::cluster::ClusterDeleteVotingConfigExclusions::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Specifies whether to wait for all excluded nodes to be removed from the cluster before clearing the voting configuration exclusions list."]
+    pub fn wait_for_removal(mut self, wait_for_removal: bool) -> Self {
+        self.builder = self.builder.wait_for_removal(wait_for_removal);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cluster Delete Voting Config Exclusions API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cluster::ClusterExistsComponentTemplateParts;
+#[doc = "Builder for the [Cluster Exists Component Template API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/indices-component-template.html)\n\nReturns information about whether a particular component template exist"]
+#[derive(Clone, Debug)]
+pub struct ClusterExistsComponentTemplate<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cluster::ClusterExistsComponentTemplate<'a, 'b>,
+}
+impl<'a, 'b> ClusterExistsComponentTemplate<'a, 'b> {
+    #[doc = "Creates a new instance of [ClusterExistsComponentTemplate] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: ClusterExistsComponentTemplateParts<'b>) -> Self {
+        ClusterExistsComponentTemplate {
+            client,
+            builder: crate::cluster::ClusterExistsComponentTemplate::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node (default: false)"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cluster Exists Component Template API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cluster::ClusterGetComponentTemplateParts;
+#[doc = "Builder for the [Cluster Get Component Template API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/indices-component-template.html)\n\nReturns one or more component templates"]
+#[derive(Clone, Debug)]
+pub struct ClusterGetComponentTemplate<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cluster::ClusterGetComponentTemplate<'a, 'b>,
+}
+impl<'a, 'b> ClusterGetComponentTemplate<'a, 'b> {
+    #[doc = "Creates a new instance of [ClusterGetComponentTemplate] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: ClusterGetComponentTemplateParts<'b>) -> Self {
+        ClusterGetComponentTemplate {
+            client,
+            builder: crate::cluster::ClusterGetComponentTemplate::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node (default: false)"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cluster Get Component Template API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cluster::ClusterGetSettingsParts;
+#[doc = "Builder for the [Cluster Get Settings API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-get-settings.html)\n\nReturns cluster settings."]
+#[derive(Clone, Debug)]
+pub struct ClusterGetSettings<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cluster::ClusterGetSettings<'a, 'b>,
+}
+impl<'a, 'b> ClusterGetSettings<'a, 'b> {
+    #[doc = "Creates a new instance of [ClusterGetSettings]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        ClusterGetSettings {
+            client,
+            builder: crate::cluster::ClusterGetSettings::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Return settings in flat format (default: false)"]
+    pub fn flat_settings(mut self, flat_settings: bool) -> Self {
+        self.builder = self.builder.flat_settings(flat_settings);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Whether to return all default clusters setting."]
+    pub fn include_defaults(mut self, include_defaults: bool) -> Self {
+        self.builder = self.builder.include_defaults(include_defaults);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cluster Get Settings API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cluster::ClusterHealthParts;
+#[doc = "Builder for the [Cluster Health API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-health.html)\n\nReturns basic information about the health of the cluster."]
+#[derive(Clone, Debug)]
+pub struct ClusterHealth<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cluster::ClusterHealth<'a, 'b>,
+}
+impl<'a, 'b> ClusterHealth<'a, 'b> {
+    #[doc = "Creates a new instance of [ClusterHealth] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: ClusterHealthParts<'b>) -> Self {
+        ClusterHealth {
+            client,
+            builder: crate::cluster::ClusterHealth::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether to expand wildcard expression to concrete indices that are open, closed or both."]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Specify the level of detail for returned information"]
+    pub fn level(mut self, level: Level) -> Self {
+        self.builder = self.builder.level(level);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node (default: false)"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Wait until the specified number of shards is active"]
+    pub fn wait_for_active_shards(mut self, wait_for_active_shards: &'b str) -> Self {
+        self.builder = self.builder.wait_for_active_shards(wait_for_active_shards);
+        self
+    }
+    #[doc = "Wait until all currently queued events with the given priority are processed"]
+    pub fn wait_for_events(mut self, wait_for_events: WaitForEvents) -> Self {
+        self.builder = self.builder.wait_for_events(wait_for_events);
+        self
+    }
+    #[doc = "Whether to wait until there are no initializing shards in the cluster"]
+    pub fn wait_for_no_initializing_shards(
+        mut self,
+        wait_for_no_initializing_shards: bool,
+    ) -> Self {
+        self.builder = self
+            .builder
+            .wait_for_no_initializing_shards(wait_for_no_initializing_shards);
+        self
+    }
+    #[doc = "Whether to wait until there are no relocating shards in the cluster"]
+    pub fn wait_for_no_relocating_shards(mut self, wait_for_no_relocating_shards: bool) -> Self {
+        self.builder = self
+            .builder
+            .wait_for_no_relocating_shards(wait_for_no_relocating_shards);
+        self
+    }
+    #[doc = "Wait until the specified number of nodes is available"]
+    pub fn wait_for_nodes(mut self, wait_for_nodes: &'b str) -> Self {
+        self.builder = self.builder.wait_for_nodes(wait_for_nodes);
+        self
+    }
+    #[doc = "Wait until cluster is in a specific state"]
+    pub fn wait_for_status(mut self, wait_for_status: WaitForStatus) -> Self {
+        self.builder = self.builder.wait_for_status(wait_for_status);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cluster Health API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cluster::ClusterPendingTasksParts;
+#[doc = "Builder for the [Cluster Pending Tasks API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-pending.html)\n\nReturns a list of any cluster-level changes (e.g. create index, update mapping,\nallocate or fail shard) which have not yet been executed."]
+#[derive(Clone, Debug)]
+pub struct ClusterPendingTasks<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cluster::ClusterPendingTasks<'a, 'b>,
+}
+impl<'a, 'b> ClusterPendingTasks<'a, 'b> {
+    #[doc = "Creates a new instance of [ClusterPendingTasks]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        ClusterPendingTasks {
+            client,
+            builder: crate::cluster::ClusterPendingTasks::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node (default: false)"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Specify timeout for connection to master"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cluster Pending Tasks API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cluster::ClusterPostVotingConfigExclusionsParts;
+#[doc = "Builder for the [Cluster Post Voting Config Exclusions API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/voting-config-exclusions.html)\n\nUpdates the cluster voting config exclusions by node ids or node names."]
+#[derive(Clone, Debug)]
+pub struct ClusterPostVotingConfigExclusions<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::cluster::ClusterPostVotingConfigExclusions<'a, 'b, B>,
+}
+impl<'a, 'b, B> ClusterPostVotingConfigExclusions<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [ClusterPostVotingConfigExclusions]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        ClusterPostVotingConfigExclusions {
+            client,
+            builder: crate::cluster::ClusterPostVotingConfigExclusions::new(client.transport()),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> ClusterPostVotingConfigExclusions<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        ClusterPostVotingConfigExclusions {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "A comma-separated list of the persistent ids of the nodes to exclude from the voting configuration. If specified, you may not also specify ?node_names."]
+    pub fn node_ids(mut self, node_ids: &'b str) -> Self {
+        self.builder = self.builder.node_ids(node_ids);
+        self
+    }
+    #[doc = "A comma-separated list of the names of the nodes to exclude from the voting configuration. If specified, you may not also specify ?node_ids."]
+    pub fn node_names(mut self, node_names: &'b str) -> Self {
+        self.builder = self.builder.node_names(node_names);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cluster Post Voting Config Exclusions API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cluster::ClusterPutComponentTemplateParts;
+#[doc = "Builder for the [Cluster Put Component Template API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/indices-component-template.html)\n\nCreates or updates a component template"]
+#[derive(Clone, Debug)]
+pub struct ClusterPutComponentTemplate<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::cluster::ClusterPutComponentTemplate<'a, 'b, B>,
+}
+impl<'a, 'b, B> ClusterPutComponentTemplate<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [ClusterPutComponentTemplate] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: ClusterPutComponentTemplateParts<'b>) -> Self {
+        ClusterPutComponentTemplate {
+            client,
+            builder: crate::cluster::ClusterPutComponentTemplate::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> ClusterPutComponentTemplate<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        ClusterPutComponentTemplate {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Whether the index template should only be added if new or can also replace an existing one"]
+    pub fn create(mut self, create: bool) -> Self {
+        self.builder = self.builder.create(create);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Specify timeout for connection to master"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cluster Put Component Template API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cluster::ClusterPutSettingsParts;
+#[doc = "Builder for the [Cluster Put Settings API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-update-settings.html)\n\nUpdates the cluster settings."]
+#[derive(Clone, Debug)]
+pub struct ClusterPutSettings<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::cluster::ClusterPutSettings<'a, 'b, B>,
+}
+impl<'a, 'b, B> ClusterPutSettings<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [ClusterPutSettings]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        ClusterPutSettings {
+            client,
+            builder: crate::cluster::ClusterPutSettings::new(client.transport()),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> ClusterPutSettings<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        ClusterPutSettings {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Return settings in flat format (default: false)"]
+    pub fn flat_settings(mut self, flat_settings: bool) -> Self {
+        self.builder = self.builder.flat_settings(flat_settings);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cluster Put Settings API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cluster::ClusterRemoteInfoParts;
+#[doc = "Builder for the [Cluster Remote Info API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-remote-info.html)\n\nReturns the information about configured remote clusters."]
+#[derive(Clone, Debug)]
+pub struct ClusterRemoteInfo<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cluster::ClusterRemoteInfo<'a, 'b>,
+}
+impl<'a, 'b> ClusterRemoteInfo<'a, 'b> {
+    #[doc = "Creates a new instance of [ClusterRemoteInfo]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        ClusterRemoteInfo {
+            client,
+            builder: crate::cluster::ClusterRemoteInfo::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cluster Remote Info API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cluster::ClusterRerouteParts;
+#[doc = "Builder for the [Cluster Reroute API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-reroute.html)\n\nAllows to manually change the allocation of individual shards in the cluster."]
+#[derive(Clone, Debug)]
+pub struct ClusterReroute<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::cluster::ClusterReroute<'a, 'b, B>,
+}
+impl<'a, 'b, B> ClusterReroute<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [ClusterReroute]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        ClusterReroute {
+            client,
+            builder: crate::cluster::ClusterReroute::new(client.transport()),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> ClusterReroute<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        ClusterReroute {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Simulate the operation only and return the resulting state"]
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.builder = self.builder.dry_run(dry_run);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Return an explanation of why the commands can or cannot be executed"]
+    pub fn explain(mut self, explain: bool) -> Self {
+        self.builder = self.builder.explain(explain);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Explicit operation timeout for connection to master node"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Limit the information returned to the specified metrics. Defaults to all but metadata"]
+    pub fn metric(mut self, metric: &'b [&'b str]) -> Self {
+        self.builder = self.builder.metric(metric);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Retries allocation of shards that are blocked due to too many subsequent allocation failures"]
+    pub fn retry_failed(mut self, retry_failed: bool) -> Self {
+        self.builder = self.builder.retry_failed(retry_failed);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cluster Reroute API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cluster::ClusterStateParts;
+#[doc = "Builder for the [Cluster State API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-state.html)\n\nReturns a comprehensive information about the state of the cluster."]
+#[derive(Clone, Debug)]
+pub struct ClusterState<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cluster::ClusterState<'a, 'b>,
+}
+impl<'a, 'b> ClusterState<'a, 'b> {
+    #[doc = "Creates a new instance of [ClusterState] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: ClusterStateParts<'b>) -> Self {
+        ClusterState {
+            client,
+            builder: crate::cluster::ClusterState::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)"]
+    pub fn allow_no_indices(mut self, allow_no_indices: bool) -> Self {
+        self.builder = self.builder.allow_no_indices(allow_no_indices);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether to expand wildcard expression to concrete indices that are open, closed or both."]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Return settings in flat format (default: false)"]
+    pub fn flat_settings(mut self, flat_settings: bool) -> Self {
+        self.builder = self.builder.flat_settings(flat_settings);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Whether specified concrete indices should be ignored when unavailable (missing or closed)"]
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.builder = self.builder.ignore_unavailable(ignore_unavailable);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node (default: false)"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Specify timeout for connection to master"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Wait for the metadata version to be equal or greater than the specified metadata version"]
+    pub fn wait_for_metadata_version(mut self, wait_for_metadata_version: i64) -> Self {
+        self.builder = self
+            .builder
+            .wait_for_metadata_version(wait_for_metadata_version);
+        self
+    }
+    #[doc = "The maximum time to wait for wait_for_metadata_version before timing out"]
+    pub fn wait_for_timeout(mut self, wait_for_timeout: &'b str) -> Self {
+        self.builder = self.builder.wait_for_timeout(wait_for_timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cluster State API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::cluster::ClusterStatsParts;
+#[doc = "Builder for the [Cluster Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-stats.html)\n\nReturns high-level overview of cluster statistics."]
+#[derive(Clone, Debug)]
+pub struct ClusterStats<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::cluster::ClusterStats<'a, 'b>,
+}
+impl<'a, 'b> ClusterStats<'a, 'b> {
+    #[doc = "Creates a new instance of [ClusterStats] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: ClusterStatsParts<'b>) -> Self {
+        ClusterStats {
+            client,
+            builder: crate::cluster::ClusterStats::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Return settings in flat format (default: false)"]
+    pub fn flat_settings(mut self, flat_settings: bool) -> Self {
+        self.builder = self.builder.flat_settings(flat_settings);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Cluster Stats API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[doc = "Namespace client for Cluster APIs"]
+pub struct Cluster<'a> {
+    client: &'a Elasticsearch,
+}
+impl<'a> Cluster<'a> {
+    #[doc = "Creates a new instance of [Cluster]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        Self { client }
+    }
+    pub fn transport(&self) -> &Transport {
+        self.client.transport()
+    }
+    #[doc = "[Cluster Allocation Explain API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-allocation-explain.html)\n\nProvides explanations for shard allocations in the cluster."]
+    pub fn allocation_explain<'b>(&'a self) -> ClusterAllocationExplain<'a, 'b, ()> {
+        ClusterAllocationExplain::new(self.client)
+    }
+    #[doc = "[Cluster Delete Component Template API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/indices-component-template.html)\n\nDeletes a component template"]
+    pub fn delete_component_template<'b>(
+        &'a self,
+        parts: ClusterDeleteComponentTemplateParts<'b>,
+    ) -> ClusterDeleteComponentTemplate<'a, 'b> {
+        ClusterDeleteComponentTemplate::new(self.client, parts)
+    }
+    #[doc = "[Cluster Delete Voting Config Exclusions API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/voting-config-exclusions.html)\n\nClears cluster voting config exclusions."]
+    pub fn delete_voting_config_exclusions<'b>(
+        &'a self,
+    ) -> ClusterDeleteVotingConfigExclusions<'a, 'b> {
+        ClusterDeleteVotingConfigExclusions::new(self.client)
+    }
+    #[doc = "[Cluster Exists Component Template API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/indices-component-template.html)\n\nReturns information about whether a particular component template exist"]
+    pub fn exists_component_template<'b>(
+        &'a self,
+        parts: ClusterExistsComponentTemplateParts<'b>,
+    ) -> ClusterExistsComponentTemplate<'a, 'b> {
+        ClusterExistsComponentTemplate::new(self.client, parts)
+    }
+    #[doc = "[Cluster Get Component Template API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/indices-component-template.html)\n\nReturns one or more component templates"]
+    pub fn get_component_template<'b>(
+        &'a self,
+        parts: ClusterGetComponentTemplateParts<'b>,
+    ) -> ClusterGetComponentTemplate<'a, 'b> {
+        ClusterGetComponentTemplate::new(self.client, parts)
+    }
+    #[doc = "[Cluster Get Settings API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-get-settings.html)\n\nReturns cluster settings."]
+    pub fn get_settings<'b>(&'a self) -> ClusterGetSettings<'a, 'b> {
+        ClusterGetSettings::new(self.client)
+    }
+    #[doc = "[Cluster Health API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-health.html)\n\nReturns basic information about the health of the cluster."]
+    pub fn health<'b>(&'a self, parts: ClusterHealthParts<'b>) -> ClusterHealth<'a, 'b> {
+        ClusterHealth::new(self.client, parts)
+    }
+    #[doc = "[Cluster Pending Tasks API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-pending.html)\n\nReturns a list of any cluster-level changes (e.g. create index, update mapping,\nallocate or fail shard) which have not yet been executed."]
+    pub fn pending_tasks<'b>(&'a self) -> ClusterPendingTasks<'a, 'b> {
+        ClusterPendingTasks::new(self.client)
+    }
+    #[doc = "[Cluster Post Voting Config Exclusions API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/voting-config-exclusions.html)\n\nUpdates the cluster voting config exclusions by node ids or node names."]
+    pub fn post_voting_config_exclusions<'b>(
+        &'a self,
+    ) -> ClusterPostVotingConfigExclusions<'a, 'b, ()> {
+        ClusterPostVotingConfigExclusions::new(self.client)
+    }
+    #[doc = "[Cluster Put Component Template API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/indices-component-template.html)\n\nCreates or updates a component template"]
+    pub fn put_component_template<'b>(
+        &'a self,
+        parts: ClusterPutComponentTemplateParts<'b>,
+    ) -> ClusterPutComponentTemplate<'a, 'b, ()> {
+        ClusterPutComponentTemplate::new(self.client, parts)
+    }
+    #[doc = "[Cluster Put Settings API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-update-settings.html)\n\nUpdates the cluster settings."]
+    pub fn put_settings<'b>(&'a self) -> ClusterPutSettings<'a, 'b, ()> {
+        ClusterPutSettings::new(self.client)
+    }
+    #[doc = "[Cluster Remote Info API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-remote-info.html)\n\nReturns the information about configured remote clusters."]
+    pub fn remote_info<'b>(&'a self) -> ClusterRemoteInfo<'a, 'b> {
+        ClusterRemoteInfo::new(self.client)
+    }
+    #[doc = "[Cluster Reroute API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-reroute.html)\n\nAllows to manually change the allocation of individual shards in the cluster."]
+    pub fn reroute<'b>(&'a self) -> ClusterReroute<'a, 'b, ()> {
+        ClusterReroute::new(self.client)
+    }
+    #[doc = "[Cluster State API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-state.html)\n\nReturns a comprehensive information about the state of the cluster."]
+    pub fn state<'b>(&'a self, parts: ClusterStateParts<'b>) -> ClusterState<'a, 'b> {
+        ClusterState::new(self.client, parts)
+    }
+    #[doc = "[Cluster Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/cluster-stats.html)\n\nReturns high-level overview of cluster statistics."]
+    pub fn stats<'b>(&'a self, parts: ClusterStatsParts<'b>) -> ClusterStats<'a, 'b> {
+        ClusterStats::new(self.client, parts)
+    }
+}
+impl Elasticsearch {
+    #[doc = "Creates a namespace client for Cluster APIs"]
+    pub fn cluster(&self) -> Cluster {
+        Cluster::new(self)
+    }
+}
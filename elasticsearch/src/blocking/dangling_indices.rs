@@ -0,0 +1,303 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+// -----------------------------------------------
+// This file is generated, Please do not edit it manually.
+// Run the following in the root of the repo to regenerate:
+//
+// cargo make generate-api
+// -----------------------------------------------
+
+#![allow(unused_imports)]
+pub use crate::dangling_indices::DanglingIndicesDeleteDanglingIndexParts;
+use crate::{
+    blocking::{Elasticsearch, Response},
+    error::Error,
+    http::{
+        headers::{HeaderName, HeaderValue},
+        request::{Body, JsonBody, NdBody},
+        transport::{NodeSelector, Transport},
+    },
+    params::*,
+};
+use serde::Serialize;
+use std::time::Duration;
+#[doc = "Builder for the [Dangling Indices Delete Dangling Index API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/modules-gateway-dangling-indices.html)\n\nDeletes the specified dangling index"]
+#[derive(Clone, Debug)]
+pub struct DanglingIndicesDeleteDanglingIndex<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::dangling_indices::DanglingIndicesDeleteDanglingIndex<'a, 'b>,
+}
+impl<'a, 'b> DanglingIndicesDeleteDanglingIndex<'a, 'b> {
+    #[doc = "Creates a new instance of [DanglingIndicesDeleteDanglingIndex] with the specified API parts"]
+    pub fn new(
+        client: &'a Elasticsearch,
+        parts: DanglingIndicesDeleteDanglingIndexParts<'b>,
+    ) -> Self {
+        DanglingIndicesDeleteDanglingIndex {
+            client,
+            builder: crate::dangling_indices::DanglingIndicesDeleteDanglingIndex::new(
+                client.transport(),
+                parts,
+            ),
+        }
+    }
+    #[doc = "Must be set to true in order to delete the dangling index"]
+    pub fn accept_data_loss(mut self, accept_data_loss: bool) -> Self {
+        self.builder = self.builder.accept_data_loss(accept_data_loss);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Specify timeout for connection to master"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Dangling Indices Delete Dangling Index API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::dangling_indices::DanglingIndicesImportDanglingIndexParts;
+#[doc = "Builder for the [Dangling Indices Import Dangling Index API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/modules-gateway-dangling-indices.html)\n\nImports the specified dangling index"]
+#[derive(Clone, Debug)]
+pub struct DanglingIndicesImportDanglingIndex<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::dangling_indices::DanglingIndicesImportDanglingIndex<'a, 'b, B>,
+}
+impl<'a, 'b, B> DanglingIndicesImportDanglingIndex<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [DanglingIndicesImportDanglingIndex] with the specified API parts"]
+    pub fn new(
+        client: &'a Elasticsearch,
+        parts: DanglingIndicesImportDanglingIndexParts<'b>,
+    ) -> Self {
+        DanglingIndicesImportDanglingIndex {
+            client,
+            builder: crate::dangling_indices::DanglingIndicesImportDanglingIndex::new(
+                client.transport(),
+                parts,
+            ),
+        }
+    }
+    #[doc = "Must be set to true in order to import the dangling index"]
+    pub fn accept_data_loss(mut self, accept_data_loss: bool) -> Self {
+        self.builder = self.builder.accept_data_loss(accept_data_loss);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> DanglingIndicesImportDanglingIndex<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        DanglingIndicesImportDanglingIndex {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Specify timeout for connection to master"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Dangling Indices Import Dangling Index API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::dangling_indices::DanglingIndicesListDanglingIndicesParts;
+#[doc = "Builder for the [Dangling Indices List Dangling Indices API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/modules-gateway-dangling-indices.html)\n\nReturns all dangling indices."]
+#[derive(Clone, Debug)]
+pub struct DanglingIndicesListDanglingIndices<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::dangling_indices::DanglingIndicesListDanglingIndices<'a, 'b>,
+}
+impl<'a, 'b> DanglingIndicesListDanglingIndices<'a, 'b> {
+    #[doc = "Creates a new instance of [DanglingIndicesListDanglingIndices]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        DanglingIndicesListDanglingIndices {
+            client,
+            builder: crate::dangling_indices::DanglingIndicesListDanglingIndices::new(
+                client.transport(),
+            ),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Dangling Indices List Dangling Indices API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[doc = "Namespace client for DanglingIndices APIs"]
+pub struct DanglingIndices<'a> {
+    client: &'a Elasticsearch,
+}
+impl<'a> DanglingIndices<'a> {
+    #[doc = "Creates a new instance of [DanglingIndices]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        Self { client }
+    }
+    pub fn transport(&self) -> &Transport {
+        self.client.transport()
+    }
+    #[doc = "[Dangling Indices Delete Dangling Index API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/modules-gateway-dangling-indices.html)\n\nDeletes the specified dangling index"]
+    pub fn delete_dangling_index<'b>(
+        &'a self,
+        parts: DanglingIndicesDeleteDanglingIndexParts<'b>,
+    ) -> DanglingIndicesDeleteDanglingIndex<'a, 'b> {
+        DanglingIndicesDeleteDanglingIndex::new(self.client, parts)
+    }
+    #[doc = "[Dangling Indices Import Dangling Index API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/modules-gateway-dangling-indices.html)\n\nImports the specified dangling index"]
+    pub fn import_dangling_index<'b>(
+        &'a self,
+        parts: DanglingIndicesImportDanglingIndexParts<'b>,
+    ) -> DanglingIndicesImportDanglingIndex<'a, 'b, ()> {
+        DanglingIndicesImportDanglingIndex::new(self.client, parts)
+    }
+    #[doc = "[Dangling Indices List Dangling Indices API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/modules-gateway-dangling-indices.html)\n\nReturns all dangling indices."]
+    pub fn list_dangling_indices<'b>(&'a self) -> DanglingIndicesListDanglingIndices<'a, 'b> {
+        DanglingIndicesListDanglingIndices::new(self.client)
+    }
+}
+impl Elasticsearch {
+    #[doc = "Creates a namespace client for DanglingIndices APIs"]
+    pub fn dangling_indices(&self) -> DanglingIndices {
+        DanglingIndices::new(self)
+    }
+}
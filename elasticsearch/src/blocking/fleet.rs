@@ -0,0 +1,157 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+// -----------------------------------------------
+// This file is generated, Please do not edit it manually.
+// Run the following in the root of the repo to regenerate:
+//
+// cargo make generate-api
+// -----------------------------------------------
+
+#![cfg(feature = "experimental-apis")]
+#![doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+#![allow(unused_imports)]
+#[cfg(feature = "experimental-apis")]
+pub use crate::fleet::FleetGlobalCheckpointsParts;
+use crate::{
+    blocking::{Elasticsearch, Response},
+    error::Error,
+    http::{
+        headers::{HeaderName, HeaderValue},
+        request::{Body, JsonBody, NdBody},
+        transport::{NodeSelector, Transport},
+    },
+    params::*,
+};
+use serde::Serialize;
+use std::time::Duration;
+#[doc = "Builder for the Fleet Global Checkpoints API\n\nReturns the current global checkpoints for an index. This API is design for internal use by the fleet server project."]
+#[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+#[cfg(feature = "experimental-apis")]
+#[derive(Clone, Debug)]
+pub struct FleetGlobalCheckpoints<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::fleet::FleetGlobalCheckpoints<'a, 'b>,
+}
+#[cfg(feature = "experimental-apis")]
+impl<'a, 'b> FleetGlobalCheckpoints<'a, 'b> {
+    #[doc = "Creates a new instance of [FleetGlobalCheckpoints] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: FleetGlobalCheckpointsParts<'b>) -> Self {
+        FleetGlobalCheckpoints {
+            client,
+            builder: crate::fleet::FleetGlobalCheckpoints::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Comma separated list of checkpoints"]
+    pub fn checkpoints(mut self, checkpoints: &'b [&'b str]) -> Self {
+        self.builder = self.builder.checkpoints(checkpoints);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Timeout to wait for global checkpoint to advance"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Whether to wait for the global checkpoint to advance past the specified current checkpoints"]
+    pub fn wait_for_advance(mut self, wait_for_advance: bool) -> Self {
+        self.builder = self.builder.wait_for_advance(wait_for_advance);
+        self
+    }
+    #[doc = "Whether to wait for the target index to exist and all primary shards be active"]
+    pub fn wait_for_index(mut self, wait_for_index: bool) -> Self {
+        self.builder = self.builder.wait_for_index(wait_for_index);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Fleet Global Checkpoints API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[doc = "Namespace client for Fleet APIs"]
+#[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+#[cfg(feature = "experimental-apis")]
+pub struct Fleet<'a> {
+    client: &'a Elasticsearch,
+}
+#[cfg(feature = "experimental-apis")]
+impl<'a> Fleet<'a> {
+    #[doc = "Creates a new instance of [Fleet]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        Self { client }
+    }
+    pub fn transport(&self) -> &Transport {
+        self.client.transport()
+    }
+    #[doc = "Fleet Global Checkpoints API\n\nReturns the current global checkpoints for an index. This API is design for internal use by the fleet server project."]
+    #[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+    #[cfg(feature = "experimental-apis")]
+    pub fn global_checkpoints<'b>(
+        &'a self,
+        parts: FleetGlobalCheckpointsParts<'b>,
+    ) -> FleetGlobalCheckpoints<'a, 'b> {
+        FleetGlobalCheckpoints::new(self.client, parts)
+    }
+}
+#[cfg(feature = "experimental-apis")]
+impl Elasticsearch {
+    #[doc = "Creates a namespace client for Fleet APIs"]
+    pub fn fleet(&self) -> Fleet {
+        Fleet::new(self)
+    }
+}
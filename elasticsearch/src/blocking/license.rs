@@ -0,0 +1,575 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+// -----------------------------------------------
+// This file is generated, Please do not edit it manually.
+// Run the following in the root of the repo to regenerate:
+//
+// cargo make generate-api
+// -----------------------------------------------
+
+#![allow(unused_imports)]
+pub use crate::license::LicenseDeleteParts;
+use crate::{
+    blocking::{Elasticsearch, Response},
+    error::Error,
+    http::{
+        headers::{HeaderName, HeaderValue},
+        request::{Body, JsonBody, NdBody},
+        transport::{NodeSelector, Transport},
+    },
+    params::*,
+};
+use serde::Serialize;
+use std::time::Duration;
+#[doc = "Builder for the [License Delete API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/delete-license.html)\n\nDeletes licensing information for the cluster"]
+#[derive(Clone, Debug)]
+pub struct LicenseDelete<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::license::LicenseDelete<'a, 'b>,
+}
+impl<'a, 'b> LicenseDelete<'a, 'b> {
+    #[doc = "Creates a new instance of [LicenseDelete]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        LicenseDelete {
+            client,
+            builder: crate::license::LicenseDelete::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the License Delete API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::license::LicenseGetParts;
+#[doc = "Builder for the [License Get API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-license.html)\n\nRetrieves licensing information for the cluster"]
+#[derive(Clone, Debug)]
+pub struct LicenseGet<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::license::LicenseGet<'a, 'b>,
+}
+impl<'a, 'b> LicenseGet<'a, 'b> {
+    #[doc = "Creates a new instance of [LicenseGet]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        LicenseGet {
+            client,
+            builder: crate::license::LicenseGet::new(client.transport()),
+        }
+    }
+    #[doc = "Supported for backwards compatibility with 7.x. If this param is used it must be set to true"]
+    pub fn accept_enterprise(mut self, accept_enterprise: bool) -> Self {
+        self.builder = self.builder.accept_enterprise(accept_enterprise);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node (default: false)"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the License Get API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::license::LicenseGetBasicStatusParts;
+#[doc = "Builder for the [License Get Basic Status API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-basic-status.html)\n\nRetrieves information about the status of the basic license."]
+#[derive(Clone, Debug)]
+pub struct LicenseGetBasicStatus<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::license::LicenseGetBasicStatus<'a, 'b>,
+}
+impl<'a, 'b> LicenseGetBasicStatus<'a, 'b> {
+    #[doc = "Creates a new instance of [LicenseGetBasicStatus]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        LicenseGetBasicStatus {
+            client,
+            builder: crate::license::LicenseGetBasicStatus::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the License Get Basic Status API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::license::LicenseGetTrialStatusParts;
+#[doc = "Builder for the [License Get Trial Status API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-trial-status.html)\n\nRetrieves information about the status of the trial license."]
+#[derive(Clone, Debug)]
+pub struct LicenseGetTrialStatus<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::license::LicenseGetTrialStatus<'a, 'b>,
+}
+impl<'a, 'b> LicenseGetTrialStatus<'a, 'b> {
+    #[doc = "Creates a new instance of [LicenseGetTrialStatus]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        LicenseGetTrialStatus {
+            client,
+            builder: crate::license::LicenseGetTrialStatus::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the License Get Trial Status API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::license::LicensePostParts;
+#[doc = "Builder for the [License Post API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/update-license.html)\n\nUpdates the license for the cluster."]
+#[derive(Clone, Debug)]
+pub struct LicensePost<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::license::LicensePost<'a, 'b, B>,
+}
+impl<'a, 'b, B> LicensePost<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [LicensePost]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        LicensePost {
+            client,
+            builder: crate::license::LicensePost::new(client.transport()),
+        }
+    }
+    #[doc = "whether the user has acknowledged acknowledge messages (default: false)"]
+    pub fn acknowledge(mut self, acknowledge: bool) -> Self {
+        self.builder = self.builder.acknowledge(acknowledge);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> LicensePost<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        LicensePost {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the License Post API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::license::LicensePostStartBasicParts;
+#[doc = "Builder for the [License Post Start Basic API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/start-basic.html)\n\nStarts an indefinite basic license."]
+#[derive(Clone, Debug)]
+pub struct LicensePostStartBasic<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::license::LicensePostStartBasic<'a, 'b, B>,
+}
+impl<'a, 'b, B> LicensePostStartBasic<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [LicensePostStartBasic]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        LicensePostStartBasic {
+            client,
+            builder: crate::license::LicensePostStartBasic::new(client.transport()),
+        }
+    }
+    #[doc = "whether the user has acknowledged acknowledge messages (default: false)"]
+    pub fn acknowledge(mut self, acknowledge: bool) -> Self {
+        self.builder = self.builder.acknowledge(acknowledge);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> LicensePostStartBasic<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        LicensePostStartBasic {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the License Post Start Basic API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::license::LicensePostStartTrialParts;
+#[doc = "Builder for the [License Post Start Trial API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/start-trial.html)\n\nstarts a limited time trial license."]
+#[derive(Clone, Debug)]
+pub struct LicensePostStartTrial<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::license::LicensePostStartTrial<'a, 'b, B>,
+}
+impl<'a, 'b, B> LicensePostStartTrial<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [LicensePostStartTrial]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        LicensePostStartTrial {
+            client,
+            builder: crate::license::LicensePostStartTrial::new(client.transport()),
+        }
+    }
+    #[doc = "whether the user has acknowledged acknowledge messages (default: false)"]
+    pub fn acknowledge(mut self, acknowledge: bool) -> Self {
+        self.builder = self.builder.acknowledge(acknowledge);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> LicensePostStartTrial<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        LicensePostStartTrial {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The type of trial license to generate (default: \"trial\")"]
+    pub fn ty(mut self, ty: &'b str) -> Self {
+        self.builder = self.builder.ty(ty);
+        self
+    }
+    #[doc = "Creates a synchronous call to the License Post Start Trial API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[doc = "Namespace client for License APIs"]
+pub struct License<'a> {
+    client: &'a Elasticsearch,
+}
+impl<'a> License<'a> {
+    #[doc = "Creates a new instance of [License]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        Self { client }
+    }
+    pub fn transport(&self) -> &Transport {
+        self.client.transport()
+    }
+    #[doc = "[License Delete API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/delete-license.html)\n\nDeletes licensing information for the cluster"]
+    pub fn delete<'b>(&'a self) -> LicenseDelete<'a, 'b> {
+        LicenseDelete::new(self.client)
+    }
+    #[doc = "[License Get API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-license.html)\n\nRetrieves licensing information for the cluster"]
+    pub fn get<'b>(&'a self) -> LicenseGet<'a, 'b> {
+        LicenseGet::new(self.client)
+    }
+    #[doc = "[License Get Basic Status API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-basic-status.html)\n\nRetrieves information about the status of the basic license."]
+    pub fn get_basic_status<'b>(&'a self) -> LicenseGetBasicStatus<'a, 'b> {
+        LicenseGetBasicStatus::new(self.client)
+    }
+    #[doc = "[License Get Trial Status API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-trial-status.html)\n\nRetrieves information about the status of the trial license."]
+    pub fn get_trial_status<'b>(&'a self) -> LicenseGetTrialStatus<'a, 'b> {
+        LicenseGetTrialStatus::new(self.client)
+    }
+    #[doc = "[License Post API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/update-license.html)\n\nUpdates the license for the cluster."]
+    pub fn post<'b>(&'a self) -> LicensePost<'a, 'b, ()> {
+        LicensePost::new(self.client)
+    }
+    #[doc = "[License Post Start Basic API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/start-basic.html)\n\nStarts an indefinite basic license."]
+    pub fn post_start_basic<'b>(&'a self) -> LicensePostStartBasic<'a, 'b, ()> {
+        LicensePostStartBasic::new(self.client)
+    }
+    #[doc = "[License Post Start Trial API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/start-trial.html)\n\nstarts a limited time trial license."]
+    pub fn post_start_trial<'b>(&'a self) -> LicensePostStartTrial<'a, 'b, ()> {
+        LicensePostStartTrial::new(self.client)
+    }
+}
+impl Elasticsearch {
+    #[doc = "Creates a namespace client for License APIs"]
+    pub fn license(&self) -> License {
+        License::new(self)
+    }
+}
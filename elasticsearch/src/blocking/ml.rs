@@ -0,0 +1,5832 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+// -----------------------------------------------
+// This file is generated, Please do not edit it manually.
+// Run the following in the root of the repo to regenerate:
+//
+// cargo make generate-api
+// -----------------------------------------------
+
+#![allow(unused_imports)]
+pub use crate::ml::MlCloseJobParts;
+use crate::{
+    blocking::{Elasticsearch, Response},
+    error::Error,
+    http::{
+        headers::{HeaderName, HeaderValue},
+        request::{Body, JsonBody, NdBody},
+        transport::{NodeSelector, Transport},
+    },
+    params::*,
+};
+use serde::Serialize;
+use std::time::Duration;
+#[doc = "Builder for the [Ml Close Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-close-job.html)\n\nCloses one or more anomaly detection jobs. A job can be opened and closed multiple times throughout its lifecycle."]
+#[derive(Clone, Debug)]
+pub struct MlCloseJob<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlCloseJob<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlCloseJob<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlCloseJob] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlCloseJobParts<'b>) -> Self {
+        MlCloseJob {
+            client,
+            builder: crate::ml::MlCloseJob::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no jobs. (This includes `_all` string or when no jobs have been specified)"]
+    pub fn allow_no_jobs(mut self, allow_no_jobs: bool) -> Self {
+        self.builder = self.builder.allow_no_jobs(allow_no_jobs);
+        self
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no jobs. (This includes `_all` string or when no jobs have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlCloseJob<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlCloseJob {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "True if the job should be forcefully closed"]
+    pub fn force(mut self, force: bool) -> Self {
+        self.builder = self.builder.force(force);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Controls the time to wait until a job has closed. Default to 30 minutes"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Close Job API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlDeleteCalendarParts;
+#[doc = "Builder for the [Ml Delete Calendar API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-calendar.html)\n\nDeletes a calendar."]
+#[derive(Clone, Debug)]
+pub struct MlDeleteCalendar<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlDeleteCalendar<'a, 'b>,
+}
+impl<'a, 'b> MlDeleteCalendar<'a, 'b> {
+    #[doc = "Creates a new instance of [MlDeleteCalendar] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlDeleteCalendarParts<'b>) -> Self {
+        MlDeleteCalendar {
+            client,
+            builder: crate::ml::MlDeleteCalendar::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Delete Calendar API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlDeleteCalendarEventParts;
+#[doc = "Builder for the [Ml Delete Calendar Event API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-calendar-event.html)\n\nDeletes scheduled events from a calendar."]
+#[derive(Clone, Debug)]
+pub struct MlDeleteCalendarEvent<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlDeleteCalendarEvent<'a, 'b>,
+}
+impl<'a, 'b> MlDeleteCalendarEvent<'a, 'b> {
+    #[doc = "Creates a new instance of [MlDeleteCalendarEvent] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlDeleteCalendarEventParts<'b>) -> Self {
+        MlDeleteCalendarEvent {
+            client,
+            builder: crate::ml::MlDeleteCalendarEvent::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Delete Calendar Event API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlDeleteCalendarJobParts;
+#[doc = "Builder for the [Ml Delete Calendar Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-calendar-job.html)\n\nDeletes anomaly detection jobs from a calendar."]
+#[derive(Clone, Debug)]
+pub struct MlDeleteCalendarJob<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlDeleteCalendarJob<'a, 'b>,
+}
+impl<'a, 'b> MlDeleteCalendarJob<'a, 'b> {
+    #[doc = "Creates a new instance of [MlDeleteCalendarJob] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlDeleteCalendarJobParts<'b>) -> Self {
+        MlDeleteCalendarJob {
+            client,
+            builder: crate::ml::MlDeleteCalendarJob::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Delete Calendar Job API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlDeleteDataFrameAnalyticsParts;
+#[doc = "Builder for the [Ml Delete Data Frame Analytics API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/delete-dfanalytics.html)\n\nDeletes an existing data frame analytics job."]
+#[derive(Clone, Debug)]
+pub struct MlDeleteDataFrameAnalytics<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlDeleteDataFrameAnalytics<'a, 'b>,
+}
+impl<'a, 'b> MlDeleteDataFrameAnalytics<'a, 'b> {
+    #[doc = "Creates a new instance of [MlDeleteDataFrameAnalytics] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlDeleteDataFrameAnalyticsParts<'b>) -> Self {
+        MlDeleteDataFrameAnalytics {
+            client,
+            builder: crate::ml::MlDeleteDataFrameAnalytics::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "True if the job should be forcefully deleted"]
+    pub fn force(mut self, force: bool) -> Self {
+        self.builder = self.builder.force(force);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Controls the time to wait until a job is deleted. Defaults to 1 minute"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Delete Data Frame Analytics API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlDeleteDatafeedParts;
+#[doc = "Builder for the [Ml Delete Datafeed API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-datafeed.html)\n\nDeletes an existing datafeed."]
+#[derive(Clone, Debug)]
+pub struct MlDeleteDatafeed<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlDeleteDatafeed<'a, 'b>,
+}
+impl<'a, 'b> MlDeleteDatafeed<'a, 'b> {
+    #[doc = "Creates a new instance of [MlDeleteDatafeed] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlDeleteDatafeedParts<'b>) -> Self {
+        MlDeleteDatafeed {
+            client,
+            builder: crate::ml::MlDeleteDatafeed::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "True if the datafeed should be forcefully deleted"]
+    pub fn force(mut self, force: bool) -> Self {
+        self.builder = self.builder.force(force);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Delete Datafeed API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlDeleteExpiredDataParts;
+#[doc = "Builder for the [Ml Delete Expired Data API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-expired-data.html)\n\nDeletes expired and unused machine learning data."]
+#[derive(Clone, Debug)]
+pub struct MlDeleteExpiredData<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlDeleteExpiredData<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlDeleteExpiredData<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlDeleteExpiredData] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlDeleteExpiredDataParts<'b>) -> Self {
+        MlDeleteExpiredData {
+            client,
+            builder: crate::ml::MlDeleteExpiredData::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlDeleteExpiredData<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlDeleteExpiredData {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The desired requests per second for the deletion processes."]
+    pub fn requests_per_second(mut self, requests_per_second: i64) -> Self {
+        self.builder = self.builder.requests_per_second(requests_per_second);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "How long can the underlying delete processes run until they are canceled"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Delete Expired Data API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlDeleteFilterParts;
+#[doc = "Builder for the [Ml Delete Filter API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-filter.html)\n\nDeletes a filter."]
+#[derive(Clone, Debug)]
+pub struct MlDeleteFilter<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlDeleteFilter<'a, 'b>,
+}
+impl<'a, 'b> MlDeleteFilter<'a, 'b> {
+    #[doc = "Creates a new instance of [MlDeleteFilter] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlDeleteFilterParts<'b>) -> Self {
+        MlDeleteFilter {
+            client,
+            builder: crate::ml::MlDeleteFilter::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Delete Filter API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlDeleteForecastParts;
+#[doc = "Builder for the [Ml Delete Forecast API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-forecast.html)\n\nDeletes forecasts from a machine learning job."]
+#[derive(Clone, Debug)]
+pub struct MlDeleteForecast<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlDeleteForecast<'a, 'b>,
+}
+impl<'a, 'b> MlDeleteForecast<'a, 'b> {
+    #[doc = "Creates a new instance of [MlDeleteForecast] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlDeleteForecastParts<'b>) -> Self {
+        MlDeleteForecast {
+            client,
+            builder: crate::ml::MlDeleteForecast::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if `_all` matches no forecasts"]
+    pub fn allow_no_forecasts(mut self, allow_no_forecasts: bool) -> Self {
+        self.builder = self.builder.allow_no_forecasts(allow_no_forecasts);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Controls the time to wait until the forecast(s) are deleted. Default to 30 seconds"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Delete Forecast API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlDeleteJobParts;
+#[doc = "Builder for the [Ml Delete Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-job.html)\n\nDeletes an existing anomaly detection job."]
+#[derive(Clone, Debug)]
+pub struct MlDeleteJob<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlDeleteJob<'a, 'b>,
+}
+impl<'a, 'b> MlDeleteJob<'a, 'b> {
+    #[doc = "Creates a new instance of [MlDeleteJob] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlDeleteJobParts<'b>) -> Self {
+        MlDeleteJob {
+            client,
+            builder: crate::ml::MlDeleteJob::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "True if the job should be forcefully deleted"]
+    pub fn force(mut self, force: bool) -> Self {
+        self.builder = self.builder.force(force);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Should this request wait until the operation has completed before returning"]
+    pub fn wait_for_completion(mut self, wait_for_completion: bool) -> Self {
+        self.builder = self.builder.wait_for_completion(wait_for_completion);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Delete Job API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlDeleteModelSnapshotParts;
+#[doc = "Builder for the [Ml Delete Model Snapshot API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-snapshot.html)\n\nDeletes an existing model snapshot."]
+#[derive(Clone, Debug)]
+pub struct MlDeleteModelSnapshot<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlDeleteModelSnapshot<'a, 'b>,
+}
+impl<'a, 'b> MlDeleteModelSnapshot<'a, 'b> {
+    #[doc = "Creates a new instance of [MlDeleteModelSnapshot] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlDeleteModelSnapshotParts<'b>) -> Self {
+        MlDeleteModelSnapshot {
+            client,
+            builder: crate::ml::MlDeleteModelSnapshot::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Delete Model Snapshot API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlDeleteTrainedModelParts;
+#[doc = "Builder for the [Ml Delete Trained Model API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/delete-trained-models.html)\n\nDeletes an existing trained inference model that is currently not referenced by an ingest pipeline."]
+#[derive(Clone, Debug)]
+pub struct MlDeleteTrainedModel<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlDeleteTrainedModel<'a, 'b>,
+}
+impl<'a, 'b> MlDeleteTrainedModel<'a, 'b> {
+    #[doc = "Creates a new instance of [MlDeleteTrainedModel] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlDeleteTrainedModelParts<'b>) -> Self {
+        MlDeleteTrainedModel {
+            client,
+            builder: crate::ml::MlDeleteTrainedModel::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Delete Trained Model API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlDeleteTrainedModelAliasParts;
+#[doc = "Builder for the [Ml Delete Trained Model Alias API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/delete-trained-models-aliases.html)\n\nDeletes a model alias that refers to the trained model"]
+#[derive(Clone, Debug)]
+pub struct MlDeleteTrainedModelAlias<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlDeleteTrainedModelAlias<'a, 'b>,
+}
+impl<'a, 'b> MlDeleteTrainedModelAlias<'a, 'b> {
+    #[doc = "Creates a new instance of [MlDeleteTrainedModelAlias] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlDeleteTrainedModelAliasParts<'b>) -> Self {
+        MlDeleteTrainedModelAlias {
+            client,
+            builder: crate::ml::MlDeleteTrainedModelAlias::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Delete Trained Model Alias API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlEstimateModelMemoryParts;
+#[doc = "Builder for the [Ml Estimate Model Memory API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-apis.html)\n\nEstimates the model memory"]
+#[derive(Clone, Debug)]
+pub struct MlEstimateModelMemory<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlEstimateModelMemory<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlEstimateModelMemory<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlEstimateModelMemory]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        MlEstimateModelMemory {
+            client,
+            builder: crate::ml::MlEstimateModelMemory::new(client.transport()),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlEstimateModelMemory<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlEstimateModelMemory {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Estimate Model Memory API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlEvaluateDataFrameParts;
+#[doc = "Builder for the [Ml Evaluate Data Frame API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/evaluate-dfanalytics.html)\n\nEvaluates the data frame analytics for an annotated index."]
+#[derive(Clone, Debug)]
+pub struct MlEvaluateDataFrame<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlEvaluateDataFrame<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlEvaluateDataFrame<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlEvaluateDataFrame]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        MlEvaluateDataFrame {
+            client,
+            builder: crate::ml::MlEvaluateDataFrame::new(client.transport()),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlEvaluateDataFrame<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlEvaluateDataFrame {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Evaluate Data Frame API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlExplainDataFrameAnalyticsParts;
+#[doc = "Builder for the [Ml Explain Data Frame Analytics API](http://www.elastic.co/guide/en/elasticsearch/reference/8.0/explain-dfanalytics.html)\n\nExplains a data frame analytics config."]
+#[derive(Clone, Debug)]
+pub struct MlExplainDataFrameAnalytics<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlExplainDataFrameAnalytics<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlExplainDataFrameAnalytics<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlExplainDataFrameAnalytics] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlExplainDataFrameAnalyticsParts<'b>) -> Self {
+        MlExplainDataFrameAnalytics {
+            client,
+            builder: crate::ml::MlExplainDataFrameAnalytics::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlExplainDataFrameAnalytics<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlExplainDataFrameAnalytics {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Explain Data Frame Analytics API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlFlushJobParts;
+#[doc = "Builder for the [Ml Flush Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-flush-job.html)\n\nForces any buffered data to be processed by the job."]
+#[derive(Clone, Debug)]
+pub struct MlFlushJob<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlFlushJob<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlFlushJob<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlFlushJob] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlFlushJobParts<'b>) -> Self {
+        MlFlushJob {
+            client,
+            builder: crate::ml::MlFlushJob::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Advances time to the given value generating results and updating the model for the advanced interval"]
+    pub fn advance_time(mut self, advance_time: &'b str) -> Self {
+        self.builder = self.builder.advance_time(advance_time);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlFlushJob<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlFlushJob {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Calculates interim results for the most recent bucket or all buckets within the latency period"]
+    pub fn calc_interim(mut self, calc_interim: bool) -> Self {
+        self.builder = self.builder.calc_interim(calc_interim);
+        self
+    }
+    #[doc = "When used in conjunction with calc_interim, specifies the range of buckets on which to calculate interim results"]
+    pub fn end(mut self, end: &'b str) -> Self {
+        self.builder = self.builder.end(end);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Skips time to the given value without generating results or updating the model for the skipped interval"]
+    pub fn skip_time(mut self, skip_time: &'b str) -> Self {
+        self.builder = self.builder.skip_time(skip_time);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "When used in conjunction with calc_interim, specifies the range of buckets on which to calculate interim results"]
+    pub fn start(mut self, start: &'b str) -> Self {
+        self.builder = self.builder.start(start);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Flush Job API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlForecastParts;
+#[doc = "Builder for the [Ml Forecast API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-forecast.html)\n\nPredicts the future behavior of a time series by using its historical behavior."]
+#[derive(Clone, Debug)]
+pub struct MlForecast<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlForecast<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlForecast<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlForecast] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlForecastParts<'b>) -> Self {
+        MlForecast {
+            client,
+            builder: crate::ml::MlForecast::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlForecast<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlForecast {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "The duration of the forecast"]
+    pub fn duration(mut self, duration: &'b str) -> Self {
+        self.builder = self.builder.duration(duration);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "The time interval after which the forecast expires. Expired forecasts will be deleted at the first opportunity."]
+    pub fn expires_in(mut self, expires_in: &'b str) -> Self {
+        self.builder = self.builder.expires_in(expires_in);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "The max memory able to be used by the forecast. Default is 20mb."]
+    pub fn max_model_memory(mut self, max_model_memory: &'b str) -> Self {
+        self.builder = self.builder.max_model_memory(max_model_memory);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Forecast API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetBucketsParts;
+#[doc = "Builder for the [Ml Get Buckets API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-bucket.html)\n\nRetrieves anomaly detection job results for one or more buckets."]
+#[derive(Clone, Debug)]
+pub struct MlGetBuckets<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetBuckets<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlGetBuckets<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlGetBuckets] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetBucketsParts<'b>) -> Self {
+        MlGetBuckets {
+            client,
+            builder: crate::ml::MlGetBuckets::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Filter for the most anomalous buckets"]
+    pub fn anomaly_score(mut self, anomaly_score: f64) -> Self {
+        self.builder = self.builder.anomaly_score(anomaly_score);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlGetBuckets<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlGetBuckets {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Set the sort direction"]
+    pub fn desc(mut self, desc: bool) -> Self {
+        self.builder = self.builder.desc(desc);
+        self
+    }
+    #[doc = "End time filter for buckets"]
+    pub fn end(mut self, end: &'b str) -> Self {
+        self.builder = self.builder.end(end);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Exclude interim results"]
+    pub fn exclude_interim(mut self, exclude_interim: bool) -> Self {
+        self.builder = self.builder.exclude_interim(exclude_interim);
+        self
+    }
+    #[doc = "Include anomaly records"]
+    pub fn expand(mut self, expand: bool) -> Self {
+        self.builder = self.builder.expand(expand);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "skips a number of buckets"]
+    pub fn from(mut self, from: i32) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "specifies a max number of buckets to get"]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "Sort buckets by a particular field"]
+    pub fn sort(mut self, sort: &'b str) -> Self {
+        self.builder = self.builder.sort(sort);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Start time filter for buckets"]
+    pub fn start(mut self, start: &'b str) -> Self {
+        self.builder = self.builder.start(start);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Buckets API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetCalendarEventsParts;
+#[doc = "Builder for the [Ml Get Calendar Events API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-calendar-event.html)\n\nRetrieves information about the scheduled events in calendars."]
+#[derive(Clone, Debug)]
+pub struct MlGetCalendarEvents<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetCalendarEvents<'a, 'b>,
+}
+impl<'a, 'b> MlGetCalendarEvents<'a, 'b> {
+    #[doc = "Creates a new instance of [MlGetCalendarEvents] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetCalendarEventsParts<'b>) -> Self {
+        MlGetCalendarEvents {
+            client,
+            builder: crate::ml::MlGetCalendarEvents::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Get events before this time"]
+    pub fn end(mut self, end: &'b str) -> Self {
+        self.builder = self.builder.end(end);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Skips a number of events"]
+    pub fn from(mut self, from: i32) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Get events for the job. When this option is used calendar_id must be '_all'"]
+    pub fn job_id(mut self, job_id: &'b str) -> Self {
+        self.builder = self.builder.job_id(job_id);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Specifies a max number of events to get"]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Get events after this time"]
+    pub fn start(mut self, start: &'b str) -> Self {
+        self.builder = self.builder.start(start);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Calendar Events API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetCalendarsParts;
+#[doc = "Builder for the [Ml Get Calendars API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-calendar.html)\n\nRetrieves configuration information for calendars."]
+#[derive(Clone, Debug)]
+pub struct MlGetCalendars<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetCalendars<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlGetCalendars<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlGetCalendars] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetCalendarsParts<'b>) -> Self {
+        MlGetCalendars {
+            client,
+            builder: crate::ml::MlGetCalendars::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlGetCalendars<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlGetCalendars {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "skips a number of calendars"]
+    pub fn from(mut self, from: i32) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "specifies a max number of calendars to get"]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Calendars API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetCategoriesParts;
+#[doc = "Builder for the [Ml Get Categories API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-category.html)\n\nRetrieves anomaly detection job results for one or more categories."]
+#[derive(Clone, Debug)]
+pub struct MlGetCategories<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetCategories<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlGetCategories<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlGetCategories] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetCategoriesParts<'b>) -> Self {
+        MlGetCategories {
+            client,
+            builder: crate::ml::MlGetCategories::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlGetCategories<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlGetCategories {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "skips a number of categories"]
+    pub fn from(mut self, from: i32) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Specifies the partition to retrieve categories for. This is optional, and should never be used for jobs where per-partition categorization is disabled."]
+    pub fn partition_field_value(mut self, partition_field_value: &'b str) -> Self {
+        self.builder = self.builder.partition_field_value(partition_field_value);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "specifies a max number of categories to get"]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Categories API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetDataFrameAnalyticsParts;
+#[doc = "Builder for the [Ml Get Data Frame Analytics API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-dfanalytics.html)\n\nRetrieves configuration information for data frame analytics jobs."]
+#[derive(Clone, Debug)]
+pub struct MlGetDataFrameAnalytics<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetDataFrameAnalytics<'a, 'b>,
+}
+impl<'a, 'b> MlGetDataFrameAnalytics<'a, 'b> {
+    #[doc = "Creates a new instance of [MlGetDataFrameAnalytics] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetDataFrameAnalyticsParts<'b>) -> Self {
+        MlGetDataFrameAnalytics {
+            client,
+            builder: crate::ml::MlGetDataFrameAnalytics::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no data frame analytics. (This includes `_all` string or when no data frame analytics have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Omits fields that are illegal to set on data frame analytics PUT"]
+    pub fn exclude_generated(mut self, exclude_generated: bool) -> Self {
+        self.builder = self.builder.exclude_generated(exclude_generated);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "skips a number of analytics"]
+    pub fn from(mut self, from: i32) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "specifies a max number of analytics to get"]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Data Frame Analytics API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetDataFrameAnalyticsStatsParts;
+#[doc = "Builder for the [Ml Get Data Frame Analytics Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-dfanalytics-stats.html)\n\nRetrieves usage information for data frame analytics jobs."]
+#[derive(Clone, Debug)]
+pub struct MlGetDataFrameAnalyticsStats<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetDataFrameAnalyticsStats<'a, 'b>,
+}
+impl<'a, 'b> MlGetDataFrameAnalyticsStats<'a, 'b> {
+    #[doc = "Creates a new instance of [MlGetDataFrameAnalyticsStats] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetDataFrameAnalyticsStatsParts<'b>) -> Self {
+        MlGetDataFrameAnalyticsStats {
+            client,
+            builder: crate::ml::MlGetDataFrameAnalyticsStats::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no data frame analytics. (This includes `_all` string or when no data frame analytics have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "skips a number of analytics"]
+    pub fn from(mut self, from: i32) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "specifies a max number of analytics to get"]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "whether the stats response should be verbose"]
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.builder = self.builder.verbose(verbose);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Data Frame Analytics Stats API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetDatafeedStatsParts;
+#[doc = "Builder for the [Ml Get Datafeed Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-datafeed-stats.html)\n\nRetrieves usage information for datafeeds."]
+#[derive(Clone, Debug)]
+pub struct MlGetDatafeedStats<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetDatafeedStats<'a, 'b>,
+}
+impl<'a, 'b> MlGetDatafeedStats<'a, 'b> {
+    #[doc = "Creates a new instance of [MlGetDatafeedStats] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetDatafeedStatsParts<'b>) -> Self {
+        MlGetDatafeedStats {
+            client,
+            builder: crate::ml::MlGetDatafeedStats::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no datafeeds. (This includes `_all` string or when no datafeeds have been specified)"]
+    pub fn allow_no_datafeeds(mut self, allow_no_datafeeds: bool) -> Self {
+        self.builder = self.builder.allow_no_datafeeds(allow_no_datafeeds);
+        self
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no datafeeds. (This includes `_all` string or when no datafeeds have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Datafeed Stats API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetDatafeedsParts;
+#[doc = "Builder for the [Ml Get Datafeeds API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-datafeed.html)\n\nRetrieves configuration information for datafeeds."]
+#[derive(Clone, Debug)]
+pub struct MlGetDatafeeds<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetDatafeeds<'a, 'b>,
+}
+impl<'a, 'b> MlGetDatafeeds<'a, 'b> {
+    #[doc = "Creates a new instance of [MlGetDatafeeds] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetDatafeedsParts<'b>) -> Self {
+        MlGetDatafeeds {
+            client,
+            builder: crate::ml::MlGetDatafeeds::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no datafeeds. (This includes `_all` string or when no datafeeds have been specified)"]
+    pub fn allow_no_datafeeds(mut self, allow_no_datafeeds: bool) -> Self {
+        self.builder = self.builder.allow_no_datafeeds(allow_no_datafeeds);
+        self
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no datafeeds. (This includes `_all` string or when no datafeeds have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Omits fields that are illegal to set on datafeed PUT"]
+    pub fn exclude_generated(mut self, exclude_generated: bool) -> Self {
+        self.builder = self.builder.exclude_generated(exclude_generated);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Datafeeds API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetFiltersParts;
+#[doc = "Builder for the [Ml Get Filters API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-filter.html)\n\nRetrieves filters."]
+#[derive(Clone, Debug)]
+pub struct MlGetFilters<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetFilters<'a, 'b>,
+}
+impl<'a, 'b> MlGetFilters<'a, 'b> {
+    #[doc = "Creates a new instance of [MlGetFilters] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetFiltersParts<'b>) -> Self {
+        MlGetFilters {
+            client,
+            builder: crate::ml::MlGetFilters::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "skips a number of filters"]
+    pub fn from(mut self, from: i32) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "specifies a max number of filters to get"]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Filters API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetInfluencersParts;
+#[doc = "Builder for the [Ml Get Influencers API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-influencer.html)\n\nRetrieves anomaly detection job results for one or more influencers."]
+#[derive(Clone, Debug)]
+pub struct MlGetInfluencers<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetInfluencers<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlGetInfluencers<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlGetInfluencers] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetInfluencersParts<'b>) -> Self {
+        MlGetInfluencers {
+            client,
+            builder: crate::ml::MlGetInfluencers::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlGetInfluencers<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlGetInfluencers {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "whether the results should be sorted in decending order"]
+    pub fn desc(mut self, desc: bool) -> Self {
+        self.builder = self.builder.desc(desc);
+        self
+    }
+    #[doc = "end timestamp for the requested influencers"]
+    pub fn end(mut self, end: &'b str) -> Self {
+        self.builder = self.builder.end(end);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Exclude interim results"]
+    pub fn exclude_interim(mut self, exclude_interim: bool) -> Self {
+        self.builder = self.builder.exclude_interim(exclude_interim);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "skips a number of influencers"]
+    pub fn from(mut self, from: i32) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "influencer score threshold for the requested influencers"]
+    pub fn influencer_score(mut self, influencer_score: f64) -> Self {
+        self.builder = self.builder.influencer_score(influencer_score);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "specifies a max number of influencers to get"]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "sort field for the requested influencers"]
+    pub fn sort(mut self, sort: &'b str) -> Self {
+        self.builder = self.builder.sort(sort);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "start timestamp for the requested influencers"]
+    pub fn start(mut self, start: &'b str) -> Self {
+        self.builder = self.builder.start(start);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Influencers API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetJobStatsParts;
+#[doc = "Builder for the [Ml Get Job Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-job-stats.html)\n\nRetrieves usage information for anomaly detection jobs."]
+#[derive(Clone, Debug)]
+pub struct MlGetJobStats<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetJobStats<'a, 'b>,
+}
+impl<'a, 'b> MlGetJobStats<'a, 'b> {
+    #[doc = "Creates a new instance of [MlGetJobStats] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetJobStatsParts<'b>) -> Self {
+        MlGetJobStats {
+            client,
+            builder: crate::ml::MlGetJobStats::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no jobs. (This includes `_all` string or when no jobs have been specified)"]
+    pub fn allow_no_jobs(mut self, allow_no_jobs: bool) -> Self {
+        self.builder = self.builder.allow_no_jobs(allow_no_jobs);
+        self
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no jobs. (This includes `_all` string or when no jobs have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Job Stats API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetJobsParts;
+#[doc = "Builder for the [Ml Get Jobs API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-job.html)\n\nRetrieves configuration information for anomaly detection jobs."]
+#[derive(Clone, Debug)]
+pub struct MlGetJobs<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetJobs<'a, 'b>,
+}
+impl<'a, 'b> MlGetJobs<'a, 'b> {
+    #[doc = "Creates a new instance of [MlGetJobs] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetJobsParts<'b>) -> Self {
+        MlGetJobs {
+            client,
+            builder: crate::ml::MlGetJobs::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no jobs. (This includes `_all` string or when no jobs have been specified)"]
+    pub fn allow_no_jobs(mut self, allow_no_jobs: bool) -> Self {
+        self.builder = self.builder.allow_no_jobs(allow_no_jobs);
+        self
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no jobs. (This includes `_all` string or when no jobs have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Omits fields that are illegal to set on job PUT"]
+    pub fn exclude_generated(mut self, exclude_generated: bool) -> Self {
+        self.builder = self.builder.exclude_generated(exclude_generated);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Jobs API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetModelSnapshotsParts;
+#[doc = "Builder for the [Ml Get Model Snapshots API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-snapshot.html)\n\nRetrieves information about model snapshots."]
+#[derive(Clone, Debug)]
+pub struct MlGetModelSnapshots<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetModelSnapshots<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlGetModelSnapshots<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlGetModelSnapshots] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetModelSnapshotsParts<'b>) -> Self {
+        MlGetModelSnapshots {
+            client,
+            builder: crate::ml::MlGetModelSnapshots::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlGetModelSnapshots<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlGetModelSnapshots {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "True if the results should be sorted in descending order"]
+    pub fn desc(mut self, desc: bool) -> Self {
+        self.builder = self.builder.desc(desc);
+        self
+    }
+    #[doc = "The filter 'end' query parameter"]
+    pub fn end(mut self, end: &'b str) -> Self {
+        self.builder = self.builder.end(end);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Skips a number of documents"]
+    pub fn from(mut self, from: i32) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The default number of documents returned in queries as a string."]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "Name of the field to sort on"]
+    pub fn sort(mut self, sort: &'b str) -> Self {
+        self.builder = self.builder.sort(sort);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The filter 'start' query parameter"]
+    pub fn start(mut self, start: &'b str) -> Self {
+        self.builder = self.builder.start(start);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Model Snapshots API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetOverallBucketsParts;
+#[doc = "Builder for the [Ml Get Overall Buckets API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-overall-buckets.html)\n\nRetrieves overall bucket results that summarize the bucket results of multiple anomaly detection jobs."]
+#[derive(Clone, Debug)]
+pub struct MlGetOverallBuckets<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetOverallBuckets<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlGetOverallBuckets<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlGetOverallBuckets] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetOverallBucketsParts<'b>) -> Self {
+        MlGetOverallBuckets {
+            client,
+            builder: crate::ml::MlGetOverallBuckets::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no jobs. (This includes `_all` string or when no jobs have been specified)"]
+    pub fn allow_no_jobs(mut self, allow_no_jobs: bool) -> Self {
+        self.builder = self.builder.allow_no_jobs(allow_no_jobs);
+        self
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no jobs. (This includes `_all` string or when no jobs have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlGetOverallBuckets<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlGetOverallBuckets {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "The span of the overall buckets. Defaults to the longest job bucket_span"]
+    pub fn bucket_span(mut self, bucket_span: &'b str) -> Self {
+        self.builder = self.builder.bucket_span(bucket_span);
+        self
+    }
+    #[doc = "Returns overall buckets with timestamps earlier than this time"]
+    pub fn end(mut self, end: &'b str) -> Self {
+        self.builder = self.builder.end(end);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "If true overall buckets that include interim buckets will be excluded"]
+    pub fn exclude_interim(mut self, exclude_interim: bool) -> Self {
+        self.builder = self.builder.exclude_interim(exclude_interim);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Returns overall buckets with overall scores higher than this value"]
+    pub fn overall_score(mut self, overall_score: f64) -> Self {
+        self.builder = self.builder.overall_score(overall_score);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Returns overall buckets with timestamps after this time"]
+    pub fn start(mut self, start: &'b str) -> Self {
+        self.builder = self.builder.start(start);
+        self
+    }
+    #[doc = "The number of top job bucket scores to be used in the overall_score calculation"]
+    pub fn top_n(mut self, top_n: i32) -> Self {
+        self.builder = self.builder.top_n(top_n);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Overall Buckets API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetRecordsParts;
+#[doc = "Builder for the [Ml Get Records API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-record.html)\n\nRetrieves anomaly records for an anomaly detection job."]
+#[derive(Clone, Debug)]
+pub struct MlGetRecords<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetRecords<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlGetRecords<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlGetRecords] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetRecordsParts<'b>) -> Self {
+        MlGetRecords {
+            client,
+            builder: crate::ml::MlGetRecords::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlGetRecords<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlGetRecords {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Set the sort direction"]
+    pub fn desc(mut self, desc: bool) -> Self {
+        self.builder = self.builder.desc(desc);
+        self
+    }
+    #[doc = "End time filter for records"]
+    pub fn end(mut self, end: &'b str) -> Self {
+        self.builder = self.builder.end(end);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Exclude interim results"]
+    pub fn exclude_interim(mut self, exclude_interim: bool) -> Self {
+        self.builder = self.builder.exclude_interim(exclude_interim);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "skips a number of records"]
+    pub fn from(mut self, from: i32) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Returns records with anomaly scores greater or equal than this value"]
+    pub fn record_score(mut self, record_score: f64) -> Self {
+        self.builder = self.builder.record_score(record_score);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "specifies a max number of records to get"]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "Sort records by a particular field"]
+    pub fn sort(mut self, sort: &'b str) -> Self {
+        self.builder = self.builder.sort(sort);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Start time filter for records"]
+    pub fn start(mut self, start: &'b str) -> Self {
+        self.builder = self.builder.start(start);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Records API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[cfg(feature = "experimental-apis")]
+pub use crate::ml::MlGetTrainedModelDeploymentStatsParts;
+#[doc = "Builder for the [Ml Get Trained Model Deployment Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-trained-model-deployment-stats.html)\n\nGet information about trained model deployments."]
+#[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+#[cfg(feature = "experimental-apis")]
+#[derive(Clone, Debug)]
+pub struct MlGetTrainedModelDeploymentStats<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetTrainedModelDeploymentStats<'a, 'b>,
+}
+#[cfg(feature = "experimental-apis")]
+impl<'a, 'b> MlGetTrainedModelDeploymentStats<'a, 'b> {
+    #[doc = "Creates a new instance of [MlGetTrainedModelDeploymentStats] with the specified API parts"]
+    pub fn new(
+        client: &'a Elasticsearch,
+        parts: MlGetTrainedModelDeploymentStatsParts<'b>,
+    ) -> Self {
+        MlGetTrainedModelDeploymentStats {
+            client,
+            builder: crate::ml::MlGetTrainedModelDeploymentStats::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Trained Model Deployment Stats API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetTrainedModelsParts;
+#[doc = "Builder for the [Ml Get Trained Models API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-trained-models.html)\n\nRetrieves configuration information for a trained inference model."]
+#[derive(Clone, Debug)]
+pub struct MlGetTrainedModels<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetTrainedModels<'a, 'b>,
+}
+impl<'a, 'b> MlGetTrainedModels<'a, 'b> {
+    #[doc = "Creates a new instance of [MlGetTrainedModels] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetTrainedModelsParts<'b>) -> Self {
+        MlGetTrainedModels {
+            client,
+            builder: crate::ml::MlGetTrainedModels::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no trained models. (This includes `_all` string or when no trained models have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "Should the model definition be decompressed into valid JSON or returned in a custom compressed format. Defaults to true."]
+    pub fn decompress_definition(mut self, decompress_definition: bool) -> Self {
+        self.builder = self.builder.decompress_definition(decompress_definition);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Omits fields that are illegal to set on model PUT"]
+    pub fn exclude_generated(mut self, exclude_generated: bool) -> Self {
+        self.builder = self.builder.exclude_generated(exclude_generated);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "skips a number of trained models"]
+    pub fn from(mut self, from: i32) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "A comma-separate list of fields to optionally include. Valid options are 'definition' and 'total_feature_importance'. Default is none."]
+    pub fn include(mut self, include: &'b str) -> Self {
+        self.builder = self.builder.include(include);
+        self
+    }
+    #[doc = "Should the full model definition be included in the results. These definitions can be large. So be cautious when including them. Defaults to false."]
+    pub fn include_model_definition(mut self, include_model_definition: bool) -> Self {
+        self.builder = self
+            .builder
+            .include_model_definition(include_model_definition);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "specifies a max number of trained models to get"]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "A comma-separated list of tags that the model must have."]
+    pub fn tags(mut self, tags: &'b [&'b str]) -> Self {
+        self.builder = self.builder.tags(tags);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Trained Models API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlGetTrainedModelsStatsParts;
+#[doc = "Builder for the [Ml Get Trained Models Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-trained-models-stats.html)\n\nRetrieves usage information for trained inference models."]
+#[derive(Clone, Debug)]
+pub struct MlGetTrainedModelsStats<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlGetTrainedModelsStats<'a, 'b>,
+}
+impl<'a, 'b> MlGetTrainedModelsStats<'a, 'b> {
+    #[doc = "Creates a new instance of [MlGetTrainedModelsStats] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlGetTrainedModelsStatsParts<'b>) -> Self {
+        MlGetTrainedModelsStats {
+            client,
+            builder: crate::ml::MlGetTrainedModelsStats::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no trained models. (This includes `_all` string or when no trained models have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "skips a number of trained models"]
+    pub fn from(mut self, from: i32) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "specifies a max number of trained models to get"]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Get Trained Models Stats API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[cfg(feature = "experimental-apis")]
+pub use crate::ml::MlInferTrainedModelDeploymentParts;
+#[doc = "Builder for the [Ml Infer Trained Model Deployment API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/infer-trained-model-deployment.html)\n\nEvaluate a trained model."]
+#[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+#[cfg(feature = "experimental-apis")]
+#[derive(Clone, Debug)]
+pub struct MlInferTrainedModelDeployment<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlInferTrainedModelDeployment<'a, 'b, B>,
+}
+#[cfg(feature = "experimental-apis")]
+impl<'a, 'b, B> MlInferTrainedModelDeployment<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlInferTrainedModelDeployment] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlInferTrainedModelDeploymentParts<'b>) -> Self {
+        MlInferTrainedModelDeployment {
+            client,
+            builder: crate::ml::MlInferTrainedModelDeployment::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlInferTrainedModelDeployment<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlInferTrainedModelDeployment {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Controls the amount of time to wait for inference results."]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Infer Trained Model Deployment API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlInfoParts;
+#[doc = "Builder for the [Ml Info API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-ml-info.html)\n\nReturns defaults and limits used by machine learning."]
+#[derive(Clone, Debug)]
+pub struct MlInfo<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlInfo<'a, 'b>,
+}
+impl<'a, 'b> MlInfo<'a, 'b> {
+    #[doc = "Creates a new instance of [MlInfo]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        MlInfo {
+            client,
+            builder: crate::ml::MlInfo::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Info API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlOpenJobParts;
+#[doc = "Builder for the [Ml Open Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-open-job.html)\n\nOpens one or more anomaly detection jobs."]
+#[derive(Clone, Debug)]
+pub struct MlOpenJob<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlOpenJob<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlOpenJob<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlOpenJob] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlOpenJobParts<'b>) -> Self {
+        MlOpenJob {
+            client,
+            builder: crate::ml::MlOpenJob::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlOpenJob<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlOpenJob {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Open Job API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlPostCalendarEventsParts;
+#[doc = "Builder for the [Ml Post Calendar Events API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-post-calendar-event.html)\n\nPosts scheduled events in a calendar."]
+#[derive(Clone, Debug)]
+pub struct MlPostCalendarEvents<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlPostCalendarEvents<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlPostCalendarEvents<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlPostCalendarEvents] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlPostCalendarEventsParts<'b>) -> Self {
+        MlPostCalendarEvents {
+            client,
+            builder: crate::ml::MlPostCalendarEvents::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlPostCalendarEvents<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlPostCalendarEvents {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Post Calendar Events API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlPostDataParts;
+#[doc = "Builder for the [Ml Post Data API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-post-data.html)\n\nSends data to an anomaly detection job for analysis."]
+#[derive(Clone, Debug)]
+pub struct MlPostData<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlPostData<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlPostData<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlPostData] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlPostDataParts<'b>) -> Self {
+        MlPostData {
+            client,
+            builder: crate::ml::MlPostData::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: Vec<T>) -> MlPostData<'a, 'b, NdBody<T>>
+    where
+        T: Body,
+    {
+        MlPostData {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Optional parameter to specify the end of the bucket resetting range"]
+    pub fn reset_end(mut self, reset_end: &'b str) -> Self {
+        self.builder = self.builder.reset_end(reset_end);
+        self
+    }
+    #[doc = "Optional parameter to specify the start of the bucket resetting range"]
+    pub fn reset_start(mut self, reset_start: &'b str) -> Self {
+        self.builder = self.builder.reset_start(reset_start);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Post Data API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlPreviewDataFrameAnalyticsParts;
+#[doc = "Builder for the [Ml Preview Data Frame Analytics API](http://www.elastic.co/guide/en/elasticsearch/reference/8.0/preview-dfanalytics.html)\n\nPreviews that will be analyzed given a data frame analytics config."]
+#[derive(Clone, Debug)]
+pub struct MlPreviewDataFrameAnalytics<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlPreviewDataFrameAnalytics<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlPreviewDataFrameAnalytics<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlPreviewDataFrameAnalytics] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlPreviewDataFrameAnalyticsParts<'b>) -> Self {
+        MlPreviewDataFrameAnalytics {
+            client,
+            builder: crate::ml::MlPreviewDataFrameAnalytics::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlPreviewDataFrameAnalytics<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlPreviewDataFrameAnalytics {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Preview Data Frame Analytics API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlPreviewDatafeedParts;
+#[doc = "Builder for the [Ml Preview Datafeed API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-preview-datafeed.html)\n\nPreviews a datafeed."]
+#[derive(Clone, Debug)]
+pub struct MlPreviewDatafeed<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlPreviewDatafeed<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlPreviewDatafeed<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlPreviewDatafeed] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlPreviewDatafeedParts<'b>) -> Self {
+        MlPreviewDatafeed {
+            client,
+            builder: crate::ml::MlPreviewDatafeed::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlPreviewDatafeed<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlPreviewDatafeed {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Preview Datafeed API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlPutCalendarParts;
+#[doc = "Builder for the [Ml Put Calendar API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-put-calendar.html)\n\nInstantiates a calendar."]
+#[derive(Clone, Debug)]
+pub struct MlPutCalendar<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlPutCalendar<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlPutCalendar<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlPutCalendar] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlPutCalendarParts<'b>) -> Self {
+        MlPutCalendar {
+            client,
+            builder: crate::ml::MlPutCalendar::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlPutCalendar<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlPutCalendar {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Put Calendar API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlPutCalendarJobParts;
+#[doc = "Builder for the [Ml Put Calendar Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-put-calendar-job.html)\n\nAdds an anomaly detection job to a calendar."]
+#[derive(Clone, Debug)]
+pub struct MlPutCalendarJob<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlPutCalendarJob<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlPutCalendarJob<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlPutCalendarJob] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlPutCalendarJobParts<'b>) -> Self {
+        MlPutCalendarJob {
+            client,
+            builder: crate::ml::MlPutCalendarJob::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlPutCalendarJob<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlPutCalendarJob {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Put Calendar Job API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlPutDataFrameAnalyticsParts;
+#[doc = "Builder for the [Ml Put Data Frame Analytics API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/put-dfanalytics.html)\n\nInstantiates a data frame analytics job."]
+#[derive(Clone, Debug)]
+pub struct MlPutDataFrameAnalytics<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlPutDataFrameAnalytics<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlPutDataFrameAnalytics<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlPutDataFrameAnalytics] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlPutDataFrameAnalyticsParts<'b>) -> Self {
+        MlPutDataFrameAnalytics {
+            client,
+            builder: crate::ml::MlPutDataFrameAnalytics::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlPutDataFrameAnalytics<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlPutDataFrameAnalytics {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Put Data Frame Analytics API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlPutDatafeedParts;
+#[doc = "Builder for the [Ml Put Datafeed API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-put-datafeed.html)\n\nInstantiates a datafeed."]
+#[derive(Clone, Debug)]
+pub struct MlPutDatafeed<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlPutDatafeed<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlPutDatafeed<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlPutDatafeed] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlPutDatafeedParts<'b>) -> Self {
+        MlPutDatafeed {
+            client,
+            builder: crate::ml::MlPutDatafeed::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Ignore if the source indices expressions resolves to no concrete indices (default: true)"]
+    pub fn allow_no_indices(mut self, allow_no_indices: bool) -> Self {
+        self.builder = self.builder.allow_no_indices(allow_no_indices);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlPutDatafeed<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlPutDatafeed {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether source index expressions should get expanded to open or closed indices (default: open)"]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Ignore indices that are marked as throttled (default: true)"]
+    pub fn ignore_throttled(mut self, ignore_throttled: bool) -> Self {
+        self.builder = self.builder.ignore_throttled(ignore_throttled);
+        self
+    }
+    #[doc = "Ignore unavailable indexes (default: false)"]
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.builder = self.builder.ignore_unavailable(ignore_unavailable);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Put Datafeed API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlPutFilterParts;
+#[doc = "Builder for the [Ml Put Filter API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-put-filter.html)\n\nInstantiates a filter."]
+#[derive(Clone, Debug)]
+pub struct MlPutFilter<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlPutFilter<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlPutFilter<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlPutFilter] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlPutFilterParts<'b>) -> Self {
+        MlPutFilter {
+            client,
+            builder: crate::ml::MlPutFilter::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlPutFilter<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlPutFilter {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Put Filter API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlPutJobParts;
+#[doc = "Builder for the [Ml Put Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-put-job.html)\n\nInstantiates an anomaly detection job."]
+#[derive(Clone, Debug)]
+pub struct MlPutJob<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlPutJob<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlPutJob<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlPutJob] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlPutJobParts<'b>) -> Self {
+        MlPutJob {
+            client,
+            builder: crate::ml::MlPutJob::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Ignore if the source indices expressions resolves to no concrete indices (default: true). Only set if datafeed_config is provided."]
+    pub fn allow_no_indices(mut self, allow_no_indices: bool) -> Self {
+        self.builder = self.builder.allow_no_indices(allow_no_indices);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlPutJob<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlPutJob {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether source index expressions should get expanded to open or closed indices (default: open). Only set if datafeed_config is provided."]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Ignore indices that are marked as throttled (default: true). Only set if datafeed_config is provided."]
+    pub fn ignore_throttled(mut self, ignore_throttled: bool) -> Self {
+        self.builder = self.builder.ignore_throttled(ignore_throttled);
+        self
+    }
+    #[doc = "Ignore unavailable indexes (default: false). Only set if datafeed_config is provided."]
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.builder = self.builder.ignore_unavailable(ignore_unavailable);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Put Job API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlPutTrainedModelParts;
+#[doc = "Builder for the [Ml Put Trained Model API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/put-trained-models.html)\n\nCreates an inference trained model."]
+#[derive(Clone, Debug)]
+pub struct MlPutTrainedModel<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlPutTrainedModel<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlPutTrainedModel<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlPutTrainedModel] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlPutTrainedModelParts<'b>) -> Self {
+        MlPutTrainedModel {
+            client,
+            builder: crate::ml::MlPutTrainedModel::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlPutTrainedModel<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlPutTrainedModel {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "If set to `true` and a `compressed_definition` is provided, the request defers definition decompression and skips relevant validations."]
+    pub fn defer_definition_decompression(mut self, defer_definition_decompression: bool) -> Self {
+        self.builder = self
+            .builder
+            .defer_definition_decompression(defer_definition_decompression);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Put Trained Model API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlPutTrainedModelAliasParts;
+#[doc = "Builder for the [Ml Put Trained Model Alias API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/put-trained-models-aliases.html)\n\nCreates a new model alias (or reassigns an existing one) to refer to the trained model"]
+#[derive(Clone, Debug)]
+pub struct MlPutTrainedModelAlias<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlPutTrainedModelAlias<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlPutTrainedModelAlias<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlPutTrainedModelAlias] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlPutTrainedModelAliasParts<'b>) -> Self {
+        MlPutTrainedModelAlias {
+            client,
+            builder: crate::ml::MlPutTrainedModelAlias::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlPutTrainedModelAlias<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlPutTrainedModelAlias {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "If the model_alias already exists and points to a separate model_id, this parameter must be true. Defaults to false."]
+    pub fn reassign(mut self, reassign: bool) -> Self {
+        self.builder = self.builder.reassign(reassign);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Put Trained Model Alias API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlResetJobParts;
+#[doc = "Builder for the [Ml Reset Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-reset-job.html)\n\nResets an existing anomaly detection job."]
+#[derive(Clone, Debug)]
+pub struct MlResetJob<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlResetJob<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlResetJob<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlResetJob] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlResetJobParts<'b>) -> Self {
+        MlResetJob {
+            client,
+            builder: crate::ml::MlResetJob::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlResetJob<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlResetJob {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Should this request wait until the operation has completed before returning"]
+    pub fn wait_for_completion(mut self, wait_for_completion: bool) -> Self {
+        self.builder = self.builder.wait_for_completion(wait_for_completion);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Reset Job API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlRevertModelSnapshotParts;
+#[doc = "Builder for the [Ml Revert Model Snapshot API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-revert-snapshot.html)\n\nReverts to a specific snapshot."]
+#[derive(Clone, Debug)]
+pub struct MlRevertModelSnapshot<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlRevertModelSnapshot<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlRevertModelSnapshot<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlRevertModelSnapshot] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlRevertModelSnapshotParts<'b>) -> Self {
+        MlRevertModelSnapshot {
+            client,
+            builder: crate::ml::MlRevertModelSnapshot::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlRevertModelSnapshot<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlRevertModelSnapshot {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Should we reset the results back to the time of the snapshot?"]
+    pub fn delete_intervening_results(mut self, delete_intervening_results: bool) -> Self {
+        self.builder = self
+            .builder
+            .delete_intervening_results(delete_intervening_results);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Revert Model Snapshot API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlSetUpgradeModeParts;
+#[doc = "Builder for the [Ml Set Upgrade Mode API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-set-upgrade-mode.html)\n\nSets a cluster wide upgrade_mode setting that prepares machine learning indices for an upgrade."]
+#[derive(Clone, Debug)]
+pub struct MlSetUpgradeMode<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlSetUpgradeMode<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlSetUpgradeMode<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlSetUpgradeMode]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        MlSetUpgradeMode {
+            client,
+            builder: crate::ml::MlSetUpgradeMode::new(client.transport()),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlSetUpgradeMode<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlSetUpgradeMode {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Whether to enable upgrade_mode ML setting or not. Defaults to false."]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.builder = self.builder.enabled(enabled);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Controls the time to wait before action times out. Defaults to 30 seconds"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Set Upgrade Mode API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlStartDataFrameAnalyticsParts;
+#[doc = "Builder for the [Ml Start Data Frame Analytics API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/start-dfanalytics.html)\n\nStarts a data frame analytics job."]
+#[derive(Clone, Debug)]
+pub struct MlStartDataFrameAnalytics<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlStartDataFrameAnalytics<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlStartDataFrameAnalytics<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlStartDataFrameAnalytics] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlStartDataFrameAnalyticsParts<'b>) -> Self {
+        MlStartDataFrameAnalytics {
+            client,
+            builder: crate::ml::MlStartDataFrameAnalytics::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlStartDataFrameAnalytics<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlStartDataFrameAnalytics {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Controls the time to wait until the task has started. Defaults to 20 seconds"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Start Data Frame Analytics API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlStartDatafeedParts;
+#[doc = "Builder for the [Ml Start Datafeed API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-start-datafeed.html)\n\nStarts one or more datafeeds."]
+#[derive(Clone, Debug)]
+pub struct MlStartDatafeed<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlStartDatafeed<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlStartDatafeed<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlStartDatafeed] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlStartDatafeedParts<'b>) -> Self {
+        MlStartDatafeed {
+            client,
+            builder: crate::ml::MlStartDatafeed::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlStartDatafeed<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlStartDatafeed {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "The end time when the datafeed should stop. When not set, the datafeed continues in real time"]
+    pub fn end(mut self, end: &'b str) -> Self {
+        self.builder = self.builder.end(end);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The start time from where the datafeed should begin"]
+    pub fn start(mut self, start: &'b str) -> Self {
+        self.builder = self.builder.start(start);
+        self
+    }
+    #[doc = "Controls the time to wait until a datafeed has started. Default to 20 seconds"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Start Datafeed API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[cfg(feature = "experimental-apis")]
+pub use crate::ml::MlStartTrainedModelDeploymentParts;
+#[doc = "Builder for the [Ml Start Trained Model Deployment API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/start-trained-model-deployment.html)\n\nStart a trained model deployment."]
+#[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+#[cfg(feature = "experimental-apis")]
+#[derive(Clone, Debug)]
+pub struct MlStartTrainedModelDeployment<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlStartTrainedModelDeployment<'a, 'b, B>,
+}
+#[cfg(feature = "experimental-apis")]
+impl<'a, 'b, B> MlStartTrainedModelDeployment<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlStartTrainedModelDeployment] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlStartTrainedModelDeploymentParts<'b>) -> Self {
+        MlStartTrainedModelDeployment {
+            client,
+            builder: crate::ml::MlStartTrainedModelDeployment::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlStartTrainedModelDeployment<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlStartTrainedModelDeployment {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Controls the amount of time to wait for the model to deploy."]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Start Trained Model Deployment API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlStopDataFrameAnalyticsParts;
+#[doc = "Builder for the [Ml Stop Data Frame Analytics API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/stop-dfanalytics.html)\n\nStops one or more data frame analytics jobs."]
+#[derive(Clone, Debug)]
+pub struct MlStopDataFrameAnalytics<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlStopDataFrameAnalytics<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlStopDataFrameAnalytics<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlStopDataFrameAnalytics] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlStopDataFrameAnalyticsParts<'b>) -> Self {
+        MlStopDataFrameAnalytics {
+            client,
+            builder: crate::ml::MlStopDataFrameAnalytics::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no data frame analytics. (This includes `_all` string or when no data frame analytics have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlStopDataFrameAnalytics<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlStopDataFrameAnalytics {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "True if the data frame analytics should be forcefully stopped"]
+    pub fn force(mut self, force: bool) -> Self {
+        self.builder = self.builder.force(force);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Controls the time to wait until the task has stopped. Defaults to 20 seconds"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Stop Data Frame Analytics API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlStopDatafeedParts;
+#[doc = "Builder for the [Ml Stop Datafeed API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-stop-datafeed.html)\n\nStops one or more datafeeds."]
+#[derive(Clone, Debug)]
+pub struct MlStopDatafeed<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlStopDatafeed<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlStopDatafeed<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlStopDatafeed] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlStopDatafeedParts<'b>) -> Self {
+        MlStopDatafeed {
+            client,
+            builder: crate::ml::MlStopDatafeed::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no datafeeds. (This includes `_all` string or when no datafeeds have been specified)"]
+    pub fn allow_no_datafeeds(mut self, allow_no_datafeeds: bool) -> Self {
+        self.builder = self.builder.allow_no_datafeeds(allow_no_datafeeds);
+        self
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no datafeeds. (This includes `_all` string or when no datafeeds have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlStopDatafeed<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlStopDatafeed {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "True if the datafeed should be forcefully stopped."]
+    pub fn force(mut self, force: bool) -> Self {
+        self.builder = self.builder.force(force);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Controls the time to wait until a datafeed has stopped. Default to 20 seconds"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Stop Datafeed API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[cfg(feature = "experimental-apis")]
+pub use crate::ml::MlStopTrainedModelDeploymentParts;
+#[doc = "Builder for the [Ml Stop Trained Model Deployment API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/stop-trained-model-deployment.html)\n\nStop a trained model deployment."]
+#[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+#[cfg(feature = "experimental-apis")]
+#[derive(Clone, Debug)]
+pub struct MlStopTrainedModelDeployment<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlStopTrainedModelDeployment<'a, 'b, B>,
+}
+#[cfg(feature = "experimental-apis")]
+impl<'a, 'b, B> MlStopTrainedModelDeployment<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlStopTrainedModelDeployment] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlStopTrainedModelDeploymentParts<'b>) -> Self {
+        MlStopTrainedModelDeployment {
+            client,
+            builder: crate::ml::MlStopTrainedModelDeployment::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlStopTrainedModelDeployment<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlStopTrainedModelDeployment {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Stop Trained Model Deployment API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlUpdateDataFrameAnalyticsParts;
+#[doc = "Builder for the [Ml Update Data Frame Analytics API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/update-dfanalytics.html)\n\nUpdates certain properties of a data frame analytics job."]
+#[derive(Clone, Debug)]
+pub struct MlUpdateDataFrameAnalytics<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlUpdateDataFrameAnalytics<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlUpdateDataFrameAnalytics<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlUpdateDataFrameAnalytics] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlUpdateDataFrameAnalyticsParts<'b>) -> Self {
+        MlUpdateDataFrameAnalytics {
+            client,
+            builder: crate::ml::MlUpdateDataFrameAnalytics::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlUpdateDataFrameAnalytics<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlUpdateDataFrameAnalytics {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Update Data Frame Analytics API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlUpdateDatafeedParts;
+#[doc = "Builder for the [Ml Update Datafeed API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-update-datafeed.html)\n\nUpdates certain properties of a datafeed."]
+#[derive(Clone, Debug)]
+pub struct MlUpdateDatafeed<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlUpdateDatafeed<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlUpdateDatafeed<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlUpdateDatafeed] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlUpdateDatafeedParts<'b>) -> Self {
+        MlUpdateDatafeed {
+            client,
+            builder: crate::ml::MlUpdateDatafeed::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Ignore if the source indices expressions resolves to no concrete indices (default: true)"]
+    pub fn allow_no_indices(mut self, allow_no_indices: bool) -> Self {
+        self.builder = self.builder.allow_no_indices(allow_no_indices);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlUpdateDatafeed<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlUpdateDatafeed {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether source index expressions should get expanded to open or closed indices (default: open)"]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Ignore indices that are marked as throttled (default: true)"]
+    pub fn ignore_throttled(mut self, ignore_throttled: bool) -> Self {
+        self.builder = self.builder.ignore_throttled(ignore_throttled);
+        self
+    }
+    #[doc = "Ignore unavailable indexes (default: false)"]
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.builder = self.builder.ignore_unavailable(ignore_unavailable);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Update Datafeed API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlUpdateFilterParts;
+#[doc = "Builder for the [Ml Update Filter API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-update-filter.html)\n\nUpdates the description of a filter, adds items, or removes items."]
+#[derive(Clone, Debug)]
+pub struct MlUpdateFilter<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlUpdateFilter<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlUpdateFilter<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlUpdateFilter] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlUpdateFilterParts<'b>) -> Self {
+        MlUpdateFilter {
+            client,
+            builder: crate::ml::MlUpdateFilter::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlUpdateFilter<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlUpdateFilter {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Update Filter API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlUpdateJobParts;
+#[doc = "Builder for the [Ml Update Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-update-job.html)\n\nUpdates certain properties of an anomaly detection job."]
+#[derive(Clone, Debug)]
+pub struct MlUpdateJob<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlUpdateJob<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlUpdateJob<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlUpdateJob] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlUpdateJobParts<'b>) -> Self {
+        MlUpdateJob {
+            client,
+            builder: crate::ml::MlUpdateJob::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlUpdateJob<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlUpdateJob {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Update Job API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlUpdateModelSnapshotParts;
+#[doc = "Builder for the [Ml Update Model Snapshot API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-update-snapshot.html)\n\nUpdates certain properties of a snapshot."]
+#[derive(Clone, Debug)]
+pub struct MlUpdateModelSnapshot<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlUpdateModelSnapshot<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlUpdateModelSnapshot<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlUpdateModelSnapshot] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlUpdateModelSnapshotParts<'b>) -> Self {
+        MlUpdateModelSnapshot {
+            client,
+            builder: crate::ml::MlUpdateModelSnapshot::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlUpdateModelSnapshot<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlUpdateModelSnapshot {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Update Model Snapshot API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlUpgradeJobSnapshotParts;
+#[doc = "Builder for the [Ml Upgrade Job Snapshot API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-upgrade-job-model-snapshot.html)\n\nUpgrades a given job snapshot to the current major version."]
+#[derive(Clone, Debug)]
+pub struct MlUpgradeJobSnapshot<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlUpgradeJobSnapshot<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlUpgradeJobSnapshot<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlUpgradeJobSnapshot] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MlUpgradeJobSnapshotParts<'b>) -> Self {
+        MlUpgradeJobSnapshot {
+            client,
+            builder: crate::ml::MlUpgradeJobSnapshot::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlUpgradeJobSnapshot<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlUpgradeJobSnapshot {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "How long should the API wait for the job to be opened and the old snapshot to be loaded."]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Should the request wait until the task is complete before responding to the caller. Default is false."]
+    pub fn wait_for_completion(mut self, wait_for_completion: bool) -> Self {
+        self.builder = self.builder.wait_for_completion(wait_for_completion);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Upgrade Job Snapshot API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlValidateParts;
+#[doc = "Builder for the [Ml Validate API](https://www.elastic.co/guide/en/machine-learning/8.0/ml-jobs.html)\n\nValidates an anomaly detection job."]
+#[derive(Clone, Debug)]
+pub struct MlValidate<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlValidate<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlValidate<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlValidate]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        MlValidate {
+            client,
+            builder: crate::ml::MlValidate::new(client.transport()),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlValidate<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlValidate {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Validate API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::ml::MlValidateDetectorParts;
+#[doc = "Builder for the [Ml Validate Detector API](https://www.elastic.co/guide/en/machine-learning/8.0/ml-jobs.html)\n\nValidates an anomaly detection detector."]
+#[derive(Clone, Debug)]
+pub struct MlValidateDetector<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::ml::MlValidateDetector<'a, 'b, B>,
+}
+impl<'a, 'b, B> MlValidateDetector<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MlValidateDetector]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        MlValidateDetector {
+            client,
+            builder: crate::ml::MlValidateDetector::new(client.transport()),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> MlValidateDetector<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        MlValidateDetector {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ml Validate Detector API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[doc = "Namespace client for Machine Learning APIs"]
+pub struct Ml<'a> {
+    client: &'a Elasticsearch,
+}
+impl<'a> Ml<'a> {
+    #[doc = "Creates a new instance of [Ml]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        Self { client }
+    }
+    pub fn transport(&self) -> &Transport {
+        self.client.transport()
+    }
+    #[doc = "[Ml Close Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-close-job.html)\n\nCloses one or more anomaly detection jobs. A job can be opened and closed multiple times throughout its lifecycle."]
+    pub fn close_job<'b>(&'a self, parts: MlCloseJobParts<'b>) -> MlCloseJob<'a, 'b, ()> {
+        MlCloseJob::new(self.client, parts)
+    }
+    #[doc = "[Ml Delete Calendar API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-calendar.html)\n\nDeletes a calendar."]
+    pub fn delete_calendar<'b>(
+        &'a self,
+        parts: MlDeleteCalendarParts<'b>,
+    ) -> MlDeleteCalendar<'a, 'b> {
+        MlDeleteCalendar::new(self.client, parts)
+    }
+    #[doc = "[Ml Delete Calendar Event API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-calendar-event.html)\n\nDeletes scheduled events from a calendar."]
+    pub fn delete_calendar_event<'b>(
+        &'a self,
+        parts: MlDeleteCalendarEventParts<'b>,
+    ) -> MlDeleteCalendarEvent<'a, 'b> {
+        MlDeleteCalendarEvent::new(self.client, parts)
+    }
+    #[doc = "[Ml Delete Calendar Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-calendar-job.html)\n\nDeletes anomaly detection jobs from a calendar."]
+    pub fn delete_calendar_job<'b>(
+        &'a self,
+        parts: MlDeleteCalendarJobParts<'b>,
+    ) -> MlDeleteCalendarJob<'a, 'b> {
+        MlDeleteCalendarJob::new(self.client, parts)
+    }
+    #[doc = "[Ml Delete Data Frame Analytics API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/delete-dfanalytics.html)\n\nDeletes an existing data frame analytics job."]
+    pub fn delete_data_frame_analytics<'b>(
+        &'a self,
+        parts: MlDeleteDataFrameAnalyticsParts<'b>,
+    ) -> MlDeleteDataFrameAnalytics<'a, 'b> {
+        MlDeleteDataFrameAnalytics::new(self.client, parts)
+    }
+    #[doc = "[Ml Delete Datafeed API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-datafeed.html)\n\nDeletes an existing datafeed."]
+    pub fn delete_datafeed<'b>(
+        &'a self,
+        parts: MlDeleteDatafeedParts<'b>,
+    ) -> MlDeleteDatafeed<'a, 'b> {
+        MlDeleteDatafeed::new(self.client, parts)
+    }
+    #[doc = "[Ml Delete Expired Data API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-expired-data.html)\n\nDeletes expired and unused machine learning data."]
+    pub fn delete_expired_data<'b>(
+        &'a self,
+        parts: MlDeleteExpiredDataParts<'b>,
+    ) -> MlDeleteExpiredData<'a, 'b, ()> {
+        MlDeleteExpiredData::new(self.client, parts)
+    }
+    #[doc = "[Ml Delete Filter API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-filter.html)\n\nDeletes a filter."]
+    pub fn delete_filter<'b>(&'a self, parts: MlDeleteFilterParts<'b>) -> MlDeleteFilter<'a, 'b> {
+        MlDeleteFilter::new(self.client, parts)
+    }
+    #[doc = "[Ml Delete Forecast API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-forecast.html)\n\nDeletes forecasts from a machine learning job."]
+    pub fn delete_forecast<'b>(
+        &'a self,
+        parts: MlDeleteForecastParts<'b>,
+    ) -> MlDeleteForecast<'a, 'b> {
+        MlDeleteForecast::new(self.client, parts)
+    }
+    #[doc = "[Ml Delete Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-job.html)\n\nDeletes an existing anomaly detection job."]
+    pub fn delete_job<'b>(&'a self, parts: MlDeleteJobParts<'b>) -> MlDeleteJob<'a, 'b> {
+        MlDeleteJob::new(self.client, parts)
+    }
+    #[doc = "[Ml Delete Model Snapshot API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-delete-snapshot.html)\n\nDeletes an existing model snapshot."]
+    pub fn delete_model_snapshot<'b>(
+        &'a self,
+        parts: MlDeleteModelSnapshotParts<'b>,
+    ) -> MlDeleteModelSnapshot<'a, 'b> {
+        MlDeleteModelSnapshot::new(self.client, parts)
+    }
+    #[doc = "[Ml Delete Trained Model API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/delete-trained-models.html)\n\nDeletes an existing trained inference model that is currently not referenced by an ingest pipeline."]
+    pub fn delete_trained_model<'b>(
+        &'a self,
+        parts: MlDeleteTrainedModelParts<'b>,
+    ) -> MlDeleteTrainedModel<'a, 'b> {
+        MlDeleteTrainedModel::new(self.client, parts)
+    }
+    #[doc = "[Ml Delete Trained Model Alias API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/delete-trained-models-aliases.html)\n\nDeletes a model alias that refers to the trained model"]
+    pub fn delete_trained_model_alias<'b>(
+        &'a self,
+        parts: MlDeleteTrainedModelAliasParts<'b>,
+    ) -> MlDeleteTrainedModelAlias<'a, 'b> {
+        MlDeleteTrainedModelAlias::new(self.client, parts)
+    }
+    #[doc = "[Ml Estimate Model Memory API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-apis.html)\n\nEstimates the model memory"]
+    pub fn estimate_model_memory<'b>(&'a self) -> MlEstimateModelMemory<'a, 'b, ()> {
+        MlEstimateModelMemory::new(self.client)
+    }
+    #[doc = "[Ml Evaluate Data Frame API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/evaluate-dfanalytics.html)\n\nEvaluates the data frame analytics for an annotated index."]
+    pub fn evaluate_data_frame<'b>(&'a self) -> MlEvaluateDataFrame<'a, 'b, ()> {
+        MlEvaluateDataFrame::new(self.client)
+    }
+    #[doc = "[Ml Explain Data Frame Analytics API](http://www.elastic.co/guide/en/elasticsearch/reference/8.0/explain-dfanalytics.html)\n\nExplains a data frame analytics config."]
+    pub fn explain_data_frame_analytics<'b>(
+        &'a self,
+        parts: MlExplainDataFrameAnalyticsParts<'b>,
+    ) -> MlExplainDataFrameAnalytics<'a, 'b, ()> {
+        MlExplainDataFrameAnalytics::new(self.client, parts)
+    }
+    #[doc = "[Ml Flush Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-flush-job.html)\n\nForces any buffered data to be processed by the job."]
+    pub fn flush_job<'b>(&'a self, parts: MlFlushJobParts<'b>) -> MlFlushJob<'a, 'b, ()> {
+        MlFlushJob::new(self.client, parts)
+    }
+    #[doc = "[Ml Forecast API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-forecast.html)\n\nPredicts the future behavior of a time series by using its historical behavior."]
+    pub fn forecast<'b>(&'a self, parts: MlForecastParts<'b>) -> MlForecast<'a, 'b, ()> {
+        MlForecast::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Buckets API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-bucket.html)\n\nRetrieves anomaly detection job results for one or more buckets."]
+    pub fn get_buckets<'b>(&'a self, parts: MlGetBucketsParts<'b>) -> MlGetBuckets<'a, 'b, ()> {
+        MlGetBuckets::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Calendar Events API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-calendar-event.html)\n\nRetrieves information about the scheduled events in calendars."]
+    pub fn get_calendar_events<'b>(
+        &'a self,
+        parts: MlGetCalendarEventsParts<'b>,
+    ) -> MlGetCalendarEvents<'a, 'b> {
+        MlGetCalendarEvents::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Calendars API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-calendar.html)\n\nRetrieves configuration information for calendars."]
+    pub fn get_calendars<'b>(
+        &'a self,
+        parts: MlGetCalendarsParts<'b>,
+    ) -> MlGetCalendars<'a, 'b, ()> {
+        MlGetCalendars::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Categories API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-category.html)\n\nRetrieves anomaly detection job results for one or more categories."]
+    pub fn get_categories<'b>(
+        &'a self,
+        parts: MlGetCategoriesParts<'b>,
+    ) -> MlGetCategories<'a, 'b, ()> {
+        MlGetCategories::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Data Frame Analytics API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-dfanalytics.html)\n\nRetrieves configuration information for data frame analytics jobs."]
+    pub fn get_data_frame_analytics<'b>(
+        &'a self,
+        parts: MlGetDataFrameAnalyticsParts<'b>,
+    ) -> MlGetDataFrameAnalytics<'a, 'b> {
+        MlGetDataFrameAnalytics::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Data Frame Analytics Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-dfanalytics-stats.html)\n\nRetrieves usage information for data frame analytics jobs."]
+    pub fn get_data_frame_analytics_stats<'b>(
+        &'a self,
+        parts: MlGetDataFrameAnalyticsStatsParts<'b>,
+    ) -> MlGetDataFrameAnalyticsStats<'a, 'b> {
+        MlGetDataFrameAnalyticsStats::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Datafeed Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-datafeed-stats.html)\n\nRetrieves usage information for datafeeds."]
+    pub fn get_datafeed_stats<'b>(
+        &'a self,
+        parts: MlGetDatafeedStatsParts<'b>,
+    ) -> MlGetDatafeedStats<'a, 'b> {
+        MlGetDatafeedStats::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Datafeeds API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-datafeed.html)\n\nRetrieves configuration information for datafeeds."]
+    pub fn get_datafeeds<'b>(&'a self, parts: MlGetDatafeedsParts<'b>) -> MlGetDatafeeds<'a, 'b> {
+        MlGetDatafeeds::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Filters API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-filter.html)\n\nRetrieves filters."]
+    pub fn get_filters<'b>(&'a self, parts: MlGetFiltersParts<'b>) -> MlGetFilters<'a, 'b> {
+        MlGetFilters::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Influencers API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-influencer.html)\n\nRetrieves anomaly detection job results for one or more influencers."]
+    pub fn get_influencers<'b>(
+        &'a self,
+        parts: MlGetInfluencersParts<'b>,
+    ) -> MlGetInfluencers<'a, 'b, ()> {
+        MlGetInfluencers::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Job Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-job-stats.html)\n\nRetrieves usage information for anomaly detection jobs."]
+    pub fn get_job_stats<'b>(&'a self, parts: MlGetJobStatsParts<'b>) -> MlGetJobStats<'a, 'b> {
+        MlGetJobStats::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Jobs API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-job.html)\n\nRetrieves configuration information for anomaly detection jobs."]
+    pub fn get_jobs<'b>(&'a self, parts: MlGetJobsParts<'b>) -> MlGetJobs<'a, 'b> {
+        MlGetJobs::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Model Snapshots API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-snapshot.html)\n\nRetrieves information about model snapshots."]
+    pub fn get_model_snapshots<'b>(
+        &'a self,
+        parts: MlGetModelSnapshotsParts<'b>,
+    ) -> MlGetModelSnapshots<'a, 'b, ()> {
+        MlGetModelSnapshots::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Overall Buckets API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-overall-buckets.html)\n\nRetrieves overall bucket results that summarize the bucket results of multiple anomaly detection jobs."]
+    pub fn get_overall_buckets<'b>(
+        &'a self,
+        parts: MlGetOverallBucketsParts<'b>,
+    ) -> MlGetOverallBuckets<'a, 'b, ()> {
+        MlGetOverallBuckets::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Records API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-record.html)\n\nRetrieves anomaly records for an anomaly detection job."]
+    pub fn get_records<'b>(&'a self, parts: MlGetRecordsParts<'b>) -> MlGetRecords<'a, 'b, ()> {
+        MlGetRecords::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Trained Model Deployment Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-get-trained-model-deployment-stats.html)\n\nGet information about trained model deployments."]
+    #[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+    #[cfg(feature = "experimental-apis")]
+    pub fn get_trained_model_deployment_stats<'b>(
+        &'a self,
+        parts: MlGetTrainedModelDeploymentStatsParts<'b>,
+    ) -> MlGetTrainedModelDeploymentStats<'a, 'b> {
+        MlGetTrainedModelDeploymentStats::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Trained Models API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-trained-models.html)\n\nRetrieves configuration information for a trained inference model."]
+    pub fn get_trained_models<'b>(
+        &'a self,
+        parts: MlGetTrainedModelsParts<'b>,
+    ) -> MlGetTrainedModels<'a, 'b> {
+        MlGetTrainedModels::new(self.client, parts)
+    }
+    #[doc = "[Ml Get Trained Models Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-trained-models-stats.html)\n\nRetrieves usage information for trained inference models."]
+    pub fn get_trained_models_stats<'b>(
+        &'a self,
+        parts: MlGetTrainedModelsStatsParts<'b>,
+    ) -> MlGetTrainedModelsStats<'a, 'b> {
+        MlGetTrainedModelsStats::new(self.client, parts)
+    }
+    #[doc = "[Ml Infer Trained Model Deployment API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/infer-trained-model-deployment.html)\n\nEvaluate a trained model."]
+    #[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+    #[cfg(feature = "experimental-apis")]
+    pub fn infer_trained_model_deployment<'b>(
+        &'a self,
+        parts: MlInferTrainedModelDeploymentParts<'b>,
+    ) -> MlInferTrainedModelDeployment<'a, 'b, ()> {
+        MlInferTrainedModelDeployment::new(self.client, parts)
+    }
+    #[doc = "[Ml Info API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-ml-info.html)\n\nReturns defaults and limits used by machine learning."]
+    pub fn info<'b>(&'a self) -> MlInfo<'a, 'b> {
+        MlInfo::new(self.client)
+    }
+    #[doc = "[Ml Open Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-open-job.html)\n\nOpens one or more anomaly detection jobs."]
+    pub fn open_job<'b>(&'a self, parts: MlOpenJobParts<'b>) -> MlOpenJob<'a, 'b, ()> {
+        MlOpenJob::new(self.client, parts)
+    }
+    #[doc = "[Ml Post Calendar Events API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-post-calendar-event.html)\n\nPosts scheduled events in a calendar."]
+    pub fn post_calendar_events<'b>(
+        &'a self,
+        parts: MlPostCalendarEventsParts<'b>,
+    ) -> MlPostCalendarEvents<'a, 'b, ()> {
+        MlPostCalendarEvents::new(self.client, parts)
+    }
+    #[doc = "[Ml Post Data API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-post-data.html)\n\nSends data to an anomaly detection job for analysis."]
+    pub fn post_data<'b>(&'a self, parts: MlPostDataParts<'b>) -> MlPostData<'a, 'b, ()> {
+        MlPostData::new(self.client, parts)
+    }
+    #[doc = "[Ml Preview Data Frame Analytics API](http://www.elastic.co/guide/en/elasticsearch/reference/8.0/preview-dfanalytics.html)\n\nPreviews that will be analyzed given a data frame analytics config."]
+    pub fn preview_data_frame_analytics<'b>(
+        &'a self,
+        parts: MlPreviewDataFrameAnalyticsParts<'b>,
+    ) -> MlPreviewDataFrameAnalytics<'a, 'b, ()> {
+        MlPreviewDataFrameAnalytics::new(self.client, parts)
+    }
+    #[doc = "[Ml Preview Datafeed API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-preview-datafeed.html)\n\nPreviews a datafeed."]
+    pub fn preview_datafeed<'b>(
+        &'a self,
+        parts: MlPreviewDatafeedParts<'b>,
+    ) -> MlPreviewDatafeed<'a, 'b, ()> {
+        MlPreviewDatafeed::new(self.client, parts)
+    }
+    #[doc = "[Ml Put Calendar API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-put-calendar.html)\n\nInstantiates a calendar."]
+    pub fn put_calendar<'b>(&'a self, parts: MlPutCalendarParts<'b>) -> MlPutCalendar<'a, 'b, ()> {
+        MlPutCalendar::new(self.client, parts)
+    }
+    #[doc = "[Ml Put Calendar Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-put-calendar-job.html)\n\nAdds an anomaly detection job to a calendar."]
+    pub fn put_calendar_job<'b>(
+        &'a self,
+        parts: MlPutCalendarJobParts<'b>,
+    ) -> MlPutCalendarJob<'a, 'b, ()> {
+        MlPutCalendarJob::new(self.client, parts)
+    }
+    #[doc = "[Ml Put Data Frame Analytics API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/put-dfanalytics.html)\n\nInstantiates a data frame analytics job."]
+    pub fn put_data_frame_analytics<'b>(
+        &'a self,
+        parts: MlPutDataFrameAnalyticsParts<'b>,
+    ) -> MlPutDataFrameAnalytics<'a, 'b, ()> {
+        MlPutDataFrameAnalytics::new(self.client, parts)
+    }
+    #[doc = "[Ml Put Datafeed API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-put-datafeed.html)\n\nInstantiates a datafeed."]
+    pub fn put_datafeed<'b>(&'a self, parts: MlPutDatafeedParts<'b>) -> MlPutDatafeed<'a, 'b, ()> {
+        MlPutDatafeed::new(self.client, parts)
+    }
+    #[doc = "[Ml Put Filter API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-put-filter.html)\n\nInstantiates a filter."]
+    pub fn put_filter<'b>(&'a self, parts: MlPutFilterParts<'b>) -> MlPutFilter<'a, 'b, ()> {
+        MlPutFilter::new(self.client, parts)
+    }
+    #[doc = "[Ml Put Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-put-job.html)\n\nInstantiates an anomaly detection job."]
+    pub fn put_job<'b>(&'a self, parts: MlPutJobParts<'b>) -> MlPutJob<'a, 'b, ()> {
+        MlPutJob::new(self.client, parts)
+    }
+    #[doc = "[Ml Put Trained Model API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/put-trained-models.html)\n\nCreates an inference trained model."]
+    pub fn put_trained_model<'b>(
+        &'a self,
+        parts: MlPutTrainedModelParts<'b>,
+    ) -> MlPutTrainedModel<'a, 'b, ()> {
+        MlPutTrainedModel::new(self.client, parts)
+    }
+    #[doc = "[Ml Put Trained Model Alias API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/put-trained-models-aliases.html)\n\nCreates a new model alias (or reassigns an existing one) to refer to the trained model"]
+    pub fn put_trained_model_alias<'b>(
+        &'a self,
+        parts: MlPutTrainedModelAliasParts<'b>,
+    ) -> MlPutTrainedModelAlias<'a, 'b, ()> {
+        MlPutTrainedModelAlias::new(self.client, parts)
+    }
+    #[doc = "[Ml Reset Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-reset-job.html)\n\nResets an existing anomaly detection job."]
+    pub fn reset_job<'b>(&'a self, parts: MlResetJobParts<'b>) -> MlResetJob<'a, 'b, ()> {
+        MlResetJob::new(self.client, parts)
+    }
+    #[doc = "[Ml Revert Model Snapshot API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-revert-snapshot.html)\n\nReverts to a specific snapshot."]
+    pub fn revert_model_snapshot<'b>(
+        &'a self,
+        parts: MlRevertModelSnapshotParts<'b>,
+    ) -> MlRevertModelSnapshot<'a, 'b, ()> {
+        MlRevertModelSnapshot::new(self.client, parts)
+    }
+    #[doc = "[Ml Set Upgrade Mode API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-set-upgrade-mode.html)\n\nSets a cluster wide upgrade_mode setting that prepares machine learning indices for an upgrade."]
+    pub fn set_upgrade_mode<'b>(&'a self) -> MlSetUpgradeMode<'a, 'b, ()> {
+        MlSetUpgradeMode::new(self.client)
+    }
+    #[doc = "[Ml Start Data Frame Analytics API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/start-dfanalytics.html)\n\nStarts a data frame analytics job."]
+    pub fn start_data_frame_analytics<'b>(
+        &'a self,
+        parts: MlStartDataFrameAnalyticsParts<'b>,
+    ) -> MlStartDataFrameAnalytics<'a, 'b, ()> {
+        MlStartDataFrameAnalytics::new(self.client, parts)
+    }
+    #[doc = "[Ml Start Datafeed API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-start-datafeed.html)\n\nStarts one or more datafeeds."]
+    pub fn start_datafeed<'b>(
+        &'a self,
+        parts: MlStartDatafeedParts<'b>,
+    ) -> MlStartDatafeed<'a, 'b, ()> {
+        MlStartDatafeed::new(self.client, parts)
+    }
+    #[doc = "[Ml Start Trained Model Deployment API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/start-trained-model-deployment.html)\n\nStart a trained model deployment."]
+    #[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+    #[cfg(feature = "experimental-apis")]
+    pub fn start_trained_model_deployment<'b>(
+        &'a self,
+        parts: MlStartTrainedModelDeploymentParts<'b>,
+    ) -> MlStartTrainedModelDeployment<'a, 'b, ()> {
+        MlStartTrainedModelDeployment::new(self.client, parts)
+    }
+    #[doc = "[Ml Stop Data Frame Analytics API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/stop-dfanalytics.html)\n\nStops one or more data frame analytics jobs."]
+    pub fn stop_data_frame_analytics<'b>(
+        &'a self,
+        parts: MlStopDataFrameAnalyticsParts<'b>,
+    ) -> MlStopDataFrameAnalytics<'a, 'b, ()> {
+        MlStopDataFrameAnalytics::new(self.client, parts)
+    }
+    #[doc = "[Ml Stop Datafeed API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-stop-datafeed.html)\n\nStops one or more datafeeds."]
+    pub fn stop_datafeed<'b>(
+        &'a self,
+        parts: MlStopDatafeedParts<'b>,
+    ) -> MlStopDatafeed<'a, 'b, ()> {
+        MlStopDatafeed::new(self.client, parts)
+    }
+    #[doc = "[Ml Stop Trained Model Deployment API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/stop-trained-model-deployment.html)\n\nStop a trained model deployment."]
+    #[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+    #[cfg(feature = "experimental-apis")]
+    pub fn stop_trained_model_deployment<'b>(
+        &'a self,
+        parts: MlStopTrainedModelDeploymentParts<'b>,
+    ) -> MlStopTrainedModelDeployment<'a, 'b, ()> {
+        MlStopTrainedModelDeployment::new(self.client, parts)
+    }
+    #[doc = "[Ml Update Data Frame Analytics API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/update-dfanalytics.html)\n\nUpdates certain properties of a data frame analytics job."]
+    pub fn update_data_frame_analytics<'b>(
+        &'a self,
+        parts: MlUpdateDataFrameAnalyticsParts<'b>,
+    ) -> MlUpdateDataFrameAnalytics<'a, 'b, ()> {
+        MlUpdateDataFrameAnalytics::new(self.client, parts)
+    }
+    #[doc = "[Ml Update Datafeed API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-update-datafeed.html)\n\nUpdates certain properties of a datafeed."]
+    pub fn update_datafeed<'b>(
+        &'a self,
+        parts: MlUpdateDatafeedParts<'b>,
+    ) -> MlUpdateDatafeed<'a, 'b, ()> {
+        MlUpdateDatafeed::new(self.client, parts)
+    }
+    #[doc = "[Ml Update Filter API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-update-filter.html)\n\nUpdates the description of a filter, adds items, or removes items."]
+    pub fn update_filter<'b>(
+        &'a self,
+        parts: MlUpdateFilterParts<'b>,
+    ) -> MlUpdateFilter<'a, 'b, ()> {
+        MlUpdateFilter::new(self.client, parts)
+    }
+    #[doc = "[Ml Update Job API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-update-job.html)\n\nUpdates certain properties of an anomaly detection job."]
+    pub fn update_job<'b>(&'a self, parts: MlUpdateJobParts<'b>) -> MlUpdateJob<'a, 'b, ()> {
+        MlUpdateJob::new(self.client, parts)
+    }
+    #[doc = "[Ml Update Model Snapshot API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-update-snapshot.html)\n\nUpdates certain properties of a snapshot."]
+    pub fn update_model_snapshot<'b>(
+        &'a self,
+        parts: MlUpdateModelSnapshotParts<'b>,
+    ) -> MlUpdateModelSnapshot<'a, 'b, ()> {
+        MlUpdateModelSnapshot::new(self.client, parts)
+    }
+    #[doc = "[Ml Upgrade Job Snapshot API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/ml-upgrade-job-model-snapshot.html)\n\nUpgrades a given job snapshot to the current major version."]
+    pub fn upgrade_job_snapshot<'b>(
+        &'a self,
+        parts: MlUpgradeJobSnapshotParts<'b>,
+    ) -> MlUpgradeJobSnapshot<'a, 'b, ()> {
+        MlUpgradeJobSnapshot::new(self.client, parts)
+    }
+    #[doc = "[Ml Validate API](https://www.elastic.co/guide/en/machine-learning/8.0/ml-jobs.html)\n\nValidates an anomaly detection job."]
+    pub fn validate<'b>(&'a self) -> MlValidate<'a, 'b, ()> {
+        MlValidate::new(self.client)
+    }
+    #[doc = "[Ml Validate Detector API](https://www.elastic.co/guide/en/machine-learning/8.0/ml-jobs.html)\n\nValidates an anomaly detection detector."]
+    pub fn validate_detector<'b>(&'a self) -> MlValidateDetector<'a, 'b, ()> {
+        MlValidateDetector::new(self.client)
+    }
+}
+impl Elasticsearch {
+    #[doc = "Creates a namespace client for Machine Learning APIs"]
+    pub fn ml(&self) -> Ml {
+        Ml::new(self)
+    }
+}
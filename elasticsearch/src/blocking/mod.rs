@@ -0,0 +1,285 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! A blocking client for Elasticsearch
+//!
+//! The blocking [Elasticsearch] client has the same APIs as the asynchronous
+//! [Elasticsearch](crate::Elasticsearch) client, with request builders whose `send` blocks the
+//! current thread until the [Response] is returned. This requires the `blocking` feature.
+//!
+//! Requests are sent by the same [Transport] as the asynchronous client, driven by a runtime
+//! owned by the client, so the blocking client must not be used from within an
+//! asynchronous runtime.
+//!
+//! ```rust,no_run
+//! # use elasticsearch::{blocking::{Elasticsearch, cat::CatIndicesParts}, Error};
+//! # use serde_json::Value;
+//! # fn doc() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Elasticsearch::default();
+//! let response = client
+//!     .cat()
+//!     .indices(CatIndicesParts::None)
+//!     .format("json")
+//!     .send()?;
+//!
+//! let response_body = response.json::<Value>()?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    error::Error,
+    http::{
+        headers::HeaderMap,
+        request::Body,
+        response::{self, Exception},
+        transport::Transport,
+        Method, StatusCode, Url,
+    },
+};
+use bytes::Bytes;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fmt, future::Future, sync::Arc, time::Duration};
+use tokio::runtime::{self, Runtime};
+
+/// Blocking root client for top level APIs
+#[derive(Clone, Debug)]
+pub struct Elasticsearch {
+    client: crate::Elasticsearch,
+    runtime: Arc<Runtime>,
+}
+
+impl Elasticsearch {
+    /// Creates a new instance of the blocking root client
+    ///
+    /// # Panics
+    ///
+    /// Panics if the runtime used to send requests cannot be created.
+    pub fn new(transport: Transport) -> Self {
+        let runtime = runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("elasticsearch-blocking")
+            .enable_all()
+            .build()
+            .expect("could not create runtime for blocking client");
+
+        Self {
+            client: crate::Elasticsearch::new(transport),
+            runtime: Arc::new(runtime),
+        }
+    }
+
+    /// Gets the transport of the client
+    pub fn transport(&self) -> &Transport {
+        self.client.transport()
+    }
+
+    /// Creates a synchronous request that blocks until the response is returned
+    ///
+    /// Accepts the HTTP method and relative path to an API,
+    /// and optional query string and body.
+    pub fn send<B, Q>(
+        &self,
+        method: Method,
+        path: &str,
+        headers: HeaderMap,
+        query_string: Option<&Q>,
+        body: Option<B>,
+        timeout: Option<Duration>,
+    ) -> Result<Response, Error>
+    where
+        B: Body,
+        Q: Serialize + ?Sized,
+    {
+        self.block_on(
+            self.client
+                .send(method, path, headers, query_string, body, timeout),
+        )
+    }
+
+    /// Blocks on the future of an asynchronous API call
+    pub(crate) fn block_on<F>(&self, future: F) -> Result<Response, Error>
+    where
+        F: Future<Output = Result<response::Response, Error>>,
+    {
+        let response = self.runtime.block_on(future)?;
+        Ok(Response {
+            response,
+            runtime: self.runtime.clone(),
+        })
+    }
+}
+
+impl Default for Elasticsearch {
+    /// Creates a default instance of the blocking root client, using a default [Transport]
+    fn default() -> Self {
+        Self::new(Transport::default())
+    }
+}
+
+/// A response from Elasticsearch, whose body is read by blocking the current thread
+pub struct Response {
+    response: response::Response,
+    runtime: Arc<Runtime>,
+}
+
+impl Response {
+    /// Get the response content-length, if known.
+    ///
+    /// Reasons it may not be known:
+    ///
+    /// - The server didn't send a `content-length` header.
+    /// - The response is compressed and automatically decoded (thus changing
+    ///   the actual decoded length).
+    pub fn content_length(&self) -> Option<u64> {
+        self.response.content_length()
+    }
+
+    /// Gets the response content-type.
+    pub fn content_type(&self) -> &str {
+        self.response.content_type()
+    }
+
+    /// Turn the response into an [Error] if Elasticsearch returned an error.
+    pub fn error_for_status_code(self) -> Result<Self, Error> {
+        self.response.error_for_status_code_ref()?;
+        Ok(self)
+    }
+
+    /// Turn the response into an [Error] if Elasticsearch returned an error.
+    pub fn error_for_status_code_ref(&self) -> Result<&Self, Error> {
+        self.response.error_for_status_code_ref()?;
+        Ok(self)
+    }
+
+    /// Turn the response into an [Error] if Elasticsearch returned an error, reading the
+    /// response body into the [Exception] of the error.
+    ///
+    /// Reading the response body of an error consumes `self`
+    pub fn error_for_status_with_body(self) -> Result<Self, Error> {
+        let runtime = self.runtime.clone();
+        let response = runtime.block_on(self.response.error_for_status_with_body())?;
+        Ok(Response { response, runtime })
+    }
+
+    /// Reads the response body into an [Exception] if
+    /// Elasticsearch returned a HTTP status code in the 400-599 range.
+    ///
+    /// Reading the response body consumes `self`
+    pub fn exception(self) -> Result<Option<Exception>, Error> {
+        self.runtime.block_on(self.response.exception())
+    }
+
+    /// Reads the response body as JSON
+    ///
+    /// Reading the response body consumes `self`
+    pub fn json<B>(self) -> Result<B, Error>
+    where
+        B: DeserializeOwned,
+    {
+        self.runtime.block_on(self.response.json())
+    }
+
+    /// Gets the response headers.
+    pub fn headers(&self) -> &HeaderMap {
+        self.response.headers()
+    }
+
+    /// Gets the request method.
+    pub fn method(&self) -> Method {
+        self.response.method()
+    }
+
+    /// Get the HTTP status code of the response
+    pub fn status_code(&self) -> StatusCode {
+        self.response.status_code()
+    }
+
+    /// Reads the response body as plain text
+    ///
+    /// Reading the response body consumes `self`
+    pub fn text(self) -> Result<String, Error> {
+        self.runtime.block_on(self.response.text())
+    }
+
+    /// Reads the response body as bytes
+    ///
+    /// Reading the response body consumes `self`
+    pub fn bytes(self) -> Result<Bytes, Error> {
+        self.runtime.block_on(self.response.bytes())
+    }
+
+    /// Gets the request URL
+    pub fn url(&self) -> &Url {
+        self.response.url()
+    }
+
+    /// Gets the Deprecation warning response headers
+    ///
+    /// Deprecation headers signal the use of Elasticsearch functionality
+    /// or features that are deprecated and will be removed in a future release.
+    pub fn warning_headers(&self) -> impl Iterator<Item = &str> {
+        self.response.warning_headers()
+    }
+}
+
+impl fmt::Debug for Response {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.response, f)
+    }
+}
+
+mod root;
+pub use root::*;
+
+// GENERATED-BEGIN:namespace-modules
+// Generated code - do not edit until the next GENERATED-END marker
+
+pub mod async_search;
+pub mod autoscaling;
+pub mod cat;
+pub mod ccr;
+pub mod cluster;
+pub mod dangling_indices;
+pub mod enrich;
+pub mod eql;
+pub mod features;
+pub mod fleet;
+pub mod graph;
+pub mod ilm;
+pub mod indices;
+pub mod ingest;
+pub mod license;
+pub mod logstash;
+pub mod migration;
+pub mod ml;
+pub mod monitoring;
+pub mod nodes;
+pub mod rollup;
+pub mod searchable_snapshots;
+pub mod security;
+pub mod shutdown;
+pub mod slm;
+pub mod snapshot;
+pub mod sql;
+pub mod ssl;
+pub mod tasks;
+pub mod text_structure;
+pub mod transform;
+pub mod watcher;
+pub mod xpack;
+// GENERATED-END
@@ -0,0 +1,4831 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+// -----------------------------------------------
+// This file is generated, Please do not edit it manually.
+// Run the following in the root of the repo to regenerate:
+//
+// cargo make generate-api
+// -----------------------------------------------
+
+#![allow(unused_imports)]
+pub use crate::root::BulkParts;
+use crate::{
+    blocking::{Elasticsearch, Response},
+    error::Error,
+    http::{
+        headers::{HeaderName, HeaderValue},
+        request::{Body, JsonBody, NdBody},
+        transport::{NodeSelector, Transport},
+    },
+    params::*,
+};
+use serde::Serialize;
+use std::time::Duration;
+#[doc = "Builder for the [Bulk API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-bulk.html)\n\nAllows to perform multiple index/update/delete operations in a single request."]
+#[derive(Clone, Debug)]
+pub struct Bulk<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Bulk<'a, 'b, B>,
+}
+impl<'a, 'b, B> Bulk<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [Bulk] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: BulkParts<'b>) -> Self {
+        Bulk {
+            client,
+            builder: crate::root::Bulk::new(client.transport(), parts),
+        }
+    }
+    #[doc = "True or false to return the _source field or not, or default list of fields to return, can be overridden on each sub-request"]
+    pub fn _source(mut self, _source: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source(_source);
+        self
+    }
+    #[doc = "Default list of fields to exclude from the returned _source field, can be overridden on each sub-request"]
+    pub fn _source_excludes(mut self, _source_excludes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_excludes(_source_excludes);
+        self
+    }
+    #[doc = "Default list of fields to extract and return from the _source field, can be overridden on each sub-request"]
+    pub fn _source_includes(mut self, _source_includes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_includes(_source_includes);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: Vec<T>) -> Bulk<'a, 'b, NdBody<T>>
+    where
+        T: Body,
+    {
+        Bulk {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "The pipeline id to preprocess incoming documents with"]
+    pub fn pipeline(mut self, pipeline: &'b str) -> Self {
+        self.builder = self.builder.pipeline(pipeline);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "If `true` then refresh the affected shards to make this operation visible to search, if `wait_for` then wait for a refresh to make this operation visible to search, if `false` (the default) then do nothing with refreshes."]
+    pub fn refresh(mut self, refresh: Refresh) -> Self {
+        self.builder = self.builder.refresh(refresh);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Sets require_alias for all incoming documents. Defaults to unset (false)"]
+    pub fn require_alias(mut self, require_alias: bool) -> Self {
+        self.builder = self.builder.require_alias(require_alias);
+        self
+    }
+    #[doc = "Specific routing value"]
+    pub fn routing(mut self, routing: &'b str) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Default document type for items which don't provide one"]
+    pub fn ty(mut self, ty: &'b str) -> Self {
+        self.builder = self.builder.ty(ty);
+        self
+    }
+    #[doc = "Sets the number of shard copies that must be active before proceeding with the bulk operation. Defaults to 1, meaning the primary shard only. Set to `all` for all shard copies, otherwise set to any non-negative value less than or equal to the total number of copies for the shard (number of replicas + 1)"]
+    pub fn wait_for_active_shards(mut self, wait_for_active_shards: &'b str) -> Self {
+        self.builder = self.builder.wait_for_active_shards(wait_for_active_shards);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Bulk API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::ClearScrollParts;
+#[doc = "Builder for the [Clear Scroll API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/clear-scroll-api.html)\n\nExplicitly clears the search context for a scroll."]
+#[derive(Clone, Debug)]
+pub struct ClearScroll<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::ClearScroll<'a, 'b, B>,
+}
+impl<'a, 'b, B> ClearScroll<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [ClearScroll] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: ClearScrollParts<'b>) -> Self {
+        ClearScroll {
+            client,
+            builder: crate::root::ClearScroll::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> ClearScroll<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        ClearScroll {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Clear Scroll API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::ClosePointInTimeParts;
+#[doc = "Builder for the [Close Point In Time API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/point-in-time-api.html)\n\nClose a point in time"]
+#[derive(Clone, Debug)]
+pub struct ClosePointInTime<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::ClosePointInTime<'a, 'b, B>,
+}
+impl<'a, 'b, B> ClosePointInTime<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [ClosePointInTime]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        ClosePointInTime {
+            client,
+            builder: crate::root::ClosePointInTime::new(client.transport()),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> ClosePointInTime<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        ClosePointInTime {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Close Point In Time API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::CountParts;
+#[doc = "Builder for the [Count API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-count.html)\n\nReturns number of documents matching a query."]
+#[derive(Clone, Debug)]
+pub struct Count<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Count<'a, 'b, B>,
+}
+impl<'a, 'b, B> Count<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [Count] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CountParts<'b>) -> Self {
+        Count {
+            client,
+            builder: crate::root::Count::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)"]
+    pub fn allow_no_indices(mut self, allow_no_indices: bool) -> Self {
+        self.builder = self.builder.allow_no_indices(allow_no_indices);
+        self
+    }
+    #[doc = "Specify whether wildcard and prefix queries should be analyzed (default: false)"]
+    pub fn analyze_wildcard(mut self, analyze_wildcard: bool) -> Self {
+        self.builder = self.builder.analyze_wildcard(analyze_wildcard);
+        self
+    }
+    #[doc = "The analyzer to use for the query string"]
+    pub fn analyzer(mut self, analyzer: &'b str) -> Self {
+        self.builder = self.builder.analyzer(analyzer);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> Count<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        Count {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "The default operator for query string query (AND or OR)"]
+    pub fn default_operator(mut self, default_operator: DefaultOperator) -> Self {
+        self.builder = self.builder.default_operator(default_operator);
+        self
+    }
+    #[doc = "The field to use as default where no field prefix is given in the query string"]
+    pub fn df(mut self, df: &'b str) -> Self {
+        self.builder = self.builder.df(df);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether to expand wildcard expression to concrete indices that are open, closed or both."]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Whether specified concrete, expanded or aliased indices should be ignored when throttled"]
+    pub fn ignore_throttled(mut self, ignore_throttled: bool) -> Self {
+        self.builder = self.builder.ignore_throttled(ignore_throttled);
+        self
+    }
+    #[doc = "Whether specified concrete indices should be ignored when unavailable (missing or closed)"]
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.builder = self.builder.ignore_unavailable(ignore_unavailable);
+        self
+    }
+    #[doc = "Specify whether format-based query failures (such as providing text to a numeric field) should be ignored"]
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.builder = self.builder.lenient(lenient);
+        self
+    }
+    #[doc = "Include only documents with a specific `_score` value in the result"]
+    pub fn min_score(mut self, min_score: i64) -> Self {
+        self.builder = self.builder.min_score(min_score);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Query in the Lucene query string syntax"]
+    pub fn q(mut self, q: &'b str) -> Self {
+        self.builder = self.builder.q(q);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "A comma-separated list of specific routing values"]
+    pub fn routing(mut self, routing: &'b [&'b str]) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "The maximum count for each shard, upon reaching which the query execution will terminate early"]
+    pub fn terminate_after(mut self, terminate_after: i64) -> Self {
+        self.builder = self.builder.terminate_after(terminate_after);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Count API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::CreateParts;
+#[doc = "Builder for the [Create API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-index_.html)\n\nCreates a new document in the index.\n\nReturns a 409 response when a document with a same ID already exists in the index."]
+#[derive(Clone, Debug)]
+pub struct Create<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Create<'a, 'b, B>,
+}
+impl<'a, 'b, B> Create<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [Create] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: CreateParts<'b>) -> Self {
+        Create {
+            client,
+            builder: crate::root::Create::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> Create<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        Create {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "The pipeline id to preprocess incoming documents with"]
+    pub fn pipeline(mut self, pipeline: &'b str) -> Self {
+        self.builder = self.builder.pipeline(pipeline);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "If `true` then refresh the affected shards to make this operation visible to search, if `wait_for` then wait for a refresh to make this operation visible to search, if `false` (the default) then do nothing with refreshes."]
+    pub fn refresh(mut self, refresh: Refresh) -> Self {
+        self.builder = self.builder.refresh(refresh);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Specific routing value"]
+    pub fn routing(mut self, routing: &'b str) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Explicit version number for concurrency control"]
+    pub fn version(mut self, version: i64) -> Self {
+        self.builder = self.builder.version(version);
+        self
+    }
+    #[doc = "Specific version type"]
+    pub fn version_type(mut self, version_type: VersionType) -> Self {
+        self.builder = self.builder.version_type(version_type);
+        self
+    }
+    #[doc = "Sets the number of shard copies that must be active before proceeding with the index operation. Defaults to 1, meaning the primary shard only. Set to `all` for all shard copies, otherwise set to any non-negative value less than or equal to the total number of copies for the shard (number of replicas + 1)"]
+    pub fn wait_for_active_shards(mut self, wait_for_active_shards: &'b str) -> Self {
+        self.builder = self.builder.wait_for_active_shards(wait_for_active_shards);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Create API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::DeleteParts;
+#[doc = "Builder for the [Delete API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-delete.html)\n\nRemoves a document from the index."]
+#[derive(Clone, Debug)]
+pub struct Delete<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Delete<'a, 'b>,
+}
+impl<'a, 'b> Delete<'a, 'b> {
+    #[doc = "Creates a new instance of [Delete] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: DeleteParts<'b>) -> Self {
+        Delete {
+            client,
+            builder: crate::root::Delete::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "only perform the delete operation if the last operation that has changed the document has the specified primary term"]
+    pub fn if_primary_term(mut self, if_primary_term: i64) -> Self {
+        self.builder = self.builder.if_primary_term(if_primary_term);
+        self
+    }
+    #[doc = "only perform the delete operation if the last operation that has changed the document has the specified sequence number"]
+    pub fn if_seq_no(mut self, if_seq_no: i64) -> Self {
+        self.builder = self.builder.if_seq_no(if_seq_no);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "If `true` then refresh the affected shards to make this operation visible to search, if `wait_for` then wait for a refresh to make this operation visible to search, if `false` (the default) then do nothing with refreshes."]
+    pub fn refresh(mut self, refresh: Refresh) -> Self {
+        self.builder = self.builder.refresh(refresh);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Specific routing value"]
+    pub fn routing(mut self, routing: &'b str) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Explicit version number for concurrency control"]
+    pub fn version(mut self, version: i64) -> Self {
+        self.builder = self.builder.version(version);
+        self
+    }
+    #[doc = "Specific version type"]
+    pub fn version_type(mut self, version_type: VersionType) -> Self {
+        self.builder = self.builder.version_type(version_type);
+        self
+    }
+    #[doc = "Sets the number of shard copies that must be active before proceeding with the delete operation. Defaults to 1, meaning the primary shard only. Set to `all` for all shard copies, otherwise set to any non-negative value less than or equal to the total number of copies for the shard (number of replicas + 1)"]
+    pub fn wait_for_active_shards(mut self, wait_for_active_shards: &'b str) -> Self {
+        self.builder = self.builder.wait_for_active_shards(wait_for_active_shards);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Delete API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::DeleteByQueryParts;
+#[doc = "Builder for the [Delete By Query API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-delete-by-query.html)\n\nDeletes documents matching the provided query."]
+#[derive(Clone, Debug)]
+pub struct DeleteByQuery<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::DeleteByQuery<'a, 'b, B>,
+}
+impl<'a, 'b, B> DeleteByQuery<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [DeleteByQuery] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: DeleteByQueryParts<'b>) -> Self {
+        DeleteByQuery {
+            client,
+            builder: crate::root::DeleteByQuery::new(client.transport(), parts),
+        }
+    }
+    #[doc = "True or false to return the _source field or not, or a list of fields to return"]
+    pub fn _source(mut self, _source: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source(_source);
+        self
+    }
+    #[doc = "A list of fields to exclude from the returned _source field"]
+    pub fn _source_excludes(mut self, _source_excludes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_excludes(_source_excludes);
+        self
+    }
+    #[doc = "A list of fields to extract and return from the _source field"]
+    pub fn _source_includes(mut self, _source_includes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_includes(_source_includes);
+        self
+    }
+    #[doc = "Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)"]
+    pub fn allow_no_indices(mut self, allow_no_indices: bool) -> Self {
+        self.builder = self.builder.allow_no_indices(allow_no_indices);
+        self
+    }
+    #[doc = "Specify whether wildcard and prefix queries should be analyzed (default: false)"]
+    pub fn analyze_wildcard(mut self, analyze_wildcard: bool) -> Self {
+        self.builder = self.builder.analyze_wildcard(analyze_wildcard);
+        self
+    }
+    #[doc = "The analyzer to use for the query string"]
+    pub fn analyzer(mut self, analyzer: &'b str) -> Self {
+        self.builder = self.builder.analyzer(analyzer);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> DeleteByQuery<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        DeleteByQuery {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "What to do when the delete by query hits version conflicts?"]
+    pub fn conflicts(mut self, conflicts: Conflicts) -> Self {
+        self.builder = self.builder.conflicts(conflicts);
+        self
+    }
+    #[doc = "The default operator for query string query (AND or OR)"]
+    pub fn default_operator(mut self, default_operator: DefaultOperator) -> Self {
+        self.builder = self.builder.default_operator(default_operator);
+        self
+    }
+    #[doc = "The field to use as default where no field prefix is given in the query string"]
+    pub fn df(mut self, df: &'b str) -> Self {
+        self.builder = self.builder.df(df);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether to expand wildcard expression to concrete indices that are open, closed or both."]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Starting offset (default: 0)"]
+    pub fn from(mut self, from: i64) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Whether specified concrete indices should be ignored when unavailable (missing or closed)"]
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.builder = self.builder.ignore_unavailable(ignore_unavailable);
+        self
+    }
+    #[doc = "Specify whether format-based query failures (such as providing text to a numeric field) should be ignored"]
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.builder = self.builder.lenient(lenient);
+        self
+    }
+    #[doc = "Maximum number of documents to process (default: all documents)"]
+    pub fn max_docs(mut self, max_docs: i64) -> Self {
+        self.builder = self.builder.max_docs(max_docs);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Query in the Lucene query string syntax"]
+    pub fn q(mut self, q: &'b str) -> Self {
+        self.builder = self.builder.q(q);
+        self
+    }
+    #[doc = "Should the affected indexes be refreshed?"]
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.builder = self.builder.refresh(refresh);
+        self
+    }
+    #[doc = "Specify if request cache should be used for this request or not, defaults to index level setting"]
+    pub fn request_cache(mut self, request_cache: bool) -> Self {
+        self.builder = self.builder.request_cache(request_cache);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The throttle for this request in sub-requests per second. -1 means no throttle."]
+    pub fn requests_per_second(mut self, requests_per_second: i64) -> Self {
+        self.builder = self.builder.requests_per_second(requests_per_second);
+        self
+    }
+    #[doc = "A comma-separated list of specific routing values"]
+    pub fn routing(mut self, routing: &'b [&'b str]) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "Specify how long a consistent view of the index should be maintained for scrolled search"]
+    pub fn scroll(mut self, scroll: &'b str) -> Self {
+        self.builder = self.builder.scroll(scroll);
+        self
+    }
+    #[doc = "Size on the scroll request powering the delete by query"]
+    pub fn scroll_size(mut self, scroll_size: i64) -> Self {
+        self.builder = self.builder.scroll_size(scroll_size);
+        self
+    }
+    #[doc = "Explicit timeout for each search request. Defaults to no timeout."]
+    pub fn search_timeout(mut self, search_timeout: &'b str) -> Self {
+        self.builder = self.builder.search_timeout(search_timeout);
+        self
+    }
+    #[doc = "Search operation type"]
+    pub fn search_type(mut self, search_type: SearchType) -> Self {
+        self.builder = self.builder.search_type(search_type);
+        self
+    }
+    #[doc = "The number of slices this task should be divided into. Defaults to 1, meaning the task isn't sliced into subtasks. Can be set to `auto`."]
+    pub fn slices(mut self, slices: Slices) -> Self {
+        self.builder = self.builder.slices(slices);
+        self
+    }
+    #[doc = "A comma-separated list of <field>:<direction> pairs"]
+    pub fn sort(mut self, sort: &'b [&'b str]) -> Self {
+        self.builder = self.builder.sort(sort);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Specific 'tag' of the request for logging and statistical purposes"]
+    pub fn stats(mut self, stats: &'b [&'b str]) -> Self {
+        self.builder = self.builder.stats(stats);
+        self
+    }
+    #[doc = "The maximum number of documents to collect for each shard, upon reaching which the query execution will terminate early."]
+    pub fn terminate_after(mut self, terminate_after: i64) -> Self {
+        self.builder = self.builder.terminate_after(terminate_after);
+        self
+    }
+    #[doc = "Time each individual bulk request should wait for shards that are unavailable."]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Specify whether to return document version as part of a hit"]
+    pub fn version(mut self, version: bool) -> Self {
+        self.builder = self.builder.version(version);
+        self
+    }
+    #[doc = "Sets the number of shard copies that must be active before proceeding with the delete by query operation. Defaults to 1, meaning the primary shard only. Set to `all` for all shard copies, otherwise set to any non-negative value less than or equal to the total number of copies for the shard (number of replicas + 1)"]
+    pub fn wait_for_active_shards(mut self, wait_for_active_shards: &'b str) -> Self {
+        self.builder = self.builder.wait_for_active_shards(wait_for_active_shards);
+        self
+    }
+    #[doc = "Should the request should block until the delete by query is complete."]
+    pub fn wait_for_completion(mut self, wait_for_completion: bool) -> Self {
+        self.builder = self.builder.wait_for_completion(wait_for_completion);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Delete By Query API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::DeleteByQueryRethrottleParts;
+#[doc = "Builder for the [Delete By Query Rethrottle API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-delete-by-query.html)\n\nChanges the number of requests per second for a particular Delete By Query operation."]
+#[derive(Clone, Debug)]
+pub struct DeleteByQueryRethrottle<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::DeleteByQueryRethrottle<'a, 'b, B>,
+}
+impl<'a, 'b, B> DeleteByQueryRethrottle<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [DeleteByQueryRethrottle] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: DeleteByQueryRethrottleParts<'b>) -> Self {
+        DeleteByQueryRethrottle {
+            client,
+            builder: crate::root::DeleteByQueryRethrottle::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> DeleteByQueryRethrottle<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        DeleteByQueryRethrottle {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The throttle to set on this request in floating sub-requests per second. -1 means set no throttle."]
+    pub fn requests_per_second(mut self, requests_per_second: i64) -> Self {
+        self.builder = self.builder.requests_per_second(requests_per_second);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Delete By Query Rethrottle API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::DeleteScriptParts;
+#[doc = "Builder for the [Delete Script API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/modules-scripting.html)\n\nDeletes a script."]
+#[derive(Clone, Debug)]
+pub struct DeleteScript<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::root::DeleteScript<'a, 'b>,
+}
+impl<'a, 'b> DeleteScript<'a, 'b> {
+    #[doc = "Creates a new instance of [DeleteScript] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: DeleteScriptParts<'b>) -> Self {
+        DeleteScript {
+            client,
+            builder: crate::root::DeleteScript::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Specify timeout for connection to master"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Delete Script API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::ExistsParts;
+#[doc = "Builder for the [Exists API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-get.html)\n\nReturns information about whether a document exists in an index."]
+#[derive(Clone, Debug)]
+pub struct Exists<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Exists<'a, 'b>,
+}
+impl<'a, 'b> Exists<'a, 'b> {
+    #[doc = "Creates a new instance of [Exists] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: ExistsParts<'b>) -> Self {
+        Exists {
+            client,
+            builder: crate::root::Exists::new(client.transport(), parts),
+        }
+    }
+    #[doc = "True or false to return the _source field or not, or a list of fields to return"]
+    pub fn _source(mut self, _source: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source(_source);
+        self
+    }
+    #[doc = "A list of fields to exclude from the returned _source field"]
+    pub fn _source_excludes(mut self, _source_excludes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_excludes(_source_excludes);
+        self
+    }
+    #[doc = "A list of fields to extract and return from the _source field"]
+    pub fn _source_includes(mut self, _source_includes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_includes(_source_includes);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Specify whether to perform the operation in realtime or search mode"]
+    pub fn realtime(mut self, realtime: bool) -> Self {
+        self.builder = self.builder.realtime(realtime);
+        self
+    }
+    #[doc = "Refresh the shard containing the document before performing the operation"]
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.builder = self.builder.refresh(refresh);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Specific routing value"]
+    pub fn routing(mut self, routing: &'b str) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "A comma-separated list of stored fields to return in the response"]
+    pub fn stored_fields(mut self, stored_fields: &'b [&'b str]) -> Self {
+        self.builder = self.builder.stored_fields(stored_fields);
+        self
+    }
+    #[doc = "Explicit version number for concurrency control"]
+    pub fn version(mut self, version: i64) -> Self {
+        self.builder = self.builder.version(version);
+        self
+    }
+    #[doc = "Specific version type"]
+    pub fn version_type(mut self, version_type: VersionType) -> Self {
+        self.builder = self.builder.version_type(version_type);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Exists API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::ExistsSourceParts;
+#[doc = "Builder for the [Exists Source API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-get.html)\n\nReturns information about whether a document source exists in an index."]
+#[derive(Clone, Debug)]
+pub struct ExistsSource<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::root::ExistsSource<'a, 'b>,
+}
+impl<'a, 'b> ExistsSource<'a, 'b> {
+    #[doc = "Creates a new instance of [ExistsSource] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: ExistsSourceParts<'b>) -> Self {
+        ExistsSource {
+            client,
+            builder: crate::root::ExistsSource::new(client.transport(), parts),
+        }
+    }
+    #[doc = "True or false to return the _source field or not, or a list of fields to return"]
+    pub fn _source(mut self, _source: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source(_source);
+        self
+    }
+    #[doc = "A list of fields to exclude from the returned _source field"]
+    pub fn _source_excludes(mut self, _source_excludes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_excludes(_source_excludes);
+        self
+    }
+    #[doc = "A list of fields to extract and return from the _source field"]
+    pub fn _source_includes(mut self, _source_includes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_includes(_source_includes);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Specify whether to perform the operation in realtime or search mode"]
+    pub fn realtime(mut self, realtime: bool) -> Self {
+        self.builder = self.builder.realtime(realtime);
+        self
+    }
+    #[doc = "Refresh the shard containing the document before performing the operation"]
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.builder = self.builder.refresh(refresh);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Specific routing value"]
+    pub fn routing(mut self, routing: &'b str) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit version number for concurrency control"]
+    pub fn version(mut self, version: i64) -> Self {
+        self.builder = self.builder.version(version);
+        self
+    }
+    #[doc = "Specific version type"]
+    pub fn version_type(mut self, version_type: VersionType) -> Self {
+        self.builder = self.builder.version_type(version_type);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Exists Source API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::ExplainParts;
+#[doc = "Builder for the [Explain API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-explain.html)\n\nReturns information about why a specific matches (or doesn't match) a query."]
+#[derive(Clone, Debug)]
+pub struct Explain<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Explain<'a, 'b, B>,
+}
+impl<'a, 'b, B> Explain<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [Explain] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: ExplainParts<'b>) -> Self {
+        Explain {
+            client,
+            builder: crate::root::Explain::new(client.transport(), parts),
+        }
+    }
+    #[doc = "True or false to return the _source field or not, or a list of fields to return"]
+    pub fn _source(mut self, _source: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source(_source);
+        self
+    }
+    #[doc = "A list of fields to exclude from the returned _source field"]
+    pub fn _source_excludes(mut self, _source_excludes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_excludes(_source_excludes);
+        self
+    }
+    #[doc = "A list of fields to extract and return from the _source field"]
+    pub fn _source_includes(mut self, _source_includes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_includes(_source_includes);
+        self
+    }
+    #[doc = "Specify whether wildcards and prefix queries in the query string query should be analyzed (default: false)"]
+    pub fn analyze_wildcard(mut self, analyze_wildcard: bool) -> Self {
+        self.builder = self.builder.analyze_wildcard(analyze_wildcard);
+        self
+    }
+    #[doc = "The analyzer for the query string query"]
+    pub fn analyzer(mut self, analyzer: &'b str) -> Self {
+        self.builder = self.builder.analyzer(analyzer);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> Explain<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        Explain {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "The default operator for query string query (AND or OR)"]
+    pub fn default_operator(mut self, default_operator: DefaultOperator) -> Self {
+        self.builder = self.builder.default_operator(default_operator);
+        self
+    }
+    #[doc = "The default field for query string query (default: _all)"]
+    pub fn df(mut self, df: &'b str) -> Self {
+        self.builder = self.builder.df(df);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Specify whether format-based query failures (such as providing text to a numeric field) should be ignored"]
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.builder = self.builder.lenient(lenient);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Query in the Lucene query string syntax"]
+    pub fn q(mut self, q: &'b str) -> Self {
+        self.builder = self.builder.q(q);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Specific routing value"]
+    pub fn routing(mut self, routing: &'b str) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "A comma-separated list of stored fields to return in the response"]
+    pub fn stored_fields(mut self, stored_fields: &'b [&'b str]) -> Self {
+        self.builder = self.builder.stored_fields(stored_fields);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Explain API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::FieldCapsParts;
+#[doc = "Builder for the [Field Caps API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-field-caps.html)\n\nReturns the information about the capabilities of fields among multiple indices."]
+#[derive(Clone, Debug)]
+pub struct FieldCaps<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::FieldCaps<'a, 'b, B>,
+}
+impl<'a, 'b, B> FieldCaps<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [FieldCaps] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: FieldCapsParts<'b>) -> Self {
+        FieldCaps {
+            client,
+            builder: crate::root::FieldCaps::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)"]
+    pub fn allow_no_indices(mut self, allow_no_indices: bool) -> Self {
+        self.builder = self.builder.allow_no_indices(allow_no_indices);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> FieldCaps<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        FieldCaps {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether to expand wildcard expression to concrete indices that are open, closed or both."]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "A comma-separated list of field names"]
+    pub fn fields(mut self, fields: &'b [&'b str]) -> Self {
+        self.builder = self.builder.fields(fields);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Whether specified concrete indices should be ignored when unavailable (missing or closed)"]
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.builder = self.builder.ignore_unavailable(ignore_unavailable);
+        self
+    }
+    #[doc = "Indicates whether unmapped fields should be included in the response."]
+    pub fn include_unmapped(mut self, include_unmapped: bool) -> Self {
+        self.builder = self.builder.include_unmapped(include_unmapped);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Field Caps API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::GetParts;
+#[doc = "Builder for the [Get API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-get.html)\n\nReturns a document."]
+#[derive(Clone, Debug)]
+pub struct Get<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Get<'a, 'b>,
+}
+impl<'a, 'b> Get<'a, 'b> {
+    #[doc = "Creates a new instance of [Get] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: GetParts<'b>) -> Self {
+        Get {
+            client,
+            builder: crate::root::Get::new(client.transport(), parts),
+        }
+    }
+    #[doc = "True or false to return the _source field or not, or a list of fields to return"]
+    pub fn _source(mut self, _source: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source(_source);
+        self
+    }
+    #[doc = "A list of fields to exclude from the returned _source field"]
+    pub fn _source_excludes(mut self, _source_excludes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_excludes(_source_excludes);
+        self
+    }
+    #[doc = "A list of fields to extract and return from the _source field"]
+    pub fn _source_includes(mut self, _source_includes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_includes(_source_includes);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Specify whether to perform the operation in realtime or search mode"]
+    pub fn realtime(mut self, realtime: bool) -> Self {
+        self.builder = self.builder.realtime(realtime);
+        self
+    }
+    #[doc = "Refresh the shard containing the document before performing the operation"]
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.builder = self.builder.refresh(refresh);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Specific routing value"]
+    pub fn routing(mut self, routing: &'b str) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "A comma-separated list of stored fields to return in the response"]
+    pub fn stored_fields(mut self, stored_fields: &'b [&'b str]) -> Self {
+        self.builder = self.builder.stored_fields(stored_fields);
+        self
+    }
+    #[doc = "Explicit version number for concurrency control"]
+    pub fn version(mut self, version: i64) -> Self {
+        self.builder = self.builder.version(version);
+        self
+    }
+    #[doc = "Specific version type"]
+    pub fn version_type(mut self, version_type: VersionType) -> Self {
+        self.builder = self.builder.version_type(version_type);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Get API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::GetScriptParts;
+#[doc = "Builder for the [Get Script API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/modules-scripting.html)\n\nReturns a script."]
+#[derive(Clone, Debug)]
+pub struct GetScript<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::root::GetScript<'a, 'b>,
+}
+impl<'a, 'b> GetScript<'a, 'b> {
+    #[doc = "Creates a new instance of [GetScript] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: GetScriptParts<'b>) -> Self {
+        GetScript {
+            client,
+            builder: crate::root::GetScript::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Specify timeout for connection to master"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Get Script API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[cfg(feature = "experimental-apis")]
+pub use crate::root::GetScriptContextParts;
+#[doc = "Builder for the [Get Script Context API](https://www.elastic.co/guide/en/elasticsearch/painless/8.0/painless-contexts.html)\n\nReturns all script contexts."]
+#[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+#[cfg(feature = "experimental-apis")]
+#[derive(Clone, Debug)]
+pub struct GetScriptContext<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::root::GetScriptContext<'a, 'b>,
+}
+#[cfg(feature = "experimental-apis")]
+impl<'a, 'b> GetScriptContext<'a, 'b> {
+    #[doc = "Creates a new instance of [GetScriptContext]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        GetScriptContext {
+            client,
+            builder: crate::root::GetScriptContext::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Get Script Context API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[cfg(feature = "experimental-apis")]
+pub use crate::root::GetScriptLanguagesParts;
+#[doc = "Builder for the [Get Script Languages API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/modules-scripting.html)\n\nReturns available script types, languages and contexts"]
+#[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+#[cfg(feature = "experimental-apis")]
+#[derive(Clone, Debug)]
+pub struct GetScriptLanguages<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::root::GetScriptLanguages<'a, 'b>,
+}
+#[cfg(feature = "experimental-apis")]
+impl<'a, 'b> GetScriptLanguages<'a, 'b> {
+    #[doc = "Creates a new instance of [GetScriptLanguages]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        GetScriptLanguages {
+            client,
+            builder: crate::root::GetScriptLanguages::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Get Script Languages API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::GetSourceParts;
+#[doc = "Builder for the [Get Source API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-get.html)\n\nReturns the source of a document."]
+#[derive(Clone, Debug)]
+pub struct GetSource<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::root::GetSource<'a, 'b>,
+}
+impl<'a, 'b> GetSource<'a, 'b> {
+    #[doc = "Creates a new instance of [GetSource] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: GetSourceParts<'b>) -> Self {
+        GetSource {
+            client,
+            builder: crate::root::GetSource::new(client.transport(), parts),
+        }
+    }
+    #[doc = "True or false to return the _source field or not, or a list of fields to return"]
+    pub fn _source(mut self, _source: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source(_source);
+        self
+    }
+    #[doc = "A list of fields to exclude from the returned _source field"]
+    pub fn _source_excludes(mut self, _source_excludes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_excludes(_source_excludes);
+        self
+    }
+    #[doc = "A list of fields to extract and return from the _source field"]
+    pub fn _source_includes(mut self, _source_includes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_includes(_source_includes);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Specify whether to perform the operation in realtime or search mode"]
+    pub fn realtime(mut self, realtime: bool) -> Self {
+        self.builder = self.builder.realtime(realtime);
+        self
+    }
+    #[doc = "Refresh the shard containing the document before performing the operation"]
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.builder = self.builder.refresh(refresh);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Specific routing value"]
+    pub fn routing(mut self, routing: &'b str) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit version number for concurrency control"]
+    pub fn version(mut self, version: i64) -> Self {
+        self.builder = self.builder.version(version);
+        self
+    }
+    #[doc = "Specific version type"]
+    pub fn version_type(mut self, version_type: VersionType) -> Self {
+        self.builder = self.builder.version_type(version_type);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Get Source API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::IndexParts;
+#[doc = "Builder for the [Index API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-index_.html)\n\nCreates or updates a document in an index."]
+#[derive(Clone, Debug)]
+pub struct Index<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Index<'a, 'b, B>,
+}
+impl<'a, 'b, B> Index<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [Index] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: IndexParts<'b>) -> Self {
+        Index {
+            client,
+            builder: crate::root::Index::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> Index<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        Index {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "only perform the index operation if the last operation that has changed the document has the specified primary term"]
+    pub fn if_primary_term(mut self, if_primary_term: i64) -> Self {
+        self.builder = self.builder.if_primary_term(if_primary_term);
+        self
+    }
+    #[doc = "only perform the index operation if the last operation that has changed the document has the specified sequence number"]
+    pub fn if_seq_no(mut self, if_seq_no: i64) -> Self {
+        self.builder = self.builder.if_seq_no(if_seq_no);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Explicit operation type. Defaults to `index` for requests with an explicit document ID, and to `create`for requests without an explicit document ID"]
+    pub fn op_type(mut self, op_type: OpType) -> Self {
+        self.builder = self.builder.op_type(op_type);
+        self
+    }
+    #[doc = "The pipeline id to preprocess incoming documents with"]
+    pub fn pipeline(mut self, pipeline: &'b str) -> Self {
+        self.builder = self.builder.pipeline(pipeline);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "If `true` then refresh the affected shards to make this operation visible to search, if `wait_for` then wait for a refresh to make this operation visible to search, if `false` (the default) then do nothing with refreshes."]
+    pub fn refresh(mut self, refresh: Refresh) -> Self {
+        self.builder = self.builder.refresh(refresh);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "When true, requires destination to be an alias. Default is false"]
+    pub fn require_alias(mut self, require_alias: bool) -> Self {
+        self.builder = self.builder.require_alias(require_alias);
+        self
+    }
+    #[doc = "Specific routing value"]
+    pub fn routing(mut self, routing: &'b str) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Explicit version number for concurrency control"]
+    pub fn version(mut self, version: i64) -> Self {
+        self.builder = self.builder.version(version);
+        self
+    }
+    #[doc = "Specific version type"]
+    pub fn version_type(mut self, version_type: VersionType) -> Self {
+        self.builder = self.builder.version_type(version_type);
+        self
+    }
+    #[doc = "Sets the number of shard copies that must be active before proceeding with the index operation. Defaults to 1, meaning the primary shard only. Set to `all` for all shard copies, otherwise set to any non-negative value less than or equal to the total number of copies for the shard (number of replicas + 1)"]
+    pub fn wait_for_active_shards(mut self, wait_for_active_shards: &'b str) -> Self {
+        self.builder = self.builder.wait_for_active_shards(wait_for_active_shards);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Index API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::InfoParts;
+#[doc = "Builder for the [Info API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/index.html)\n\nReturns basic information about the cluster."]
+#[derive(Clone, Debug)]
+pub struct Info<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Info<'a, 'b>,
+}
+impl<'a, 'b> Info<'a, 'b> {
+    #[doc = "Creates a new instance of [Info]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        Info {
+            client,
+            builder: crate::root::Info::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Info API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::MgetParts;
+#[doc = "Builder for the [Mget API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-multi-get.html)\n\nAllows to get multiple documents in one request."]
+#[derive(Clone, Debug)]
+pub struct Mget<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Mget<'a, 'b, B>,
+}
+impl<'a, 'b, B> Mget<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [Mget] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MgetParts<'b>) -> Self {
+        Mget {
+            client,
+            builder: crate::root::Mget::new(client.transport(), parts),
+        }
+    }
+    #[doc = "True or false to return the _source field or not, or a list of fields to return"]
+    pub fn _source(mut self, _source: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source(_source);
+        self
+    }
+    #[doc = "A list of fields to exclude from the returned _source field"]
+    pub fn _source_excludes(mut self, _source_excludes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_excludes(_source_excludes);
+        self
+    }
+    #[doc = "A list of fields to extract and return from the _source field"]
+    pub fn _source_includes(mut self, _source_includes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_includes(_source_includes);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> Mget<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        Mget {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Specify whether to perform the operation in realtime or search mode"]
+    pub fn realtime(mut self, realtime: bool) -> Self {
+        self.builder = self.builder.realtime(realtime);
+        self
+    }
+    #[doc = "Refresh the shard containing the document before performing the operation"]
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.builder = self.builder.refresh(refresh);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Specific routing value"]
+    pub fn routing(mut self, routing: &'b str) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "A comma-separated list of stored fields to return in the response"]
+    pub fn stored_fields(mut self, stored_fields: &'b [&'b str]) -> Self {
+        self.builder = self.builder.stored_fields(stored_fields);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Mget API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::MsearchParts;
+#[doc = "Builder for the [Msearch API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-multi-search.html)\n\nAllows to execute several search operations in one request."]
+#[derive(Clone, Debug)]
+pub struct Msearch<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Msearch<'a, 'b, B>,
+}
+impl<'a, 'b, B> Msearch<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [Msearch] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MsearchParts<'b>) -> Self {
+        Msearch {
+            client,
+            builder: crate::root::Msearch::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: Vec<T>) -> Msearch<'a, 'b, NdBody<T>>
+    where
+        T: Body,
+    {
+        Msearch {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Indicates whether network round-trips should be minimized as part of cross-cluster search requests execution"]
+    pub fn ccs_minimize_roundtrips(mut self, ccs_minimize_roundtrips: bool) -> Self {
+        self.builder = self
+            .builder
+            .ccs_minimize_roundtrips(ccs_minimize_roundtrips);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Controls the maximum number of concurrent searches the multi search api will execute"]
+    pub fn max_concurrent_searches(mut self, max_concurrent_searches: i64) -> Self {
+        self.builder = self
+            .builder
+            .max_concurrent_searches(max_concurrent_searches);
+        self
+    }
+    #[doc = "The number of concurrent shard requests each sub search executes concurrently per node. This value should be used to limit the impact of the search on the cluster in order to limit the number of concurrent shard requests"]
+    pub fn max_concurrent_shard_requests(mut self, max_concurrent_shard_requests: i64) -> Self {
+        self.builder = self
+            .builder
+            .max_concurrent_shard_requests(max_concurrent_shard_requests);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "A threshold that enforces a pre-filter roundtrip to prefilter search shards based on query rewriting if the\u{a0}number of shards the search request expands to exceeds the threshold. This filter roundtrip can limit the number of shards significantly if for instance a shard can not match any documents based on its rewrite method ie. if date filters are mandatory to match but the shard bounds and the query are disjoint."]
+    pub fn pre_filter_shard_size(mut self, pre_filter_shard_size: i64) -> Self {
+        self.builder = self.builder.pre_filter_shard_size(pre_filter_shard_size);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Indicates whether hits.total should be rendered as an integer or an object in the rest search response"]
+    pub fn rest_total_hits_as_int(mut self, rest_total_hits_as_int: bool) -> Self {
+        self.builder = self.builder.rest_total_hits_as_int(rest_total_hits_as_int);
+        self
+    }
+    #[doc = "Search operation type"]
+    pub fn search_type(mut self, search_type: SearchType) -> Self {
+        self.builder = self.builder.search_type(search_type);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Specify whether aggregation and suggester names should be prefixed by their respective types in the response"]
+    pub fn typed_keys(mut self, typed_keys: bool) -> Self {
+        self.builder = self.builder.typed_keys(typed_keys);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Msearch API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::MsearchTemplateParts;
+#[doc = "Builder for the [Msearch Template API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-multi-search.html)\n\nAllows to execute several search template operations in one request."]
+#[derive(Clone, Debug)]
+pub struct MsearchTemplate<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::MsearchTemplate<'a, 'b, B>,
+}
+impl<'a, 'b, B> MsearchTemplate<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [MsearchTemplate] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MsearchTemplateParts<'b>) -> Self {
+        MsearchTemplate {
+            client,
+            builder: crate::root::MsearchTemplate::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: Vec<T>) -> MsearchTemplate<'a, 'b, NdBody<T>>
+    where
+        T: Body,
+    {
+        MsearchTemplate {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Indicates whether network round-trips should be minimized as part of cross-cluster search requests execution"]
+    pub fn ccs_minimize_roundtrips(mut self, ccs_minimize_roundtrips: bool) -> Self {
+        self.builder = self
+            .builder
+            .ccs_minimize_roundtrips(ccs_minimize_roundtrips);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Controls the maximum number of concurrent searches the multi search api will execute"]
+    pub fn max_concurrent_searches(mut self, max_concurrent_searches: i64) -> Self {
+        self.builder = self
+            .builder
+            .max_concurrent_searches(max_concurrent_searches);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Indicates whether hits.total should be rendered as an integer or an object in the rest search response"]
+    pub fn rest_total_hits_as_int(mut self, rest_total_hits_as_int: bool) -> Self {
+        self.builder = self.builder.rest_total_hits_as_int(rest_total_hits_as_int);
+        self
+    }
+    #[doc = "Search operation type"]
+    pub fn search_type(mut self, search_type: SearchType) -> Self {
+        self.builder = self.builder.search_type(search_type);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Specify whether aggregation and suggester names should be prefixed by their respective types in the response"]
+    pub fn typed_keys(mut self, typed_keys: bool) -> Self {
+        self.builder = self.builder.typed_keys(typed_keys);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Msearch Template API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::MtermvectorsParts;
+#[doc = "Builder for the [Mtermvectors API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-multi-termvectors.html)\n\nReturns multiple termvectors in one request."]
+#[derive(Clone, Debug)]
+pub struct Mtermvectors<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Mtermvectors<'a, 'b, B>,
+}
+impl<'a, 'b, B> Mtermvectors<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [Mtermvectors] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: MtermvectorsParts<'b>) -> Self {
+        Mtermvectors {
+            client,
+            builder: crate::root::Mtermvectors::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> Mtermvectors<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        Mtermvectors {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Specifies if document count, sum of document frequencies and sum of total term frequencies should be returned. Applies to all returned documents unless otherwise specified in body \"params\" or \"docs\"."]
+    pub fn field_statistics(mut self, field_statistics: bool) -> Self {
+        self.builder = self.builder.field_statistics(field_statistics);
+        self
+    }
+    #[doc = "A comma-separated list of fields to return. Applies to all returned documents unless otherwise specified in body \"params\" or \"docs\"."]
+    pub fn fields(mut self, fields: &'b [&'b str]) -> Self {
+        self.builder = self.builder.fields(fields);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "A comma-separated list of documents ids. You must define ids as parameter or set \"ids\" or \"docs\" in the request body"]
+    pub fn ids(mut self, ids: &'b [&'b str]) -> Self {
+        self.builder = self.builder.ids(ids);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Specifies if term offsets should be returned. Applies to all returned documents unless otherwise specified in body \"params\" or \"docs\"."]
+    pub fn offsets(mut self, offsets: bool) -> Self {
+        self.builder = self.builder.offsets(offsets);
+        self
+    }
+    #[doc = "Specifies if term payloads should be returned. Applies to all returned documents unless otherwise specified in body \"params\" or \"docs\"."]
+    pub fn payloads(mut self, payloads: bool) -> Self {
+        self.builder = self.builder.payloads(payloads);
+        self
+    }
+    #[doc = "Specifies if term positions should be returned. Applies to all returned documents unless otherwise specified in body \"params\" or \"docs\"."]
+    pub fn positions(mut self, positions: bool) -> Self {
+        self.builder = self.builder.positions(positions);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random) .Applies to all returned documents unless otherwise specified in body \"params\" or \"docs\"."]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Specifies if requests are real-time as opposed to near-real-time (default: true)."]
+    pub fn realtime(mut self, realtime: bool) -> Self {
+        self.builder = self.builder.realtime(realtime);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Specific routing value. Applies to all returned documents unless otherwise specified in body \"params\" or \"docs\"."]
+    pub fn routing(mut self, routing: &'b str) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Specifies if total term frequency and document frequency should be returned. Applies to all returned documents unless otherwise specified in body \"params\" or \"docs\"."]
+    pub fn term_statistics(mut self, term_statistics: bool) -> Self {
+        self.builder = self.builder.term_statistics(term_statistics);
+        self
+    }
+    #[doc = "Explicit version number for concurrency control"]
+    pub fn version(mut self, version: i64) -> Self {
+        self.builder = self.builder.version(version);
+        self
+    }
+    #[doc = "Specific version type"]
+    pub fn version_type(mut self, version_type: VersionType) -> Self {
+        self.builder = self.builder.version_type(version_type);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Mtermvectors API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::OpenPointInTimeParts;
+#[doc = "Builder for the [Open Point In Time API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/point-in-time-api.html)\n\nOpen a point in time that can be used in subsequent searches"]
+#[derive(Clone, Debug)]
+pub struct OpenPointInTime<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::OpenPointInTime<'a, 'b, B>,
+}
+impl<'a, 'b, B> OpenPointInTime<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [OpenPointInTime] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: OpenPointInTimeParts<'b>) -> Self {
+        OpenPointInTime {
+            client,
+            builder: crate::root::OpenPointInTime::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> OpenPointInTime<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        OpenPointInTime {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether to expand wildcard expression to concrete indices that are open, closed or both."]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Whether specified concrete indices should be ignored when unavailable (missing or closed)"]
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.builder = self.builder.ignore_unavailable(ignore_unavailable);
+        self
+    }
+    #[doc = "Specific the time to live for the point in time"]
+    pub fn keep_alive(mut self, keep_alive: &'b str) -> Self {
+        self.builder = self.builder.keep_alive(keep_alive);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Specific routing value"]
+    pub fn routing(mut self, routing: &'b str) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Open Point In Time API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::PingParts;
+#[doc = "Builder for the [Ping API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/index.html)\n\nReturns whether the cluster is running."]
+#[derive(Clone, Debug)]
+pub struct Ping<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Ping<'a, 'b>,
+}
+impl<'a, 'b> Ping<'a, 'b> {
+    #[doc = "Creates a new instance of [Ping]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        Ping {
+            client,
+            builder: crate::root::Ping::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Ping API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::PutScriptParts;
+#[doc = "Builder for the [Put Script API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/modules-scripting.html)\n\nCreates or updates a script."]
+#[derive(Clone, Debug)]
+pub struct PutScript<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::PutScript<'a, 'b, B>,
+}
+impl<'a, 'b, B> PutScript<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [PutScript] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: PutScriptParts<'b>) -> Self {
+        PutScript {
+            client,
+            builder: crate::root::PutScript::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> PutScript<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        PutScript {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Context name to compile script against"]
+    pub fn context(mut self, context: &'b str) -> Self {
+        self.builder = self.builder.context(context);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Specify timeout for connection to master"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Put Script API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[cfg(feature = "experimental-apis")]
+pub use crate::root::RankEvalParts;
+#[doc = "Builder for the [Rank Eval API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-rank-eval.html)\n\nAllows to evaluate the quality of ranked search results over a set of typical search queries"]
+#[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+#[cfg(feature = "experimental-apis")]
+#[derive(Clone, Debug)]
+pub struct RankEval<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::RankEval<'a, 'b, B>,
+}
+#[cfg(feature = "experimental-apis")]
+impl<'a, 'b, B> RankEval<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [RankEval] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: RankEvalParts<'b>) -> Self {
+        RankEval {
+            client,
+            builder: crate::root::RankEval::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)"]
+    pub fn allow_no_indices(mut self, allow_no_indices: bool) -> Self {
+        self.builder = self.builder.allow_no_indices(allow_no_indices);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> RankEval<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        RankEval {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether to expand wildcard expression to concrete indices that are open, closed or both."]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Whether specified concrete indices should be ignored when unavailable (missing or closed)"]
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.builder = self.builder.ignore_unavailable(ignore_unavailable);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Search operation type"]
+    pub fn search_type(mut self, search_type: SearchType) -> Self {
+        self.builder = self.builder.search_type(search_type);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Rank Eval API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::ReindexParts;
+#[doc = "Builder for the [Reindex API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-reindex.html)\n\nAllows to copy documents from one index to another, optionally filtering the source\ndocuments by a query, changing the destination index settings, or fetching the\ndocuments from a remote cluster."]
+#[derive(Clone, Debug)]
+pub struct Reindex<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Reindex<'a, 'b, B>,
+}
+impl<'a, 'b, B> Reindex<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [Reindex]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        Reindex {
+            client,
+            builder: crate::root::Reindex::new(client.transport()),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> Reindex<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        Reindex {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Maximum number of documents to process (default: all documents)"]
+    pub fn max_docs(mut self, max_docs: i64) -> Self {
+        self.builder = self.builder.max_docs(max_docs);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Should the affected indexes be refreshed?"]
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.builder = self.builder.refresh(refresh);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The throttle to set on this request in sub-requests per second. -1 means no throttle."]
+    pub fn requests_per_second(mut self, requests_per_second: i64) -> Self {
+        self.builder = self.builder.requests_per_second(requests_per_second);
+        self
+    }
+    #[doc = "Control how long to keep the search context alive"]
+    pub fn scroll(mut self, scroll: &'b str) -> Self {
+        self.builder = self.builder.scroll(scroll);
+        self
+    }
+    #[doc = "The number of slices this task should be divided into. Defaults to 1, meaning the task isn't sliced into subtasks. Can be set to `auto`."]
+    pub fn slices(mut self, slices: Slices) -> Self {
+        self.builder = self.builder.slices(slices);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Time each individual bulk request should wait for shards that are unavailable."]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Sets the number of shard copies that must be active before proceeding with the reindex operation. Defaults to 1, meaning the primary shard only. Set to `all` for all shard copies, otherwise set to any non-negative value less than or equal to the total number of copies for the shard (number of replicas + 1)"]
+    pub fn wait_for_active_shards(mut self, wait_for_active_shards: &'b str) -> Self {
+        self.builder = self.builder.wait_for_active_shards(wait_for_active_shards);
+        self
+    }
+    #[doc = "Should the request should block until the reindex is complete."]
+    pub fn wait_for_completion(mut self, wait_for_completion: bool) -> Self {
+        self.builder = self.builder.wait_for_completion(wait_for_completion);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Reindex API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::ReindexRethrottleParts;
+#[doc = "Builder for the [Reindex Rethrottle API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-reindex.html)\n\nChanges the number of requests per second for a particular Reindex operation."]
+#[derive(Clone, Debug)]
+pub struct ReindexRethrottle<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::ReindexRethrottle<'a, 'b, B>,
+}
+impl<'a, 'b, B> ReindexRethrottle<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [ReindexRethrottle] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: ReindexRethrottleParts<'b>) -> Self {
+        ReindexRethrottle {
+            client,
+            builder: crate::root::ReindexRethrottle::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> ReindexRethrottle<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        ReindexRethrottle {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The throttle to set on this request in floating sub-requests per second. -1 means set no throttle."]
+    pub fn requests_per_second(mut self, requests_per_second: i64) -> Self {
+        self.builder = self.builder.requests_per_second(requests_per_second);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Reindex Rethrottle API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::RenderSearchTemplateParts;
+#[doc = "Builder for the [Render Search Template API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/render-search-template-api.html)\n\nAllows to use the Mustache language to pre-render a search definition."]
+#[derive(Clone, Debug)]
+pub struct RenderSearchTemplate<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::RenderSearchTemplate<'a, 'b, B>,
+}
+impl<'a, 'b, B> RenderSearchTemplate<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [RenderSearchTemplate] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: RenderSearchTemplateParts<'b>) -> Self {
+        RenderSearchTemplate {
+            client,
+            builder: crate::root::RenderSearchTemplate::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> RenderSearchTemplate<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        RenderSearchTemplate {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Render Search Template API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[cfg(feature = "experimental-apis")]
+pub use crate::root::ScriptsPainlessExecuteParts;
+#[doc = "Builder for the [Scripts Painless Execute API](https://www.elastic.co/guide/en/elasticsearch/painless/8.0/painless-execute-api.html)\n\nAllows an arbitrary script to be executed and a result to be returned"]
+#[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+#[cfg(feature = "experimental-apis")]
+#[derive(Clone, Debug)]
+pub struct ScriptsPainlessExecute<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::ScriptsPainlessExecute<'a, 'b, B>,
+}
+#[cfg(feature = "experimental-apis")]
+impl<'a, 'b, B> ScriptsPainlessExecute<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [ScriptsPainlessExecute]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        ScriptsPainlessExecute {
+            client,
+            builder: crate::root::ScriptsPainlessExecute::new(client.transport()),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> ScriptsPainlessExecute<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        ScriptsPainlessExecute {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Scripts Painless Execute API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::ScrollParts;
+#[doc = "Builder for the [Scroll API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-request-body.html#request-body-search-scroll)\n\nAllows to retrieve a large numbers of results from a single search request."]
+#[derive(Clone, Debug)]
+pub struct Scroll<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Scroll<'a, 'b, B>,
+}
+impl<'a, 'b, B> Scroll<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [Scroll] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: ScrollParts<'b>) -> Self {
+        Scroll {
+            client,
+            builder: crate::root::Scroll::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> Scroll<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        Scroll {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Indicates whether hits.total should be rendered as an integer or an object in the rest search response"]
+    pub fn rest_total_hits_as_int(mut self, rest_total_hits_as_int: bool) -> Self {
+        self.builder = self.builder.rest_total_hits_as_int(rest_total_hits_as_int);
+        self
+    }
+    #[doc = "Specify how long a consistent view of the index should be maintained for scrolled search"]
+    pub fn scroll(mut self, scroll: &'b str) -> Self {
+        self.builder = self.builder.scroll(scroll);
+        self
+    }
+    #[doc = "The scroll ID for scrolled search"]
+    pub fn scroll_id(mut self, scroll_id: &'b str) -> Self {
+        self.builder = self.builder.scroll_id(scroll_id);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Scroll API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::SearchParts;
+#[doc = "Builder for the [Search API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-search.html)\n\nReturns results matching a query."]
+#[derive(Clone, Debug)]
+pub struct Search<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Search<'a, 'b, B>,
+}
+impl<'a, 'b, B> Search<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [Search] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: SearchParts<'b>) -> Self {
+        Search {
+            client,
+            builder: crate::root::Search::new(client.transport(), parts),
+        }
+    }
+    #[doc = "True or false to return the _source field or not, or a list of fields to return"]
+    pub fn _source(mut self, _source: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source(_source);
+        self
+    }
+    #[doc = "A list of fields to exclude from the returned _source field"]
+    pub fn _source_excludes(mut self, _source_excludes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_excludes(_source_excludes);
+        self
+    }
+    #[doc = "A list of fields to extract and return from the _source field"]
+    pub fn _source_includes(mut self, _source_includes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_includes(_source_includes);
+        self
+    }
+    #[doc = "Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)"]
+    pub fn allow_no_indices(mut self, allow_no_indices: bool) -> Self {
+        self.builder = self.builder.allow_no_indices(allow_no_indices);
+        self
+    }
+    #[doc = "Indicate if an error should be returned if there is a partial search failure or timeout"]
+    pub fn allow_partial_search_results(mut self, allow_partial_search_results: bool) -> Self {
+        self.builder = self
+            .builder
+            .allow_partial_search_results(allow_partial_search_results);
+        self
+    }
+    #[doc = "Specify whether wildcard and prefix queries should be analyzed (default: false)"]
+    pub fn analyze_wildcard(mut self, analyze_wildcard: bool) -> Self {
+        self.builder = self.builder.analyze_wildcard(analyze_wildcard);
+        self
+    }
+    #[doc = "The analyzer to use for the query string"]
+    pub fn analyzer(mut self, analyzer: &'b str) -> Self {
+        self.builder = self.builder.analyzer(analyzer);
+        self
+    }
+    #[doc = "The number of shard results that should be reduced at once on the coordinating node. This value should be used as a protection mechanism to reduce the memory overhead per search request if the potential number of shards in the request can be large."]
+    pub fn batched_reduce_size(mut self, batched_reduce_size: i64) -> Self {
+        self.builder = self.builder.batched_reduce_size(batched_reduce_size);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> Search<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        Search {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Indicates whether network round-trips should be minimized as part of cross-cluster search requests execution"]
+    pub fn ccs_minimize_roundtrips(mut self, ccs_minimize_roundtrips: bool) -> Self {
+        self.builder = self
+            .builder
+            .ccs_minimize_roundtrips(ccs_minimize_roundtrips);
+        self
+    }
+    #[doc = "The default operator for query string query (AND or OR)"]
+    pub fn default_operator(mut self, default_operator: DefaultOperator) -> Self {
+        self.builder = self.builder.default_operator(default_operator);
+        self
+    }
+    #[doc = "The field to use as default where no field prefix is given in the query string"]
+    pub fn df(mut self, df: &'b str) -> Self {
+        self.builder = self.builder.df(df);
+        self
+    }
+    #[doc = "A comma-separated list of fields to return as the docvalue representation of a field for each hit"]
+    pub fn docvalue_fields(mut self, docvalue_fields: &'b [&'b str]) -> Self {
+        self.builder = self.builder.docvalue_fields(docvalue_fields);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether to expand wildcard expression to concrete indices that are open, closed or both."]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "Specify whether to return detailed information about score computation as part of a hit"]
+    pub fn explain(mut self, explain: bool) -> Self {
+        self.builder = self.builder.explain(explain);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Starting offset (default: 0)"]
+    pub fn from(mut self, from: i64) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Whether specified concrete, expanded or aliased indices should be ignored when throttled"]
+    pub fn ignore_throttled(mut self, ignore_throttled: bool) -> Self {
+        self.builder = self.builder.ignore_throttled(ignore_throttled);
+        self
+    }
+    #[doc = "Whether specified concrete indices should be ignored when unavailable (missing or closed)"]
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.builder = self.builder.ignore_unavailable(ignore_unavailable);
+        self
+    }
+    #[doc = "Specify whether format-based query failures (such as providing text to a numeric field) should be ignored"]
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.builder = self.builder.lenient(lenient);
+        self
+    }
+    #[doc = "The number of concurrent shard requests per node this search executes concurrently. This value should be used to limit the impact of the search on the cluster in order to limit the number of concurrent shard requests"]
+    pub fn max_concurrent_shard_requests(mut self, max_concurrent_shard_requests: i64) -> Self {
+        self.builder = self
+            .builder
+            .max_concurrent_shard_requests(max_concurrent_shard_requests);
+        self
+    }
+    #[doc = "The minimum compatible version that all shards involved in search should have for this request to be successful"]
+    pub fn min_compatible_shard_node(mut self, min_compatible_shard_node: &'b str) -> Self {
+        self.builder = self
+            .builder
+            .min_compatible_shard_node(min_compatible_shard_node);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "A threshold that enforces a pre-filter roundtrip to prefilter search shards based on query rewriting if the\u{a0}number of shards the search request expands to exceeds the threshold. This filter roundtrip can limit the number of shards significantly if for instance a shard can not match any documents based on its rewrite method ie. if date filters are mandatory to match but the shard bounds and the query are disjoint."]
+    pub fn pre_filter_shard_size(mut self, pre_filter_shard_size: i64) -> Self {
+        self.builder = self.builder.pre_filter_shard_size(pre_filter_shard_size);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Query in the Lucene query string syntax"]
+    pub fn q(mut self, q: &'b str) -> Self {
+        self.builder = self.builder.q(q);
+        self
+    }
+    #[doc = "Specify if request cache should be used for this request or not, defaults to index level setting"]
+    pub fn request_cache(mut self, request_cache: bool) -> Self {
+        self.builder = self.builder.request_cache(request_cache);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Indicates whether hits.total should be rendered as an integer or an object in the rest search response"]
+    pub fn rest_total_hits_as_int(mut self, rest_total_hits_as_int: bool) -> Self {
+        self.builder = self.builder.rest_total_hits_as_int(rest_total_hits_as_int);
+        self
+    }
+    #[doc = "A comma-separated list of specific routing values"]
+    pub fn routing(mut self, routing: &'b [&'b str]) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "Specify how long a consistent view of the index should be maintained for scrolled search"]
+    pub fn scroll(mut self, scroll: &'b str) -> Self {
+        self.builder = self.builder.scroll(scroll);
+        self
+    }
+    #[doc = "Search operation type"]
+    pub fn search_type(mut self, search_type: SearchType) -> Self {
+        self.builder = self.builder.search_type(search_type);
+        self
+    }
+    #[doc = "Specify whether to return sequence number and primary term of the last modification of each hit"]
+    pub fn seq_no_primary_term(mut self, seq_no_primary_term: bool) -> Self {
+        self.builder = self.builder.seq_no_primary_term(seq_no_primary_term);
+        self
+    }
+    #[doc = "Number of hits to return (default: 10)"]
+    pub fn size(mut self, size: i64) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "A comma-separated list of <field>:<direction> pairs"]
+    pub fn sort(mut self, sort: &'b [&'b str]) -> Self {
+        self.builder = self.builder.sort(sort);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Specific 'tag' of the request for logging and statistical purposes"]
+    pub fn stats(mut self, stats: &'b [&'b str]) -> Self {
+        self.builder = self.builder.stats(stats);
+        self
+    }
+    #[doc = "A comma-separated list of stored fields to return as part of a hit"]
+    pub fn stored_fields(mut self, stored_fields: &'b [&'b str]) -> Self {
+        self.builder = self.builder.stored_fields(stored_fields);
+        self
+    }
+    #[doc = "Specify which field to use for suggestions"]
+    pub fn suggest_field(mut self, suggest_field: &'b str) -> Self {
+        self.builder = self.builder.suggest_field(suggest_field);
+        self
+    }
+    #[doc = "Specify suggest mode"]
+    pub fn suggest_mode(mut self, suggest_mode: SuggestMode) -> Self {
+        self.builder = self.builder.suggest_mode(suggest_mode);
+        self
+    }
+    #[doc = "How many suggestions to return in response"]
+    pub fn suggest_size(mut self, suggest_size: i64) -> Self {
+        self.builder = self.builder.suggest_size(suggest_size);
+        self
+    }
+    #[doc = "The source text for which the suggestions should be returned"]
+    pub fn suggest_text(mut self, suggest_text: &'b str) -> Self {
+        self.builder = self.builder.suggest_text(suggest_text);
+        self
+    }
+    #[doc = "The maximum number of documents to collect for each shard, upon reaching which the query execution will terminate early."]
+    pub fn terminate_after(mut self, terminate_after: i64) -> Self {
+        self.builder = self.builder.terminate_after(terminate_after);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Whether to calculate and return scores even if they are not used for sorting"]
+    pub fn track_scores(mut self, track_scores: bool) -> Self {
+        self.builder = self.builder.track_scores(track_scores);
+        self
+    }
+    #[doc = "Indicate if the number of documents that match the query should be tracked. A number can also be specified, to accurately track the total hit count up to the number."]
+    pub fn track_total_hits<T: Into<TrackTotalHits>>(mut self, track_total_hits: T) -> Self {
+        self.builder = self.builder.track_total_hits(track_total_hits);
+        self
+    }
+    #[doc = "Specify whether aggregation and suggester names should be prefixed by their respective types in the response"]
+    pub fn typed_keys(mut self, typed_keys: bool) -> Self {
+        self.builder = self.builder.typed_keys(typed_keys);
+        self
+    }
+    #[doc = "Specify whether to return document version as part of a hit"]
+    pub fn version(mut self, version: bool) -> Self {
+        self.builder = self.builder.version(version);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Search API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[cfg(feature = "experimental-apis")]
+pub use crate::root::SearchMvtParts;
+#[doc = "Builder for the [Search Mvt API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-vector-tile-api.html)\n\nSearches a vector tile for geospatial values. Returns results as a binary Mapbox vector tile."]
+#[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+#[cfg(feature = "experimental-apis")]
+#[derive(Clone, Debug)]
+pub struct SearchMvt<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::SearchMvt<'a, 'b, B>,
+}
+#[cfg(feature = "experimental-apis")]
+impl<'a, 'b, B> SearchMvt<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [SearchMvt] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: SearchMvtParts<'b>) -> Self {
+        SearchMvt {
+            client,
+            builder: crate::root::SearchMvt::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> SearchMvt<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        SearchMvt {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "If false, the meta layer's feature is the bounding box of the tile. If true, the meta layer's feature is a bounding box resulting from a `geo_bounds` aggregation."]
+    pub fn exact_bounds(mut self, exact_bounds: bool) -> Self {
+        self.builder = self.builder.exact_bounds(exact_bounds);
+        self
+    }
+    #[doc = "Size, in pixels, of a side of the vector tile."]
+    pub fn extent(mut self, extent: i32) -> Self {
+        self.builder = self.builder.extent(extent);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Additional zoom levels available through the aggs layer. Accepts 0-8."]
+    pub fn grid_precision(mut self, grid_precision: i32) -> Self {
+        self.builder = self.builder.grid_precision(grid_precision);
+        self
+    }
+    #[doc = "Determines the geometry type for features in the aggs layer."]
+    pub fn grid_type(mut self, grid_type: GridType) -> Self {
+        self.builder = self.builder.grid_type(grid_type);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Maximum number of features to return in the hits layer. Accepts 0-10000."]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Search Mvt API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::SearchShardsParts;
+#[doc = "Builder for the [Search Shards API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-shards.html)\n\nReturns information about the indices and shards that a search request would be executed against."]
+#[derive(Clone, Debug)]
+pub struct SearchShards<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::SearchShards<'a, 'b, B>,
+}
+impl<'a, 'b, B> SearchShards<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [SearchShards] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: SearchShardsParts<'b>) -> Self {
+        SearchShards {
+            client,
+            builder: crate::root::SearchShards::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)"]
+    pub fn allow_no_indices(mut self, allow_no_indices: bool) -> Self {
+        self.builder = self.builder.allow_no_indices(allow_no_indices);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> SearchShards<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        SearchShards {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether to expand wildcard expression to concrete indices that are open, closed or both."]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Whether specified concrete indices should be ignored when unavailable (missing or closed)"]
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.builder = self.builder.ignore_unavailable(ignore_unavailable);
+        self
+    }
+    #[doc = "Return local information, do not retrieve the state from master node (default: false)"]
+    pub fn local(mut self, local: bool) -> Self {
+        self.builder = self.builder.local(local);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Specific routing value"]
+    pub fn routing(mut self, routing: &'b str) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Search Shards API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::SearchTemplateParts;
+#[doc = "Builder for the [Search Template API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-template.html)\n\nAllows to use the Mustache language to pre-render a search definition."]
+#[derive(Clone, Debug)]
+pub struct SearchTemplate<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::SearchTemplate<'a, 'b, B>,
+}
+impl<'a, 'b, B> SearchTemplate<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [SearchTemplate] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: SearchTemplateParts<'b>) -> Self {
+        SearchTemplate {
+            client,
+            builder: crate::root::SearchTemplate::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)"]
+    pub fn allow_no_indices(mut self, allow_no_indices: bool) -> Self {
+        self.builder = self.builder.allow_no_indices(allow_no_indices);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> SearchTemplate<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        SearchTemplate {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Indicates whether network round-trips should be minimized as part of cross-cluster search requests execution"]
+    pub fn ccs_minimize_roundtrips(mut self, ccs_minimize_roundtrips: bool) -> Self {
+        self.builder = self
+            .builder
+            .ccs_minimize_roundtrips(ccs_minimize_roundtrips);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether to expand wildcard expression to concrete indices that are open, closed or both."]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "Specify whether to return detailed information about score computation as part of a hit"]
+    pub fn explain(mut self, explain: bool) -> Self {
+        self.builder = self.builder.explain(explain);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Whether specified concrete, expanded or aliased indices should be ignored when throttled"]
+    pub fn ignore_throttled(mut self, ignore_throttled: bool) -> Self {
+        self.builder = self.builder.ignore_throttled(ignore_throttled);
+        self
+    }
+    #[doc = "Whether specified concrete indices should be ignored when unavailable (missing or closed)"]
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.builder = self.builder.ignore_unavailable(ignore_unavailable);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Specify whether to profile the query execution"]
+    pub fn profile(mut self, profile: bool) -> Self {
+        self.builder = self.builder.profile(profile);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Indicates whether hits.total should be rendered as an integer or an object in the rest search response"]
+    pub fn rest_total_hits_as_int(mut self, rest_total_hits_as_int: bool) -> Self {
+        self.builder = self.builder.rest_total_hits_as_int(rest_total_hits_as_int);
+        self
+    }
+    #[doc = "A comma-separated list of specific routing values"]
+    pub fn routing(mut self, routing: &'b [&'b str]) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "Specify how long a consistent view of the index should be maintained for scrolled search"]
+    pub fn scroll(mut self, scroll: &'b str) -> Self {
+        self.builder = self.builder.scroll(scroll);
+        self
+    }
+    #[doc = "Search operation type"]
+    pub fn search_type(mut self, search_type: SearchType) -> Self {
+        self.builder = self.builder.search_type(search_type);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Specify whether aggregation and suggester names should be prefixed by their respective types in the response"]
+    pub fn typed_keys(mut self, typed_keys: bool) -> Self {
+        self.builder = self.builder.typed_keys(typed_keys);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Search Template API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[cfg(feature = "beta-apis")]
+pub use crate::root::TermsEnumParts;
+#[doc = "Builder for the [Terms Enum API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-terms-enum.html)\n\nThe terms enum API  can be used to discover terms in the index that begin with the provided string. It is designed for low-latency look-ups used in auto-complete scenarios."]
+#[doc = "&nbsp;\n# Optional, beta\nThis requires the `beta-apis` feature. On track to become stable but breaking changes can\nhappen in minor versions.\n        "]
+#[cfg(feature = "beta-apis")]
+#[derive(Clone, Debug)]
+pub struct TermsEnum<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::TermsEnum<'a, 'b, B>,
+}
+#[cfg(feature = "beta-apis")]
+impl<'a, 'b, B> TermsEnum<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [TermsEnum] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: TermsEnumParts<'b>) -> Self {
+        TermsEnum {
+            client,
+            builder: crate::root::TermsEnum::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> TermsEnum<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        TermsEnum {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Terms Enum API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::TermvectorsParts;
+#[doc = "Builder for the [Termvectors API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-termvectors.html)\n\nReturns information and statistics about terms in the fields of a particular document."]
+#[derive(Clone, Debug)]
+pub struct Termvectors<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Termvectors<'a, 'b, B>,
+}
+impl<'a, 'b, B> Termvectors<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [Termvectors] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: TermvectorsParts<'b>) -> Self {
+        Termvectors {
+            client,
+            builder: crate::root::Termvectors::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> Termvectors<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        Termvectors {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Specifies if document count, sum of document frequencies and sum of total term frequencies should be returned."]
+    pub fn field_statistics(mut self, field_statistics: bool) -> Self {
+        self.builder = self.builder.field_statistics(field_statistics);
+        self
+    }
+    #[doc = "A comma-separated list of fields to return."]
+    pub fn fields(mut self, fields: &'b [&'b str]) -> Self {
+        self.builder = self.builder.fields(fields);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Specifies if term offsets should be returned."]
+    pub fn offsets(mut self, offsets: bool) -> Self {
+        self.builder = self.builder.offsets(offsets);
+        self
+    }
+    #[doc = "Specifies if term payloads should be returned."]
+    pub fn payloads(mut self, payloads: bool) -> Self {
+        self.builder = self.builder.payloads(payloads);
+        self
+    }
+    #[doc = "Specifies if term positions should be returned."]
+    pub fn positions(mut self, positions: bool) -> Self {
+        self.builder = self.builder.positions(positions);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random)."]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Specifies if request is real-time as opposed to near-real-time (default: true)."]
+    pub fn realtime(mut self, realtime: bool) -> Self {
+        self.builder = self.builder.realtime(realtime);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Specific routing value."]
+    pub fn routing(mut self, routing: &'b str) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Specifies if total term frequency and document frequency should be returned."]
+    pub fn term_statistics(mut self, term_statistics: bool) -> Self {
+        self.builder = self.builder.term_statistics(term_statistics);
+        self
+    }
+    #[doc = "Explicit version number for concurrency control"]
+    pub fn version(mut self, version: i64) -> Self {
+        self.builder = self.builder.version(version);
+        self
+    }
+    #[doc = "Specific version type"]
+    pub fn version_type(mut self, version_type: VersionType) -> Self {
+        self.builder = self.builder.version_type(version_type);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Termvectors API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::UpdateParts;
+#[doc = "Builder for the [Update API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-update.html)\n\nUpdates a document with a script or partial document."]
+#[derive(Clone, Debug)]
+pub struct Update<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::Update<'a, 'b, B>,
+}
+impl<'a, 'b, B> Update<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [Update] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: UpdateParts<'b>) -> Self {
+        Update {
+            client,
+            builder: crate::root::Update::new(client.transport(), parts),
+        }
+    }
+    #[doc = "True or false to return the _source field or not, or a list of fields to return"]
+    pub fn _source(mut self, _source: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source(_source);
+        self
+    }
+    #[doc = "A list of fields to exclude from the returned _source field"]
+    pub fn _source_excludes(mut self, _source_excludes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_excludes(_source_excludes);
+        self
+    }
+    #[doc = "A list of fields to extract and return from the _source field"]
+    pub fn _source_includes(mut self, _source_includes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_includes(_source_includes);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> Update<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        Update {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "only perform the update operation if the last operation that has changed the document has the specified primary term"]
+    pub fn if_primary_term(mut self, if_primary_term: i64) -> Self {
+        self.builder = self.builder.if_primary_term(if_primary_term);
+        self
+    }
+    #[doc = "only perform the update operation if the last operation that has changed the document has the specified sequence number"]
+    pub fn if_seq_no(mut self, if_seq_no: i64) -> Self {
+        self.builder = self.builder.if_seq_no(if_seq_no);
+        self
+    }
+    #[doc = "The script language (default: painless)"]
+    pub fn lang(mut self, lang: &'b str) -> Self {
+        self.builder = self.builder.lang(lang);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "If `true` then refresh the affected shards to make this operation visible to search, if `wait_for` then wait for a refresh to make this operation visible to search, if `false` (the default) then do nothing with refreshes."]
+    pub fn refresh(mut self, refresh: Refresh) -> Self {
+        self.builder = self.builder.refresh(refresh);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "When true, requires destination is an alias. Default is false"]
+    pub fn require_alias(mut self, require_alias: bool) -> Self {
+        self.builder = self.builder.require_alias(require_alias);
+        self
+    }
+    #[doc = "Specify how many times should the operation be retried when a conflict occurs (default: 0)"]
+    pub fn retry_on_conflict(mut self, retry_on_conflict: i64) -> Self {
+        self.builder = self.builder.retry_on_conflict(retry_on_conflict);
+        self
+    }
+    #[doc = "Specific routing value"]
+    pub fn routing(mut self, routing: &'b str) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Explicit operation timeout"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Sets the number of shard copies that must be active before proceeding with the update operation. Defaults to 1, meaning the primary shard only. Set to `all` for all shard copies, otherwise set to any non-negative value less than or equal to the total number of copies for the shard (number of replicas + 1)"]
+    pub fn wait_for_active_shards(mut self, wait_for_active_shards: &'b str) -> Self {
+        self.builder = self.builder.wait_for_active_shards(wait_for_active_shards);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Update API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::UpdateByQueryParts;
+#[doc = "Builder for the [Update By Query API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-update-by-query.html)\n\nPerforms an update on every document in the index without changing the source,\nfor example to pick up a mapping change."]
+#[derive(Clone, Debug)]
+pub struct UpdateByQuery<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::UpdateByQuery<'a, 'b, B>,
+}
+impl<'a, 'b, B> UpdateByQuery<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [UpdateByQuery] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: UpdateByQueryParts<'b>) -> Self {
+        UpdateByQuery {
+            client,
+            builder: crate::root::UpdateByQuery::new(client.transport(), parts),
+        }
+    }
+    #[doc = "True or false to return the _source field or not, or a list of fields to return"]
+    pub fn _source(mut self, _source: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source(_source);
+        self
+    }
+    #[doc = "A list of fields to exclude from the returned _source field"]
+    pub fn _source_excludes(mut self, _source_excludes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_excludes(_source_excludes);
+        self
+    }
+    #[doc = "A list of fields to extract and return from the _source field"]
+    pub fn _source_includes(mut self, _source_includes: &'b [&'b str]) -> Self {
+        self.builder = self.builder._source_includes(_source_includes);
+        self
+    }
+    #[doc = "Whether to ignore if a wildcard indices expression resolves into no concrete indices. (This includes `_all` string or when no indices have been specified)"]
+    pub fn allow_no_indices(mut self, allow_no_indices: bool) -> Self {
+        self.builder = self.builder.allow_no_indices(allow_no_indices);
+        self
+    }
+    #[doc = "Specify whether wildcard and prefix queries should be analyzed (default: false)"]
+    pub fn analyze_wildcard(mut self, analyze_wildcard: bool) -> Self {
+        self.builder = self.builder.analyze_wildcard(analyze_wildcard);
+        self
+    }
+    #[doc = "The analyzer to use for the query string"]
+    pub fn analyzer(mut self, analyzer: &'b str) -> Self {
+        self.builder = self.builder.analyzer(analyzer);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> UpdateByQuery<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        UpdateByQuery {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "What to do when the update by query hits version conflicts?"]
+    pub fn conflicts(mut self, conflicts: Conflicts) -> Self {
+        self.builder = self.builder.conflicts(conflicts);
+        self
+    }
+    #[doc = "The default operator for query string query (AND or OR)"]
+    pub fn default_operator(mut self, default_operator: DefaultOperator) -> Self {
+        self.builder = self.builder.default_operator(default_operator);
+        self
+    }
+    #[doc = "The field to use as default where no field prefix is given in the query string"]
+    pub fn df(mut self, df: &'b str) -> Self {
+        self.builder = self.builder.df(df);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether to expand wildcard expression to concrete indices that are open, closed or both."]
+    pub fn expand_wildcards(mut self, expand_wildcards: &'b [ExpandWildcards]) -> Self {
+        self.builder = self.builder.expand_wildcards(expand_wildcards);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Starting offset (default: 0)"]
+    pub fn from(mut self, from: i64) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Whether specified concrete indices should be ignored when unavailable (missing or closed)"]
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.builder = self.builder.ignore_unavailable(ignore_unavailable);
+        self
+    }
+    #[doc = "Specify whether format-based query failures (such as providing text to a numeric field) should be ignored"]
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.builder = self.builder.lenient(lenient);
+        self
+    }
+    #[doc = "Maximum number of documents to process (default: all documents)"]
+    pub fn max_docs(mut self, max_docs: i64) -> Self {
+        self.builder = self.builder.max_docs(max_docs);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Ingest pipeline to set on index requests made by this action. (default: none)"]
+    pub fn pipeline(mut self, pipeline: &'b str) -> Self {
+        self.builder = self.builder.pipeline(pipeline);
+        self
+    }
+    #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
+    pub fn preference(mut self, preference: &'b str) -> Self {
+        self.builder = self.builder.preference(preference);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Query in the Lucene query string syntax"]
+    pub fn q(mut self, q: &'b str) -> Self {
+        self.builder = self.builder.q(q);
+        self
+    }
+    #[doc = "Should the affected indexes be refreshed?"]
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.builder = self.builder.refresh(refresh);
+        self
+    }
+    #[doc = "Specify if request cache should be used for this request or not, defaults to index level setting"]
+    pub fn request_cache(mut self, request_cache: bool) -> Self {
+        self.builder = self.builder.request_cache(request_cache);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The throttle to set on this request in sub-requests per second. -1 means no throttle."]
+    pub fn requests_per_second(mut self, requests_per_second: i64) -> Self {
+        self.builder = self.builder.requests_per_second(requests_per_second);
+        self
+    }
+    #[doc = "A comma-separated list of specific routing values"]
+    pub fn routing(mut self, routing: &'b [&'b str]) -> Self {
+        self.builder = self.builder.routing(routing);
+        self
+    }
+    #[doc = "Specify how long a consistent view of the index should be maintained for scrolled search"]
+    pub fn scroll(mut self, scroll: &'b str) -> Self {
+        self.builder = self.builder.scroll(scroll);
+        self
+    }
+    #[doc = "Size on the scroll request powering the update by query"]
+    pub fn scroll_size(mut self, scroll_size: i64) -> Self {
+        self.builder = self.builder.scroll_size(scroll_size);
+        self
+    }
+    #[doc = "Explicit timeout for each search request. Defaults to no timeout."]
+    pub fn search_timeout(mut self, search_timeout: &'b str) -> Self {
+        self.builder = self.builder.search_timeout(search_timeout);
+        self
+    }
+    #[doc = "Search operation type"]
+    pub fn search_type(mut self, search_type: SearchType) -> Self {
+        self.builder = self.builder.search_type(search_type);
+        self
+    }
+    #[doc = "The number of slices this task should be divided into. Defaults to 1, meaning the task isn't sliced into subtasks. Can be set to `auto`."]
+    pub fn slices(mut self, slices: Slices) -> Self {
+        self.builder = self.builder.slices(slices);
+        self
+    }
+    #[doc = "A comma-separated list of <field>:<direction> pairs"]
+    pub fn sort(mut self, sort: &'b [&'b str]) -> Self {
+        self.builder = self.builder.sort(sort);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Specific 'tag' of the request for logging and statistical purposes"]
+    pub fn stats(mut self, stats: &'b [&'b str]) -> Self {
+        self.builder = self.builder.stats(stats);
+        self
+    }
+    #[doc = "The maximum number of documents to collect for each shard, upon reaching which the query execution will terminate early."]
+    pub fn terminate_after(mut self, terminate_after: i64) -> Self {
+        self.builder = self.builder.terminate_after(terminate_after);
+        self
+    }
+    #[doc = "Time each individual bulk request should wait for shards that are unavailable."]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Specify whether to return document version as part of a hit"]
+    pub fn version(mut self, version: bool) -> Self {
+        self.builder = self.builder.version(version);
+        self
+    }
+    #[doc = "Should the document increment the version number (internal) on hit or not (reindex)"]
+    pub fn version_type(mut self, version_type: bool) -> Self {
+        self.builder = self.builder.version_type(version_type);
+        self
+    }
+    #[doc = "Sets the number of shard copies that must be active before proceeding with the update by query operation. Defaults to 1, meaning the primary shard only. Set to `all` for all shard copies, otherwise set to any non-negative value less than or equal to the total number of copies for the shard (number of replicas + 1)"]
+    pub fn wait_for_active_shards(mut self, wait_for_active_shards: &'b str) -> Self {
+        self.builder = self.builder.wait_for_active_shards(wait_for_active_shards);
+        self
+    }
+    #[doc = "Should the request should block until the update by query operation is complete."]
+    pub fn wait_for_completion(mut self, wait_for_completion: bool) -> Self {
+        self.builder = self.builder.wait_for_completion(wait_for_completion);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Update By Query API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::root::UpdateByQueryRethrottleParts;
+#[doc = "Builder for the [Update By Query Rethrottle API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-update-by-query.html)\n\nChanges the number of requests per second for a particular Update By Query operation."]
+#[derive(Clone, Debug)]
+pub struct UpdateByQueryRethrottle<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::root::UpdateByQueryRethrottle<'a, 'b, B>,
+}
+impl<'a, 'b, B> UpdateByQueryRethrottle<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [UpdateByQueryRethrottle] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: UpdateByQueryRethrottleParts<'b>) -> Self {
+        UpdateByQueryRethrottle {
+            client,
+            builder: crate::root::UpdateByQueryRethrottle::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> UpdateByQueryRethrottle<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        UpdateByQueryRethrottle {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The throttle to set on this request in floating sub-requests per second. -1 means set no throttle."]
+    pub fn requests_per_second(mut self, requests_per_second: i64) -> Self {
+        self.builder = self.builder.requests_per_second(requests_per_second);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Update By Query Rethrottle API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+impl Elasticsearch {
+    #[doc = "[Bulk API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-bulk.html)\n\nAllows to perform multiple index/update/delete operations in a single request."]
+    pub fn bulk<'a, 'b>(&'a self, parts: BulkParts<'b>) -> Bulk<'a, 'b, ()> {
+        Bulk::new(self, parts)
+    }
+    #[doc = "[Clear Scroll API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/clear-scroll-api.html)\n\nExplicitly clears the search context for a scroll."]
+    pub fn clear_scroll<'a, 'b>(&'a self, parts: ClearScrollParts<'b>) -> ClearScroll<'a, 'b, ()> {
+        ClearScroll::new(self, parts)
+    }
+    #[doc = "[Close Point In Time API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/point-in-time-api.html)\n\nClose a point in time"]
+    pub fn close_point_in_time<'a, 'b>(&'a self) -> ClosePointInTime<'a, 'b, ()> {
+        ClosePointInTime::new(self)
+    }
+    #[doc = "[Count API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-count.html)\n\nReturns number of documents matching a query."]
+    pub fn count<'a, 'b>(&'a self, parts: CountParts<'b>) -> Count<'a, 'b, ()> {
+        Count::new(self, parts)
+    }
+    #[doc = "[Create API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-index_.html)\n\nCreates a new document in the index.\n\nReturns a 409 response when a document with a same ID already exists in the index."]
+    pub fn create<'a, 'b>(&'a self, parts: CreateParts<'b>) -> Create<'a, 'b, ()> {
+        Create::new(self, parts)
+    }
+    #[doc = "[Delete API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-delete.html)\n\nRemoves a document from the index."]
+    pub fn delete<'a, 'b>(&'a self, parts: DeleteParts<'b>) -> Delete<'a, 'b> {
+        Delete::new(self, parts)
+    }
+    #[doc = "[Delete By Query API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-delete-by-query.html)\n\nDeletes documents matching the provided query."]
+    pub fn delete_by_query<'a, 'b>(
+        &'a self,
+        parts: DeleteByQueryParts<'b>,
+    ) -> DeleteByQuery<'a, 'b, ()> {
+        DeleteByQuery::new(self, parts)
+    }
+    #[doc = "[Delete By Query Rethrottle API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-delete-by-query.html)\n\nChanges the number of requests per second for a particular Delete By Query operation."]
+    pub fn delete_by_query_rethrottle<'a, 'b>(
+        &'a self,
+        parts: DeleteByQueryRethrottleParts<'b>,
+    ) -> DeleteByQueryRethrottle<'a, 'b, ()> {
+        DeleteByQueryRethrottle::new(self, parts)
+    }
+    #[doc = "[Delete Script API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/modules-scripting.html)\n\nDeletes a script."]
+    pub fn delete_script<'a, 'b>(&'a self, parts: DeleteScriptParts<'b>) -> DeleteScript<'a, 'b> {
+        DeleteScript::new(self, parts)
+    }
+    #[doc = "[Exists API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-get.html)\n\nReturns information about whether a document exists in an index."]
+    pub fn exists<'a, 'b>(&'a self, parts: ExistsParts<'b>) -> Exists<'a, 'b> {
+        Exists::new(self, parts)
+    }
+    #[doc = "[Exists Source API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-get.html)\n\nReturns information about whether a document source exists in an index."]
+    pub fn exists_source<'a, 'b>(&'a self, parts: ExistsSourceParts<'b>) -> ExistsSource<'a, 'b> {
+        ExistsSource::new(self, parts)
+    }
+    #[doc = "[Explain API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-explain.html)\n\nReturns information about why a specific matches (or doesn't match) a query."]
+    pub fn explain<'a, 'b>(&'a self, parts: ExplainParts<'b>) -> Explain<'a, 'b, ()> {
+        Explain::new(self, parts)
+    }
+    #[doc = "[Field Caps API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-field-caps.html)\n\nReturns the information about the capabilities of fields among multiple indices."]
+    pub fn field_caps<'a, 'b>(&'a self, parts: FieldCapsParts<'b>) -> FieldCaps<'a, 'b, ()> {
+        FieldCaps::new(self, parts)
+    }
+    #[doc = "[Get API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-get.html)\n\nReturns a document."]
+    pub fn get<'a, 'b>(&'a self, parts: GetParts<'b>) -> Get<'a, 'b> {
+        Get::new(self, parts)
+    }
+    #[doc = "[Get Script API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/modules-scripting.html)\n\nReturns a script."]
+    pub fn get_script<'a, 'b>(&'a self, parts: GetScriptParts<'b>) -> GetScript<'a, 'b> {
+        GetScript::new(self, parts)
+    }
+    #[doc = "[Get Script Context API](https://www.elastic.co/guide/en/elasticsearch/painless/8.0/painless-contexts.html)\n\nReturns all script contexts."]
+    #[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+    #[cfg(feature = "experimental-apis")]
+    pub fn get_script_context<'a, 'b>(&'a self) -> GetScriptContext<'a, 'b> {
+        GetScriptContext::new(self)
+    }
+    #[doc = "[Get Script Languages API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/modules-scripting.html)\n\nReturns available script types, languages and contexts"]
+    #[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+    #[cfg(feature = "experimental-apis")]
+    pub fn get_script_languages<'a, 'b>(&'a self) -> GetScriptLanguages<'a, 'b> {
+        GetScriptLanguages::new(self)
+    }
+    #[doc = "[Get Source API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-get.html)\n\nReturns the source of a document."]
+    pub fn get_source<'a, 'b>(&'a self, parts: GetSourceParts<'b>) -> GetSource<'a, 'b> {
+        GetSource::new(self, parts)
+    }
+    #[doc = "[Index API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-index_.html)\n\nCreates or updates a document in an index."]
+    pub fn index<'a, 'b>(&'a self, parts: IndexParts<'b>) -> Index<'a, 'b, ()> {
+        Index::new(self, parts)
+    }
+    #[doc = "[Info API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/index.html)\n\nReturns basic information about the cluster."]
+    pub fn info<'a, 'b>(&'a self) -> Info<'a, 'b> {
+        Info::new(self)
+    }
+    #[doc = "[Mget API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-multi-get.html)\n\nAllows to get multiple documents in one request."]
+    pub fn mget<'a, 'b>(&'a self, parts: MgetParts<'b>) -> Mget<'a, 'b, ()> {
+        Mget::new(self, parts)
+    }
+    #[doc = "[Msearch API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-multi-search.html)\n\nAllows to execute several search operations in one request."]
+    pub fn msearch<'a, 'b>(&'a self, parts: MsearchParts<'b>) -> Msearch<'a, 'b, ()> {
+        Msearch::new(self, parts)
+    }
+    #[doc = "[Msearch Template API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-multi-search.html)\n\nAllows to execute several search template operations in one request."]
+    pub fn msearch_template<'a, 'b>(
+        &'a self,
+        parts: MsearchTemplateParts<'b>,
+    ) -> MsearchTemplate<'a, 'b, ()> {
+        MsearchTemplate::new(self, parts)
+    }
+    #[doc = "[Mtermvectors API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-multi-termvectors.html)\n\nReturns multiple termvectors in one request."]
+    pub fn mtermvectors<'a, 'b>(
+        &'a self,
+        parts: MtermvectorsParts<'b>,
+    ) -> Mtermvectors<'a, 'b, ()> {
+        Mtermvectors::new(self, parts)
+    }
+    #[doc = "[Open Point In Time API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/point-in-time-api.html)\n\nOpen a point in time that can be used in subsequent searches"]
+    pub fn open_point_in_time<'a, 'b>(
+        &'a self,
+        parts: OpenPointInTimeParts<'b>,
+    ) -> OpenPointInTime<'a, 'b, ()> {
+        OpenPointInTime::new(self, parts)
+    }
+    #[doc = "[Ping API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/index.html)\n\nReturns whether the cluster is running."]
+    pub fn ping<'a, 'b>(&'a self) -> Ping<'a, 'b> {
+        Ping::new(self)
+    }
+    #[doc = "[Put Script API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/modules-scripting.html)\n\nCreates or updates a script."]
+    pub fn put_script<'a, 'b>(&'a self, parts: PutScriptParts<'b>) -> PutScript<'a, 'b, ()> {
+        PutScript::new(self, parts)
+    }
+    #[doc = "[Rank Eval API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-rank-eval.html)\n\nAllows to evaluate the quality of ranked search results over a set of typical search queries"]
+    #[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+    #[cfg(feature = "experimental-apis")]
+    pub fn rank_eval<'a, 'b>(&'a self, parts: RankEvalParts<'b>) -> RankEval<'a, 'b, ()> {
+        RankEval::new(self, parts)
+    }
+    #[doc = "[Reindex API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-reindex.html)\n\nAllows to copy documents from one index to another, optionally filtering the source\ndocuments by a query, changing the destination index settings, or fetching the\ndocuments from a remote cluster."]
+    pub fn reindex<'a, 'b>(&'a self) -> Reindex<'a, 'b, ()> {
+        Reindex::new(self)
+    }
+    #[doc = "[Reindex Rethrottle API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-reindex.html)\n\nChanges the number of requests per second for a particular Reindex operation."]
+    pub fn reindex_rethrottle<'a, 'b>(
+        &'a self,
+        parts: ReindexRethrottleParts<'b>,
+    ) -> ReindexRethrottle<'a, 'b, ()> {
+        ReindexRethrottle::new(self, parts)
+    }
+    #[doc = "[Render Search Template API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/render-search-template-api.html)\n\nAllows to use the Mustache language to pre-render a search definition."]
+    pub fn render_search_template<'a, 'b>(
+        &'a self,
+        parts: RenderSearchTemplateParts<'b>,
+    ) -> RenderSearchTemplate<'a, 'b, ()> {
+        RenderSearchTemplate::new(self, parts)
+    }
+    #[doc = "[Scripts Painless Execute API](https://www.elastic.co/guide/en/elasticsearch/painless/8.0/painless-execute-api.html)\n\nAllows an arbitrary script to be executed and a result to be returned"]
+    #[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+    #[cfg(feature = "experimental-apis")]
+    pub fn scripts_painless_execute<'a, 'b>(&'a self) -> ScriptsPainlessExecute<'a, 'b, ()> {
+        ScriptsPainlessExecute::new(self)
+    }
+    #[doc = "[Scroll API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-request-body.html#request-body-search-scroll)\n\nAllows to retrieve a large numbers of results from a single search request."]
+    pub fn scroll<'a, 'b>(&'a self, parts: ScrollParts<'b>) -> Scroll<'a, 'b, ()> {
+        Scroll::new(self, parts)
+    }
+    #[doc = "[Search API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-search.html)\n\nReturns results matching a query."]
+    pub fn search<'a, 'b>(&'a self, parts: SearchParts<'b>) -> Search<'a, 'b, ()> {
+        Search::new(self, parts)
+    }
+    #[doc = "[Search Mvt API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-vector-tile-api.html)\n\nSearches a vector tile for geospatial values. Returns results as a binary Mapbox vector tile."]
+    #[doc = "&nbsp;\n# Optional, experimental\nThis requires the `experimental-apis` feature. Can have breaking changes in future\nversions or might even be removed entirely.\n        "]
+    #[cfg(feature = "experimental-apis")]
+    pub fn search_mvt<'a, 'b>(&'a self, parts: SearchMvtParts<'b>) -> SearchMvt<'a, 'b, ()> {
+        SearchMvt::new(self, parts)
+    }
+    #[doc = "[Search Shards API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-shards.html)\n\nReturns information about the indices and shards that a search request would be executed against."]
+    pub fn search_shards<'a, 'b>(
+        &'a self,
+        parts: SearchShardsParts<'b>,
+    ) -> SearchShards<'a, 'b, ()> {
+        SearchShards::new(self, parts)
+    }
+    #[doc = "[Search Template API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-template.html)\n\nAllows to use the Mustache language to pre-render a search definition."]
+    pub fn search_template<'a, 'b>(
+        &'a self,
+        parts: SearchTemplateParts<'b>,
+    ) -> SearchTemplate<'a, 'b, ()> {
+        SearchTemplate::new(self, parts)
+    }
+    #[doc = "[Terms Enum API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/search-terms-enum.html)\n\nThe terms enum API  can be used to discover terms in the index that begin with the provided string. It is designed for low-latency look-ups used in auto-complete scenarios."]
+    #[doc = "&nbsp;\n# Optional, beta\nThis requires the `beta-apis` feature. On track to become stable but breaking changes can\nhappen in minor versions.\n        "]
+    #[cfg(feature = "beta-apis")]
+    pub fn terms_enum<'a, 'b>(&'a self, parts: TermsEnumParts<'b>) -> TermsEnum<'a, 'b, ()> {
+        TermsEnum::new(self, parts)
+    }
+    #[doc = "[Termvectors API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-termvectors.html)\n\nReturns information and statistics about terms in the fields of a particular document."]
+    pub fn termvectors<'a, 'b>(&'a self, parts: TermvectorsParts<'b>) -> Termvectors<'a, 'b, ()> {
+        Termvectors::new(self, parts)
+    }
+    #[doc = "[Update API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-update.html)\n\nUpdates a document with a script or partial document."]
+    pub fn update<'a, 'b>(&'a self, parts: UpdateParts<'b>) -> Update<'a, 'b, ()> {
+        Update::new(self, parts)
+    }
+    #[doc = "[Update By Query API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-update-by-query.html)\n\nPerforms an update on every document in the index without changing the source,\nfor example to pick up a mapping change."]
+    pub fn update_by_query<'a, 'b>(
+        &'a self,
+        parts: UpdateByQueryParts<'b>,
+    ) -> UpdateByQuery<'a, 'b, ()> {
+        UpdateByQuery::new(self, parts)
+    }
+    #[doc = "[Update By Query Rethrottle API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/docs-update-by-query.html)\n\nChanges the number of requests per second for a particular Update By Query operation."]
+    pub fn update_by_query_rethrottle<'a, 'b>(
+        &'a self,
+        parts: UpdateByQueryRethrottleParts<'b>,
+    ) -> UpdateByQueryRethrottle<'a, 'b, ()> {
+        UpdateByQueryRethrottle::new(self, parts)
+    }
+}
@@ -0,0 +1,205 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+// -----------------------------------------------
+// This file is generated, Please do not edit it manually.
+// Run the following in the root of the repo to regenerate:
+//
+// cargo make generate-api
+// -----------------------------------------------
+
+#![allow(unused_imports)]
+pub use crate::text_structure::TextStructureFindStructureParts;
+use crate::{
+    blocking::{Elasticsearch, Response},
+    error::Error,
+    http::{
+        headers::{HeaderName, HeaderValue},
+        request::{Body, JsonBody, NdBody},
+        transport::{NodeSelector, Transport},
+    },
+    params::*,
+};
+use serde::Serialize;
+use std::time::Duration;
+#[doc = "Builder for the [Text Structure Find Structure API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/find-structure.html)\n\nFinds the structure of a text file. The text file must contain data that is suitable to be ingested into Elasticsearch."]
+#[derive(Clone, Debug)]
+pub struct TextStructureFindStructure<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::text_structure::TextStructureFindStructure<'a, 'b, B>,
+}
+impl<'a, 'b, B> TextStructureFindStructure<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [TextStructureFindStructure]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        TextStructureFindStructure {
+            client,
+            builder: crate::text_structure::TextStructureFindStructure::new(client.transport()),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: Vec<T>) -> TextStructureFindStructure<'a, 'b, NdBody<T>>
+    where
+        T: Body,
+    {
+        TextStructureFindStructure {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Optional parameter to specify the character set of the file"]
+    pub fn charset(mut self, charset: &'b str) -> Self {
+        self.builder = self.builder.charset(charset);
+        self
+    }
+    #[doc = "Optional parameter containing a comma separated list of the column names for a delimited file"]
+    pub fn column_names(mut self, column_names: &'b [&'b str]) -> Self {
+        self.builder = self.builder.column_names(column_names);
+        self
+    }
+    #[doc = "Optional parameter to specify the delimiter character for a delimited file - must be a single character"]
+    pub fn delimiter(mut self, delimiter: &'b str) -> Self {
+        self.builder = self.builder.delimiter(delimiter);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Whether to include a commentary on how the structure was derived"]
+    pub fn explain(mut self, explain: bool) -> Self {
+        self.builder = self.builder.explain(explain);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Optional parameter to specify the high level file format"]
+    pub fn format(mut self, format: Format) -> Self {
+        self.builder = self.builder.format(format);
+        self
+    }
+    #[doc = "Optional parameter to specify the Grok pattern that should be used to extract fields from messages in a semi-structured text file"]
+    pub fn grok_pattern(mut self, grok_pattern: &'b str) -> Self {
+        self.builder = self.builder.grok_pattern(grok_pattern);
+        self
+    }
+    #[doc = "Optional parameter to specify whether a delimited file includes the column names in its first row"]
+    pub fn has_header_row(mut self, has_header_row: bool) -> Self {
+        self.builder = self.builder.has_header_row(has_header_row);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Maximum number of characters permitted in a single message when lines are merged to create messages."]
+    pub fn line_merge_size_limit(mut self, line_merge_size_limit: i32) -> Self {
+        self.builder = self.builder.line_merge_size_limit(line_merge_size_limit);
+        self
+    }
+    #[doc = "How many lines of the file should be included in the analysis"]
+    pub fn lines_to_sample(mut self, lines_to_sample: i32) -> Self {
+        self.builder = self.builder.lines_to_sample(lines_to_sample);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Optional parameter to specify the quote character for a delimited file - must be a single character"]
+    pub fn quote(mut self, quote: &'b str) -> Self {
+        self.builder = self.builder.quote(quote);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "Optional parameter to specify whether the values between delimiters in a delimited file should have whitespace trimmed from them"]
+    pub fn should_trim_fields(mut self, should_trim_fields: bool) -> Self {
+        self.builder = self.builder.should_trim_fields(should_trim_fields);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Timeout after which the analysis will be aborted"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Optional parameter to specify the timestamp field in the file"]
+    pub fn timestamp_field(mut self, timestamp_field: &'b str) -> Self {
+        self.builder = self.builder.timestamp_field(timestamp_field);
+        self
+    }
+    #[doc = "Optional parameter to specify the timestamp format in the file - may be either a Joda or Java time format"]
+    pub fn timestamp_format(mut self, timestamp_format: &'b str) -> Self {
+        self.builder = self.builder.timestamp_format(timestamp_format);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Text Structure Find Structure API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[doc = "Namespace client for TextStructure APIs"]
+pub struct TextStructure<'a> {
+    client: &'a Elasticsearch,
+}
+impl<'a> TextStructure<'a> {
+    #[doc = "Creates a new instance of [TextStructure]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        Self { client }
+    }
+    pub fn transport(&self) -> &Transport {
+        self.client.transport()
+    }
+    #[doc = "[Text Structure Find Structure API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/find-structure.html)\n\nFinds the structure of a text file. The text file must contain data that is suitable to be ingested into Elasticsearch."]
+    pub fn find_structure<'b>(&'a self) -> TextStructureFindStructure<'a, 'b, ()> {
+        TextStructureFindStructure::new(self.client)
+    }
+}
+impl Elasticsearch {
+    #[doc = "Creates a namespace client for TextStructure APIs"]
+    pub fn text_structure(&self) -> TextStructure {
+        TextStructure::new(self)
+    }
+}
@@ -0,0 +1,739 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+// -----------------------------------------------
+// This file is generated, Please do not edit it manually.
+// Run the following in the root of the repo to regenerate:
+//
+// cargo make generate-api
+// -----------------------------------------------
+
+#![allow(unused_imports)]
+pub use crate::transform::TransformDeleteTransformParts;
+use crate::{
+    blocking::{Elasticsearch, Response},
+    error::Error,
+    http::{
+        headers::{HeaderName, HeaderValue},
+        request::{Body, JsonBody, NdBody},
+        transport::{NodeSelector, Transport},
+    },
+    params::*,
+};
+use serde::Serialize;
+use std::time::Duration;
+#[doc = "Builder for the [Transform Delete Transform API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/delete-transform.html)\n\nDeletes an existing transform."]
+#[derive(Clone, Debug)]
+pub struct TransformDeleteTransform<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::transform::TransformDeleteTransform<'a, 'b>,
+}
+impl<'a, 'b> TransformDeleteTransform<'a, 'b> {
+    #[doc = "Creates a new instance of [TransformDeleteTransform] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: TransformDeleteTransformParts<'b>) -> Self {
+        TransformDeleteTransform {
+            client,
+            builder: crate::transform::TransformDeleteTransform::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "When `true`, the transform is deleted regardless of its current state. The default value is `false`, meaning that the transform must be `stopped` before it can be deleted."]
+    pub fn force(mut self, force: bool) -> Self {
+        self.builder = self.builder.force(force);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Transform Delete Transform API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::transform::TransformGetTransformParts;
+#[doc = "Builder for the [Transform Get Transform API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-transform.html)\n\nRetrieves configuration information for transforms."]
+#[derive(Clone, Debug)]
+pub struct TransformGetTransform<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::transform::TransformGetTransform<'a, 'b>,
+}
+impl<'a, 'b> TransformGetTransform<'a, 'b> {
+    #[doc = "Creates a new instance of [TransformGetTransform] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: TransformGetTransformParts<'b>) -> Self {
+        TransformGetTransform {
+            client,
+            builder: crate::transform::TransformGetTransform::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no transforms. (This includes `_all` string or when no transforms have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "Omits fields that are illegal to set on transform PUT"]
+    pub fn exclude_generated(mut self, exclude_generated: bool) -> Self {
+        self.builder = self.builder.exclude_generated(exclude_generated);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "skips a number of transform configs, defaults to 0"]
+    pub fn from(mut self, from: i32) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "specifies a max number of transforms to get, defaults to 100"]
+    pub fn size(mut self, size: i32) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Transform Get Transform API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::transform::TransformGetTransformStatsParts;
+#[doc = "Builder for the [Transform Get Transform Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-transform-stats.html)\n\nRetrieves usage information for transforms."]
+#[derive(Clone, Debug)]
+pub struct TransformGetTransformStats<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::transform::TransformGetTransformStats<'a, 'b>,
+}
+impl<'a, 'b> TransformGetTransformStats<'a, 'b> {
+    #[doc = "Creates a new instance of [TransformGetTransformStats] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: TransformGetTransformStatsParts<'b>) -> Self {
+        TransformGetTransformStats {
+            client,
+            builder: crate::transform::TransformGetTransformStats::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no transforms. (This includes `_all` string or when no transforms have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "skips a number of transform stats, defaults to 0"]
+    pub fn from(mut self, from: i64) -> Self {
+        self.builder = self.builder.from(from);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "specifies a max number of transform stats to get, defaults to 100"]
+    pub fn size(mut self, size: i64) -> Self {
+        self.builder = self.builder.size(size);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Transform Get Transform Stats API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::transform::TransformPreviewTransformParts;
+#[doc = "Builder for the [Transform Preview Transform API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/preview-transform.html)\n\nPreviews a transform."]
+#[derive(Clone, Debug)]
+pub struct TransformPreviewTransform<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::transform::TransformPreviewTransform<'a, 'b, B>,
+}
+impl<'a, 'b, B> TransformPreviewTransform<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [TransformPreviewTransform] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: TransformPreviewTransformParts<'b>) -> Self {
+        TransformPreviewTransform {
+            client,
+            builder: crate::transform::TransformPreviewTransform::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> TransformPreviewTransform<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        TransformPreviewTransform {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Transform Preview Transform API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::transform::TransformPutTransformParts;
+#[doc = "Builder for the [Transform Put Transform API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/put-transform.html)\n\nInstantiates a transform."]
+#[derive(Clone, Debug)]
+pub struct TransformPutTransform<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::transform::TransformPutTransform<'a, 'b, B>,
+}
+impl<'a, 'b, B> TransformPutTransform<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [TransformPutTransform] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: TransformPutTransformParts<'b>) -> Self {
+        TransformPutTransform {
+            client,
+            builder: crate::transform::TransformPutTransform::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> TransformPutTransform<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        TransformPutTransform {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "If validations should be deferred until transform starts, defaults to false."]
+    pub fn defer_validation(mut self, defer_validation: bool) -> Self {
+        self.builder = self.builder.defer_validation(defer_validation);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Transform Put Transform API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::transform::TransformStartTransformParts;
+#[doc = "Builder for the [Transform Start Transform API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/start-transform.html)\n\nStarts one or more transforms."]
+#[derive(Clone, Debug)]
+pub struct TransformStartTransform<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::transform::TransformStartTransform<'a, 'b, B>,
+}
+impl<'a, 'b, B> TransformStartTransform<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [TransformStartTransform] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: TransformStartTransformParts<'b>) -> Self {
+        TransformStartTransform {
+            client,
+            builder: crate::transform::TransformStartTransform::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> TransformStartTransform<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        TransformStartTransform {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Controls the time to wait for the transform to start"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Transform Start Transform API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::transform::TransformStopTransformParts;
+#[doc = "Builder for the [Transform Stop Transform API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/stop-transform.html)\n\nStops one or more transforms."]
+#[derive(Clone, Debug)]
+pub struct TransformStopTransform<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::transform::TransformStopTransform<'a, 'b, B>,
+}
+impl<'a, 'b, B> TransformStopTransform<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [TransformStopTransform] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: TransformStopTransformParts<'b>) -> Self {
+        TransformStopTransform {
+            client,
+            builder: crate::transform::TransformStopTransform::new(client.transport(), parts),
+        }
+    }
+    #[doc = "Whether to ignore if a wildcard expression matches no transforms. (This includes `_all` string or when no transforms have been specified)"]
+    pub fn allow_no_match(mut self, allow_no_match: bool) -> Self {
+        self.builder = self.builder.allow_no_match(allow_no_match);
+        self
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> TransformStopTransform<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        TransformStopTransform {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Whether to force stop a failed transform or not. Default to false"]
+    pub fn force(mut self, force: bool) -> Self {
+        self.builder = self.builder.force(force);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Controls the time to wait until the transform has stopped. Default to 30 seconds"]
+    pub fn timeout(mut self, timeout: &'b str) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+    #[doc = "Whether to wait for the transform to reach a checkpoint before stopping. Default to false"]
+    pub fn wait_for_checkpoint(mut self, wait_for_checkpoint: bool) -> Self {
+        self.builder = self.builder.wait_for_checkpoint(wait_for_checkpoint);
+        self
+    }
+    #[doc = "Whether to wait for the transform to fully stop before returning or not. Default to false"]
+    pub fn wait_for_completion(mut self, wait_for_completion: bool) -> Self {
+        self.builder = self.builder.wait_for_completion(wait_for_completion);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Transform Stop Transform API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::transform::TransformUpdateTransformParts;
+#[doc = "Builder for the [Transform Update Transform API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/update-transform.html)\n\nUpdates certain properties of a transform."]
+#[derive(Clone, Debug)]
+pub struct TransformUpdateTransform<'a, 'b, B> {
+    client: &'a Elasticsearch,
+    builder: crate::transform::TransformUpdateTransform<'a, 'b, B>,
+}
+impl<'a, 'b, B> TransformUpdateTransform<'a, 'b, B>
+where
+    B: Body,
+{
+    #[doc = "Creates a new instance of [TransformUpdateTransform] with the specified API parts"]
+    pub fn new(client: &'a Elasticsearch, parts: TransformUpdateTransformParts<'b>) -> Self {
+        TransformUpdateTransform {
+            client,
+            builder: crate::transform::TransformUpdateTransform::new(client.transport(), parts),
+        }
+    }
+    #[doc = "The body for the API call"]
+    pub fn body<T>(self, body: T) -> TransformUpdateTransform<'a, 'b, JsonBody<T>>
+    where
+        T: Serialize,
+    {
+        TransformUpdateTransform {
+            client: self.client,
+            builder: self.builder.body(body),
+        }
+    }
+    #[doc = "If validations should be deferred until transform starts, defaults to false."]
+    pub fn defer_validation(mut self, defer_validation: bool) -> Self {
+        self.builder = self.builder.defer_validation(defer_validation);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Transform Update Transform API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[doc = "Namespace client for Transform APIs"]
+pub struct Transform<'a> {
+    client: &'a Elasticsearch,
+}
+impl<'a> Transform<'a> {
+    #[doc = "Creates a new instance of [Transform]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        Self { client }
+    }
+    pub fn transport(&self) -> &Transport {
+        self.client.transport()
+    }
+    #[doc = "[Transform Delete Transform API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/delete-transform.html)\n\nDeletes an existing transform."]
+    pub fn delete_transform<'b>(
+        &'a self,
+        parts: TransformDeleteTransformParts<'b>,
+    ) -> TransformDeleteTransform<'a, 'b> {
+        TransformDeleteTransform::new(self.client, parts)
+    }
+    #[doc = "[Transform Get Transform API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-transform.html)\n\nRetrieves configuration information for transforms."]
+    pub fn get_transform<'b>(
+        &'a self,
+        parts: TransformGetTransformParts<'b>,
+    ) -> TransformGetTransform<'a, 'b> {
+        TransformGetTransform::new(self.client, parts)
+    }
+    #[doc = "[Transform Get Transform Stats API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/get-transform-stats.html)\n\nRetrieves usage information for transforms."]
+    pub fn get_transform_stats<'b>(
+        &'a self,
+        parts: TransformGetTransformStatsParts<'b>,
+    ) -> TransformGetTransformStats<'a, 'b> {
+        TransformGetTransformStats::new(self.client, parts)
+    }
+    #[doc = "[Transform Preview Transform API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/preview-transform.html)\n\nPreviews a transform."]
+    pub fn preview_transform<'b>(
+        &'a self,
+        parts: TransformPreviewTransformParts<'b>,
+    ) -> TransformPreviewTransform<'a, 'b, ()> {
+        TransformPreviewTransform::new(self.client, parts)
+    }
+    #[doc = "[Transform Put Transform API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/put-transform.html)\n\nInstantiates a transform."]
+    pub fn put_transform<'b>(
+        &'a self,
+        parts: TransformPutTransformParts<'b>,
+    ) -> TransformPutTransform<'a, 'b, ()> {
+        TransformPutTransform::new(self.client, parts)
+    }
+    #[doc = "[Transform Start Transform API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/start-transform.html)\n\nStarts one or more transforms."]
+    pub fn start_transform<'b>(
+        &'a self,
+        parts: TransformStartTransformParts<'b>,
+    ) -> TransformStartTransform<'a, 'b, ()> {
+        TransformStartTransform::new(self.client, parts)
+    }
+    #[doc = "[Transform Stop Transform API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/stop-transform.html)\n\nStops one or more transforms."]
+    pub fn stop_transform<'b>(
+        &'a self,
+        parts: TransformStopTransformParts<'b>,
+    ) -> TransformStopTransform<'a, 'b, ()> {
+        TransformStopTransform::new(self.client, parts)
+    }
+    #[doc = "[Transform Update Transform API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/update-transform.html)\n\nUpdates certain properties of a transform."]
+    pub fn update_transform<'b>(
+        &'a self,
+        parts: TransformUpdateTransformParts<'b>,
+    ) -> TransformUpdateTransform<'a, 'b, ()> {
+        TransformUpdateTransform::new(self.client, parts)
+    }
+}
+impl Elasticsearch {
+    #[doc = "Creates a namespace client for Transform APIs"]
+    pub fn transform(&self) -> Transform {
+        Transform::new(self)
+    }
+}
@@ -0,0 +1,201 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+// -----------------------------------------------
+// This file is generated, Please do not edit it manually.
+// Run the following in the root of the repo to regenerate:
+//
+// cargo make generate-api
+// -----------------------------------------------
+
+#![allow(unused_imports)]
+pub use crate::xpack::XpackInfoParts;
+use crate::{
+    blocking::{Elasticsearch, Response},
+    error::Error,
+    http::{
+        headers::{HeaderName, HeaderValue},
+        request::{Body, JsonBody, NdBody},
+        transport::{NodeSelector, Transport},
+    },
+    params::*,
+};
+use serde::Serialize;
+use std::time::Duration;
+#[doc = "Builder for the [Xpack Info API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/info-api.html)\n\nRetrieves information about the installed X-Pack features."]
+#[derive(Clone, Debug)]
+pub struct XpackInfo<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::xpack::XpackInfo<'a, 'b>,
+}
+impl<'a, 'b> XpackInfo<'a, 'b> {
+    #[doc = "Creates a new instance of [XpackInfo]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        XpackInfo {
+            client,
+            builder: crate::xpack::XpackInfo::new(client.transport()),
+        }
+    }
+    #[doc = "If this param is used it must be set to true"]
+    pub fn accept_enterprise(mut self, accept_enterprise: bool) -> Self {
+        self.builder = self.builder.accept_enterprise(accept_enterprise);
+        self
+    }
+    #[doc = "Comma-separated list of info categories. Can be any of: build, license, features"]
+    pub fn categories(mut self, categories: &'b [&'b str]) -> Self {
+        self.builder = self.builder.categories(categories);
+        self
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Xpack Info API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+pub use crate::xpack::XpackUsageParts;
+#[doc = "Builder for the [Xpack Usage API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/usage-api.html)\n\nRetrieves usage information about the installed X-Pack features."]
+#[derive(Clone, Debug)]
+pub struct XpackUsage<'a, 'b> {
+    client: &'a Elasticsearch,
+    builder: crate::xpack::XpackUsage<'a, 'b>,
+}
+impl<'a, 'b> XpackUsage<'a, 'b> {
+    #[doc = "Creates a new instance of [XpackUsage]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        XpackUsage {
+            client,
+            builder: crate::xpack::XpackUsage::new(client.transport()),
+        }
+    }
+    #[doc = "Include the stack trace of returned errors."]
+    pub fn error_trace(mut self, error_trace: bool) -> Self {
+        self.builder = self.builder.error_trace(error_trace);
+        self
+    }
+    #[doc = "A comma-separated list of filters used to reduce the response."]
+    pub fn filter_path(mut self, filter_path: &'b [&'b str]) -> Self {
+        self.builder = self.builder.filter_path(filter_path);
+        self
+    }
+    #[doc = "Adds a HTTP header"]
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.builder = self.builder.header(key, value);
+        self
+    }
+    #[doc = "Return human readable values for statistics."]
+    pub fn human(mut self, human: bool) -> Self {
+        self.builder = self.builder.human(human);
+        self
+    }
+    #[doc = "Specify timeout for watch write operation"]
+    pub fn master_timeout(mut self, master_timeout: &'b str) -> Self {
+        self.builder = self.builder.master_timeout(master_timeout);
+        self
+    }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.builder = self.builder.node_selector(selector);
+        self
+    }
+    #[doc = "Pretty format the returned JSON response."]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.builder = self.builder.pretty(pretty);
+        self
+    }
+    #[doc = "Sets a request timeout for this API call.\n\nThe timeout is applied from when the request starts connecting until the response body has finished."]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(timeout);
+        self
+    }
+    #[doc = "The URL-encoded request definition. Useful for libraries that do not accept a request body for non-POST requests."]
+    pub fn source(mut self, source: &'b str) -> Self {
+        self.builder = self.builder.source(source);
+        self
+    }
+    #[doc = "Creates a synchronous call to the Xpack Usage API that blocks until the response is returned"]
+    pub fn send(self) -> Result<Response, Error> {
+        self.client.block_on(self.builder.send())
+    }
+}
+#[doc = "Namespace client for X-Pack APIs"]
+pub struct Xpack<'a> {
+    client: &'a Elasticsearch,
+}
+impl<'a> Xpack<'a> {
+    #[doc = "Creates a new instance of [Xpack]"]
+    pub fn new(client: &'a Elasticsearch) -> Self {
+        Self { client }
+    }
+    pub fn transport(&self) -> &Transport {
+        self.client.transport()
+    }
+    #[doc = "[Xpack Info API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/info-api.html)\n\nRetrieves information about the installed X-Pack features."]
+    pub fn info<'b>(&'a self) -> XpackInfo<'a, 'b> {
+        XpackInfo::new(self.client)
+    }
+    #[doc = "[Xpack Usage API](https://www.elastic.co/guide/en/elasticsearch/reference/8.0/usage-api.html)\n\nRetrieves usage information about the installed X-Pack features."]
+    pub fn usage<'b>(&'a self) -> XpackUsage<'a, 'b> {
+        XpackUsage::new(self.client)
+    }
+}
+impl Elasticsearch {
+    #[doc = "Creates a namespace client for X-Pack APIs"]
+    pub fn xpack(&self) -> Xpack {
+        Xpack::new(self)
+    }
+}
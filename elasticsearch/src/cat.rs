@@ -127,7 +127,7 @@ use crate::{
         headers::{HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE},
         request::{Body, JsonBody, NdBody, PARTS_ENCODED},
         response::Response,
-        transport::Transport,
+        transport::{NodeSelector, RequestOptions, Transport},
         Method,
     },
     params::*,
@@ -174,6 +174,7 @@ pub struct CatAliases<'a, 'b> {
     help: Option<bool>,
     human: Option<bool>,
     local: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -198,6 +199,7 @@ impl<'a, 'b> CatAliases<'a, 'b> {
             help: None,
             human: None,
             local: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -250,6 +252,11 @@ impl<'a, 'b> CatAliases<'a, 'b> {
         self.local = Some(local);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -319,9 +326,20 @@ impl<'a, 'b> CatAliases<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.aliases")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -366,6 +384,7 @@ pub struct CatAllocation<'a, 'b> {
     human: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -391,6 +410,7 @@ impl<'a, 'b> CatAllocation<'a, 'b> {
             human: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -448,6 +468,11 @@ impl<'a, 'b> CatAllocation<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -518,9 +543,20 @@ impl<'a, 'b> CatAllocation<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.allocation")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -562,6 +598,7 @@ pub struct CatCount<'a, 'b> {
     headers: HeaderMap,
     help: Option<bool>,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -584,6 +621,7 @@ impl<'a, 'b> CatCount<'a, 'b> {
             h: None,
             help: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -626,6 +664,11 @@ impl<'a, 'b> CatCount<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -690,9 +733,20 @@ impl<'a, 'b> CatCount<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.count")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -736,6 +790,7 @@ pub struct CatFielddata<'a, 'b> {
     headers: HeaderMap,
     help: Option<bool>,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -760,6 +815,7 @@ impl<'a, 'b> CatFielddata<'a, 'b> {
             h: None,
             help: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -812,6 +868,11 @@ impl<'a, 'b> CatFielddata<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -881,9 +942,20 @@ impl<'a, 'b> CatFielddata<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.fielddata")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -914,6 +986,7 @@ pub struct CatHealth<'a, 'b> {
     headers: HeaderMap,
     help: Option<bool>,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -938,6 +1011,7 @@ impl<'a, 'b> CatHealth<'a, 'b> {
             h: None,
             help: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -982,6 +1056,11 @@ impl<'a, 'b> CatHealth<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1060,9 +1139,20 @@ impl<'a, 'b> CatHealth<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.health")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1091,6 +1181,7 @@ pub struct CatHelp<'a, 'b> {
     headers: HeaderMap,
     help: Option<bool>,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -1110,6 +1201,7 @@ impl<'a, 'b> CatHelp<'a, 'b> {
             filter_path: None,
             help: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -1141,6 +1233,11 @@ impl<'a, 'b> CatHelp<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1193,9 +1290,20 @@ impl<'a, 'b> CatHelp<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.help")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1242,6 +1350,7 @@ pub struct CatIndices<'a, 'b> {
     human: Option<bool>,
     include_unloaded_segments: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     pri: Option<bool>,
     request_timeout: Option<Duration>,
@@ -1271,6 +1380,7 @@ impl<'a, 'b> CatIndices<'a, 'b> {
             human: None,
             include_unloaded_segments: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             pri: None,
             request_timeout: None,
@@ -1340,6 +1450,11 @@ impl<'a, 'b> CatIndices<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1429,9 +1544,20 @@ impl<'a, 'b> CatIndices<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.indices")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1464,6 +1590,7 @@ pub struct CatMaster<'a, 'b> {
     human: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -1488,6 +1615,7 @@ impl<'a, 'b> CatMaster<'a, 'b> {
             human: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -1540,6 +1668,11 @@ impl<'a, 'b> CatMaster<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1608,9 +1741,20 @@ impl<'a, 'b> CatMaster<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.master")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1652,6 +1796,7 @@ pub struct CatMlDataFrameAnalytics<'a, 'b> {
     headers: HeaderMap,
     help: Option<bool>,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -1677,6 +1822,7 @@ impl<'a, 'b> CatMlDataFrameAnalytics<'a, 'b> {
             h: None,
             help: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -1730,6 +1876,11 @@ impl<'a, 'b> CatMlDataFrameAnalytics<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1805,9 +1956,20 @@ impl<'a, 'b> CatMlDataFrameAnalytics<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.ml_data_frame_analytics")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1850,6 +2012,7 @@ pub struct CatMlDatafeeds<'a, 'b> {
     headers: HeaderMap,
     help: Option<bool>,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -1875,6 +2038,7 @@ impl<'a, 'b> CatMlDatafeeds<'a, 'b> {
             h: None,
             help: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -1928,6 +2092,11 @@ impl<'a, 'b> CatMlDatafeeds<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2003,9 +2172,20 @@ impl<'a, 'b> CatMlDatafeeds<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.ml_datafeeds")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2049,6 +2229,7 @@ pub struct CatMlJobs<'a, 'b> {
     headers: HeaderMap,
     help: Option<bool>,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -2075,6 +2256,7 @@ impl<'a, 'b> CatMlJobs<'a, 'b> {
             h: None,
             help: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -2133,6 +2315,11 @@ impl<'a, 'b> CatMlJobs<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2210,9 +2397,20 @@ impl<'a, 'b> CatMlJobs<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.ml_jobs")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2256,6 +2454,7 @@ pub struct CatMlTrainedModels<'a, 'b> {
     headers: HeaderMap,
     help: Option<bool>,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -2283,6 +2482,7 @@ impl<'a, 'b> CatMlTrainedModels<'a, 'b> {
             h: None,
             help: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -2342,6 +2542,11 @@ impl<'a, 'b> CatMlTrainedModels<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2426,9 +2631,20 @@ impl<'a, 'b> CatMlTrainedModels<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.ml_trained_models")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2461,6 +2677,7 @@ pub struct CatNodeattrs<'a, 'b> {
     human: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -2485,6 +2702,7 @@ impl<'a, 'b> CatNodeattrs<'a, 'b> {
             human: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -2537,6 +2755,11 @@ impl<'a, 'b> CatNodeattrs<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2605,9 +2828,20 @@ impl<'a, 'b> CatNodeattrs<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.nodeattrs")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2642,6 +2876,7 @@ pub struct CatNodes<'a, 'b> {
     human: Option<bool>,
     include_unloaded_segments: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -2669,6 +2904,7 @@ impl<'a, 'b> CatNodes<'a, 'b> {
             human: None,
             include_unloaded_segments: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -2732,6 +2968,11 @@ impl<'a, 'b> CatNodes<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2811,9 +3052,20 @@ impl<'a, 'b> CatNodes<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.nodes")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2846,6 +3098,7 @@ pub struct CatPendingTasks<'a, 'b> {
     human: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -2871,6 +3124,7 @@ impl<'a, 'b> CatPendingTasks<'a, 'b> {
             human: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -2924,6 +3178,11 @@ impl<'a, 'b> CatPendingTasks<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2999,9 +3258,20 @@ impl<'a, 'b> CatPendingTasks<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.pending_tasks")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3035,6 +3305,7 @@ pub struct CatPlugins<'a, 'b> {
     include_bootstrap: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -3060,6 +3331,7 @@ impl<'a, 'b> CatPlugins<'a, 'b> {
             include_bootstrap: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -3117,6 +3389,11 @@ impl<'a, 'b> CatPlugins<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -3187,9 +3464,20 @@ impl<'a, 'b> CatPlugins<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.plugins")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3235,6 +3523,7 @@ pub struct CatRecovery<'a, 'b> {
     help: Option<bool>,
     human: Option<bool>,
     index: Option<&'b [&'b str]>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -3262,6 +3551,7 @@ impl<'a, 'b> CatRecovery<'a, 'b> {
             help: None,
             human: None,
             index: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -3325,6 +3615,11 @@ impl<'a, 'b> CatRecovery<'a, 'b> {
         self.index = Some(index);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -3405,9 +3700,20 @@ impl<'a, 'b> CatRecovery<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.recovery")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3440,6 +3746,7 @@ pub struct CatRepositories<'a, 'b> {
     human: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -3464,6 +3771,7 @@ impl<'a, 'b> CatRepositories<'a, 'b> {
             human: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -3516,6 +3824,11 @@ impl<'a, 'b> CatRepositories<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -3584,9 +3897,20 @@ impl<'a, 'b> CatRepositories<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.repositories")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3629,6 +3953,7 @@ pub struct CatSegments<'a, 'b> {
     headers: HeaderMap,
     help: Option<bool>,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -3652,6 +3977,7 @@ impl<'a, 'b> CatSegments<'a, 'b> {
             h: None,
             help: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -3699,6 +4025,11 @@ impl<'a, 'b> CatSegments<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -3765,9 +4096,20 @@ impl<'a, 'b> CatSegments<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.segments")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3811,6 +4153,7 @@ pub struct CatShards<'a, 'b> {
     help: Option<bool>,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -3836,6 +4179,7 @@ impl<'a, 'b> CatShards<'a, 'b> {
             help: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -3889,6 +4233,11 @@ impl<'a, 'b> CatShards<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -3964,9 +4313,20 @@ impl<'a, 'b> CatShards<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.shards")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -4010,6 +4370,7 @@ pub struct CatSnapshots<'a, 'b> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -4035,6 +4396,7 @@ impl<'a, 'b> CatSnapshots<'a, 'b> {
             human: None,
             ignore_unavailable: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -4088,6 +4450,11 @@ impl<'a, 'b> CatSnapshots<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -4163,9 +4530,20 @@ impl<'a, 'b> CatSnapshots<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.snapshots")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -4202,6 +4580,7 @@ pub struct CatTasks<'a, 'b> {
     headers: HeaderMap,
     help: Option<bool>,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     nodes: Option<&'b [&'b str]>,
     parent_task_id: Option<&'b str>,
     pretty: Option<bool>,
@@ -4230,6 +4609,7 @@ impl<'a, 'b> CatTasks<'a, 'b> {
             h: None,
             help: None,
             human: None,
+            node_selector: None,
             nodes: None,
             parent_task_id: None,
             pretty: None,
@@ -4285,6 +4665,11 @@ impl<'a, 'b> CatTasks<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "A comma-separated list of node IDs or names to limit the returned information; use `_local` to return information from the node you're connecting to, leave empty to get information from all nodes"]
     pub fn nodes(mut self, nodes: &'b [&'b str]) -> Self {
         self.nodes = Some(nodes);
@@ -4376,9 +4761,20 @@ impl<'a, 'b> CatTasks<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.tasks")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -4420,6 +4816,7 @@ pub struct CatTemplates<'a, 'b> {
     human: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -4444,6 +4841,7 @@ impl<'a, 'b> CatTemplates<'a, 'b> {
             human: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -4496,6 +4894,11 @@ impl<'a, 'b> CatTemplates<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -4564,9 +4967,20 @@ impl<'a, 'b> CatTemplates<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.templates")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -4610,6 +5024,7 @@ pub struct CatThreadPool<'a, 'b> {
     human: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -4635,6 +5050,7 @@ impl<'a, 'b> CatThreadPool<'a, 'b> {
             human: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -4688,6 +5104,11 @@ impl<'a, 'b> CatThreadPool<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -4763,9 +5184,20 @@ impl<'a, 'b> CatThreadPool<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.thread_pool")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -4808,6 +5240,7 @@ pub struct CatTransforms<'a, 'b> {
     headers: HeaderMap,
     help: Option<bool>,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     s: Option<&'b [&'b str]>,
@@ -4834,6 +5267,7 @@ impl<'a, 'b> CatTransforms<'a, 'b> {
             h: None,
             help: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             s: None,
@@ -4888,6 +5322,11 @@ impl<'a, 'b> CatTransforms<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -4970,9 +5409,20 @@ impl<'a, 'b> CatTransforms<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cat.transforms")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -40,7 +40,7 @@ use crate::{
         headers::{HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE},
         request::{Body, JsonBody, NdBody, PARTS_ENCODED},
         response::Response,
-        transport::Transport,
+        transport::{NodeSelector, RequestOptions, Transport},
         Method,
     },
     params::*,
@@ -77,6 +77,7 @@ pub struct CcrDeleteAutoFollowPattern<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -92,6 +93,7 @@ impl<'a, 'b> CcrDeleteAutoFollowPattern<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -117,6 +119,11 @@ impl<'a, 'b> CcrDeleteAutoFollowPattern<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -159,9 +166,20 @@ impl<'a, 'b> CcrDeleteAutoFollowPattern<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("ccr.delete_auto_follow_pattern")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -198,6 +216,7 @@ pub struct CcrFollow<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -218,6 +237,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -237,6 +257,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -263,6 +284,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -312,9 +338,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("ccr.follow")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -351,6 +388,7 @@ pub struct CcrFollowInfo<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -366,6 +404,7 @@ impl<'a, 'b> CcrFollowInfo<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -391,6 +430,11 @@ impl<'a, 'b> CcrFollowInfo<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -433,9 +477,20 @@ impl<'a, 'b> CcrFollowInfo<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("ccr.follow_info")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -472,6 +527,7 @@ pub struct CcrFollowStats<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -487,6 +543,7 @@ impl<'a, 'b> CcrFollowStats<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -512,6 +569,11 @@ impl<'a, 'b> CcrFollowStats<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -554,9 +616,20 @@ impl<'a, 'b> CcrFollowStats<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("ccr.follow_stats")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -593,6 +666,7 @@ pub struct CcrForgetFollower<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -612,6 +686,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -630,6 +705,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -655,6 +731,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -697,9 +778,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("ccr.forget_follower")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -736,6 +828,7 @@ pub struct CcrGetAutoFollowPattern<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -751,6 +844,7 @@ impl<'a, 'b> CcrGetAutoFollowPattern<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -776,6 +870,11 @@ impl<'a, 'b> CcrGetAutoFollowPattern<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -818,9 +917,20 @@ impl<'a, 'b> CcrGetAutoFollowPattern<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("ccr.get_auto_follow_pattern")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -856,6 +966,7 @@ pub struct CcrPauseAutoFollowPattern<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -875,6 +986,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -893,6 +1005,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -918,6 +1031,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -960,9 +1078,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("ccr.pause_auto_follow_pattern")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -999,6 +1128,7 @@ pub struct CcrPauseFollow<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1018,6 +1148,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1036,6 +1167,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -1061,6 +1193,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1103,9 +1240,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("ccr.pause_follow")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1140,6 +1288,7 @@ pub struct CcrPutAutoFollowPattern<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1159,6 +1308,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1177,6 +1327,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -1202,6 +1353,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1244,9 +1400,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("ccr.put_auto_follow_pattern")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1282,6 +1449,7 @@ pub struct CcrResumeAutoFollowPattern<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1301,6 +1469,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1319,6 +1488,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -1344,6 +1514,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1386,9 +1561,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("ccr.resume_auto_follow_pattern")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1425,6 +1611,7 @@ pub struct CcrResumeFollow<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1444,6 +1631,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1462,6 +1650,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -1487,6 +1676,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1529,9 +1723,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("ccr.resume_follow")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1559,6 +1764,7 @@ pub struct CcrStats<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1574,6 +1780,7 @@ impl<'a, 'b> CcrStats<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1599,6 +1806,11 @@ impl<'a, 'b> CcrStats<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1641,9 +1853,20 @@ impl<'a, 'b> CcrStats<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("ccr.stats")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1680,6 +1903,7 @@ pub struct CcrUnfollow<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1699,6 +1923,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1717,6 +1942,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -1742,6 +1968,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1784,9 +2015,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("ccr.unfollow")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
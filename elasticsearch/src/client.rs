@@ -17,7 +17,13 @@
  * under the License.
  */
 use crate::{
-    http::{headers::HeaderMap, request::Body, response::Response, transport::Transport, Method},
+    http::{
+        headers::HeaderMap,
+        request::Body,
+        response::Response,
+        transport::{Transport, TransportBuilder},
+        Method,
+    },
     Error,
 };
 
@@ -65,6 +71,15 @@ impl Elasticsearch {
         Elasticsearch { transport }
     }
 
+    /// Creates a new instance of the root client configured from environment variables,
+    /// such as `ELASTICSEARCH_URL` and `ES_API_KEY`.
+    ///
+    /// See [TransportBuilder::from_env] for the variables that are read.
+    pub fn from_env() -> Result<Self, Error> {
+        let transport = TransportBuilder::from_env()?.build()?;
+        Ok(Elasticsearch::new(transport))
+    }
+
     /// Gets the transport of the client
     pub fn transport(&self) -> &Transport {
         &self.transport
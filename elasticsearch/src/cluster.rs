@@ -37,7 +37,7 @@ use crate::{
         headers::{HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE},
         request::{Body, JsonBody, NdBody, PARTS_ENCODED},
         response::Response,
-        transport::Transport,
+        transport::{NodeSelector, RequestOptions, Transport},
         Method,
     },
     params::*,
@@ -71,6 +71,7 @@ pub struct ClusterAllocationExplain<'a, 'b, B> {
     human: Option<bool>,
     include_disk_info: Option<bool>,
     include_yes_decisions: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -92,6 +93,7 @@ where
             human: None,
             include_disk_info: None,
             include_yes_decisions: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -112,6 +114,7 @@ where
             human: self.human,
             include_disk_info: self.include_disk_info,
             include_yes_decisions: self.include_yes_decisions,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -147,6 +150,11 @@ where
         self.include_yes_decisions = Some(include_yes_decisions);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -196,9 +204,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("cluster.allocation_explain")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -233,6 +252,7 @@ pub struct ClusterDeleteComponentTemplate<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -250,6 +270,7 @@ impl<'a, 'b> ClusterDeleteComponentTemplate<'a, 'b> {
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -281,6 +302,11 @@ impl<'a, 'b> ClusterDeleteComponentTemplate<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -332,9 +358,20 @@ impl<'a, 'b> ClusterDeleteComponentTemplate<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cluster.delete_component_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -364,6 +401,7 @@ pub struct ClusterDeleteVotingConfigExclusions<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -380,6 +418,7 @@ impl<'a, 'b> ClusterDeleteVotingConfigExclusions<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -406,6 +445,11 @@ impl<'a, 'b> ClusterDeleteVotingConfigExclusions<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -455,9 +499,20 @@ impl<'a, 'b> ClusterDeleteVotingConfigExclusions<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cluster.delete_voting_config_exclusions")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -493,6 +548,7 @@ pub struct ClusterExistsComponentTemplate<'a, 'b> {
     human: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -510,6 +566,7 @@ impl<'a, 'b> ClusterExistsComponentTemplate<'a, 'b> {
             human: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -545,6 +602,11 @@ impl<'a, 'b> ClusterExistsComponentTemplate<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -591,9 +653,20 @@ impl<'a, 'b> ClusterExistsComponentTemplate<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cluster.exists_component_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -634,6 +707,7 @@ pub struct ClusterGetComponentTemplate<'a, 'b> {
     human: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -651,6 +725,7 @@ impl<'a, 'b> ClusterGetComponentTemplate<'a, 'b> {
             human: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -686,6 +761,11 @@ impl<'a, 'b> ClusterGetComponentTemplate<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -732,9 +812,20 @@ impl<'a, 'b> ClusterGetComponentTemplate<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cluster.get_component_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -765,6 +856,7 @@ pub struct ClusterGetSettings<'a, 'b> {
     human: Option<bool>,
     include_defaults: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -784,6 +876,7 @@ impl<'a, 'b> ClusterGetSettings<'a, 'b> {
             human: None,
             include_defaults: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -825,6 +918,11 @@ impl<'a, 'b> ClusterGetSettings<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -880,9 +978,20 @@ impl<'a, 'b> ClusterGetSettings<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cluster.get_settings")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -925,6 +1034,7 @@ pub struct ClusterHealth<'a, 'b> {
     level: Option<Level>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -951,6 +1061,7 @@ impl<'a, 'b> ClusterHealth<'a, 'b> {
             level: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1003,6 +1114,11 @@ impl<'a, 'b> ClusterHealth<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1106,9 +1222,20 @@ impl<'a, 'b> ClusterHealth<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cluster.health")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1138,6 +1265,7 @@ pub struct ClusterPendingTasks<'a, 'b> {
     human: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1155,6 +1283,7 @@ impl<'a, 'b> ClusterPendingTasks<'a, 'b> {
             human: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1190,6 +1319,11 @@ impl<'a, 'b> ClusterPendingTasks<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1236,9 +1370,20 @@ impl<'a, 'b> ClusterPendingTasks<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cluster.pending_tasks")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1271,6 +1416,7 @@ pub struct ClusterPostVotingConfigExclusions<'a, 'b, B> {
     human: Option<bool>,
     node_ids: Option<&'b str>,
     node_names: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1293,6 +1439,7 @@ where
             human: None,
             node_ids: None,
             node_names: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1314,6 +1461,7 @@ where
             human: self.human,
             node_ids: self.node_ids,
             node_names: self.node_names,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -1350,6 +1498,11 @@ where
         self.node_names = Some(node_names);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1403,9 +1556,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("cluster.post_voting_config_exclusions")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1442,6 +1606,7 @@ pub struct ClusterPutComponentTemplate<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1464,6 +1629,7 @@ where
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1485,6 +1651,7 @@ where
             headers: self.headers,
             human: self.human,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -1521,6 +1688,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1574,9 +1746,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("cluster.put_component_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1607,6 +1790,7 @@ pub struct ClusterPutSettings<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1629,6 +1813,7 @@ where
             flat_settings: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1650,6 +1835,7 @@ where
             headers: self.headers,
             human: self.human,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -1686,6 +1872,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1739,9 +1930,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("cluster.put_settings")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1769,6 +1971,7 @@ pub struct ClusterRemoteInfo<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1784,6 +1987,7 @@ impl<'a, 'b> ClusterRemoteInfo<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1809,6 +2013,11 @@ impl<'a, 'b> ClusterRemoteInfo<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1851,9 +2060,20 @@ impl<'a, 'b> ClusterRemoteInfo<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cluster.remote_info")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1886,6 +2106,7 @@ pub struct ClusterReroute<'a, 'b, B> {
     human: Option<bool>,
     master_timeout: Option<&'b str>,
     metric: Option<&'b [&'b str]>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     retry_failed: Option<bool>,
@@ -1911,6 +2132,7 @@ where
             human: None,
             master_timeout: None,
             metric: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             retry_failed: None,
@@ -1935,6 +2157,7 @@ where
             human: self.human,
             master_timeout: self.master_timeout,
             metric: self.metric,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             retry_failed: self.retry_failed,
@@ -1982,6 +2205,11 @@ where
         self.metric = Some(metric);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2047,9 +2275,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("cluster.reroute")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2111,6 +2350,7 @@ pub struct ClusterState<'a, 'b> {
     ignore_unavailable: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -2134,6 +2374,7 @@ impl<'a, 'b> ClusterState<'a, 'b> {
             ignore_unavailable: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -2191,6 +2432,11 @@ impl<'a, 'b> ClusterState<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2260,9 +2506,20 @@ impl<'a, 'b> ClusterState<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cluster.state")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2302,6 +2559,7 @@ pub struct ClusterStats<'a, 'b> {
     flat_settings: Option<bool>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -2319,6 +2577,7 @@ impl<'a, 'b> ClusterStats<'a, 'b> {
             filter_path: None,
             flat_settings: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -2350,6 +2609,11 @@ impl<'a, 'b> ClusterStats<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2401,9 +2665,20 @@ impl<'a, 'b> ClusterStats<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("cluster.stats")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
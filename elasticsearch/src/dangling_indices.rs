@@ -41,7 +41,7 @@ use crate::{
         headers::{HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE},
         request::{Body, JsonBody, NdBody, PARTS_ENCODED},
         response::Response,
-        transport::Transport,
+        transport::{NodeSelector, RequestOptions, Transport},
         Method,
     },
     params::*,
@@ -81,6 +81,7 @@ pub struct DanglingIndicesDeleteDanglingIndex<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -102,6 +103,7 @@ impl<'a, 'b> DanglingIndicesDeleteDanglingIndex<'a, 'b> {
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -191,9 +193,20 @@ impl<'a, 'b> DanglingIndicesDeleteDanglingIndex<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("dangling_indices.delete_dangling_index")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -231,6 +244,7 @@ pub struct DanglingIndicesImportDanglingIndex<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -256,6 +270,7 @@ where
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -282,6 +297,7 @@ where
             headers: self.headers,
             human: self.human,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -366,9 +382,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("dangling_indices.import_dangling_index")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -396,6 +423,7 @@ pub struct DanglingIndicesListDanglingIndices<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -411,6 +439,7 @@ impl<'a, 'b> DanglingIndicesListDanglingIndices<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -436,6 +465,11 @@ impl<'a, 'b> DanglingIndicesListDanglingIndices<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -478,9 +512,20 @@ impl<'a, 'b> DanglingIndicesListDanglingIndices<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("dangling_indices.list_dangling_indices")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
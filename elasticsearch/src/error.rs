@@ -32,7 +32,7 @@
  * See the License for the specific language governing permissions and
  * limitations under the License.
  */
-use crate::http::{transport::BuildError, StatusCode};
+use crate::http::{response::Exception, transport::BuildError, Method, StatusCode, Url};
 use std::{error, fmt, io};
 
 /// An error with the client.
@@ -52,8 +52,23 @@ enum Kind {
     /// A general error from this library
     Lib(String),
 
-    /// HTTP library error
-    Http(reqwest::Error),
+    /// HTTP client error
+    Http(Box<dyn error::Error + Send + Sync>),
+
+    /// HTTP client error connecting to a node
+    Connect(Box<dyn error::Error + Send + Sync>),
+
+    /// HTTP client error from a request timing out
+    Timeout(Box<dyn error::Error + Send + Sync>),
+
+    /// A response with a HTTP status code in the 400-599 range
+    Status(StatusCode, Url),
+
+    /// A response with a HTTP status code in the 400-599 range, with its body
+    Server(Box<ServerError>),
+
+    /// The server is not a supported Elasticsearch
+    UnsupportedProduct(String),
 
     /// IO error
     Io(io::Error),
@@ -62,6 +77,15 @@ enum Kind {
     Json(serde_json::error::Error),
 }
 
+/// The details of a response with a HTTP status code in the 400-599 range
+#[derive(Debug)]
+struct ServerError {
+    status_code: StatusCode,
+    method: Method,
+    url: Url,
+    exception: Option<Exception>,
+}
+
 impl From<io::Error> for Error {
     fn from(err: io::Error) -> Error {
         Error {
@@ -70,10 +94,15 @@ impl From<io::Error> for Error {
     }
 }
 
+#[cfg(feature = "reqwest")]
 impl From<reqwest::Error> for Error {
     fn from(err: reqwest::Error) -> Error {
-        Error {
-            kind: Kind::Http(err),
+        if err.is_connect() {
+            Error::connect(err)
+        } else if err.is_timeout() {
+            Error::timeout(err)
+        } else {
+            Error::http(err)
         }
     }
 }
@@ -108,23 +137,150 @@ pub(crate) fn lib(err: impl Into<String>) -> Error {
     }
 }
 
+pub(crate) fn status(status_code: StatusCode, url: Url) -> Error {
+    Error {
+        kind: Kind::Status(status_code, url),
+    }
+}
+
+pub(crate) fn server(
+    status_code: StatusCode,
+    method: Method,
+    url: Url,
+    exception: Option<Exception>,
+) -> Error {
+    Error {
+        kind: Kind::Server(Box::new(ServerError {
+            status_code,
+            method,
+            url,
+            exception,
+        })),
+    }
+}
+
+pub(crate) fn unsupported_product(reason: impl Into<String>) -> Error {
+    Error {
+        kind: Kind::UnsupportedProduct(reason.into()),
+    }
+}
+
 impl Error {
+    /// Creates an error from a HTTP client error, for use by
+    /// [HttpClient](crate::http::client::HttpClient) implementations
+    pub fn http(err: impl Into<Box<dyn error::Error + Send + Sync>>) -> Error {
+        Error {
+            kind: Kind::Http(err.into()),
+        }
+    }
+
+    /// Creates an error from a HTTP client error connecting to a node, for use by
+    /// [HttpClient](crate::http::client::HttpClient) implementations
+    pub fn connect(err: impl Into<Box<dyn error::Error + Send + Sync>>) -> Error {
+        Error {
+            kind: Kind::Connect(err.into()),
+        }
+    }
+
+    /// Creates an error from a HTTP client error from a request timing out, for use by
+    /// [HttpClient](crate::http::client::HttpClient) implementations
+    pub fn timeout(err: impl Into<Box<dyn error::Error + Send + Sync>>) -> Error {
+        Error {
+            kind: Kind::Timeout(err.into()),
+        }
+    }
+
     /// The status code, if the error was generated from a response
     pub fn status_code(&self) -> Option<StatusCode> {
         match &self.kind {
-            Kind::Http(err) => err.status(),
+            Kind::Status(status_code, _) => Some(*status_code),
+            Kind::Server(err) => Some(err.status_code),
             _ => None,
         }
     }
 
-    /// Returns true if the error is related to a timeout
-    pub fn is_timeout(&self) -> bool {
+    /// The method of the request, if the error was generated from a response with
+    /// [Response::error_for_status_with_body](crate::http::response::Response::error_for_status_with_body)
+    pub fn method(&self) -> Option<Method> {
         match &self.kind {
-            Kind::Http(err) => err.is_timeout(),
-            _ => false,
+            Kind::Server(err) => Some(err.method),
+            _ => None,
+        }
+    }
+
+    /// The URL of the request, if the error was generated from a response
+    pub fn url(&self) -> Option<&Url> {
+        match &self.kind {
+            Kind::Status(_, url) => Some(url),
+            Kind::Server(err) => Some(&err.url),
+            _ => None,
+        }
+    }
+
+    /// The [Exception] returned by Elasticsearch, if the error was generated from a response
+    /// with [Response::error_for_status_with_body](crate::http::response::Response::error_for_status_with_body)
+    /// and the body of the response is an exception
+    pub fn exception(&self) -> Option<&Exception> {
+        match &self.kind {
+            Kind::Server(err) => err.exception.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the error was generated from a response with status code 404
+    pub fn is_not_found(&self) -> bool {
+        self.status_code() == Some(StatusCode::NOT_FOUND)
+    }
+
+    /// Returns true if the error was generated from a response with status code 409
+    pub fn is_conflict(&self) -> bool {
+        self.status_code() == Some(StatusCode::CONFLICT)
+    }
+
+    /// Returns true if the error was generated from a response with status code 429,
+    /// which Elasticsearch returns when it rejects requests because it is overloaded
+    pub fn is_too_many_requests(&self) -> bool {
+        self.status_code() == Some(StatusCode::TOO_MANY_REQUESTS)
+    }
+
+    /// Returns true if the error is a conflict because the version of a document
+    /// does not match the version, or sequence number and primary term, of the request
+    pub fn is_version_conflict(&self) -> bool {
+        self.is_conflict() && self.has_exception_type("version_conflict_engine_exception")
+    }
+
+    /// Returns true if the error is because an index that the request targets does not exist
+    pub fn is_index_not_found(&self) -> bool {
+        self.has_exception_type("index_not_found_exception")
+    }
+
+    /// Whether the exception or one of its root causes has the given type
+    fn has_exception_type(&self, ty: &str) -> bool {
+        match self.exception() {
+            Some(ex) => {
+                ex.error().ty() == Some(ty)
+                    || ex.error().root_cause().iter().any(|c| c.ty() == Some(ty))
+            }
+            None => false,
         }
     }
 
+    /// Returns true if the error is related to a timeout
+    pub fn is_timeout(&self) -> bool {
+        matches!(&self.kind, Kind::Timeout(_))
+    }
+
+    /// Returns true if the error is related to connecting to a node
+    pub fn is_connect(&self) -> bool {
+        matches!(&self.kind, Kind::Connect(_))
+    }
+
+    /// Returns true if the server that the client sent requests to could not be verified
+    /// as a supported Elasticsearch
+    pub fn is_unsupported_product(&self) -> bool {
+        matches!(&self.kind, Kind::UnsupportedProduct(_))
+    }
+
     /// Returns true if the error is related to serialization or deserialization
     pub fn is_json(&self) -> bool {
         match &self.kind {
@@ -139,7 +295,10 @@ impl error::Error for Error {
         match &self.kind {
             Kind::Build(err) => Some(err),
             Kind::Lib(_) => None,
-            Kind::Http(err) => Some(err),
+            Kind::Http(err) | Kind::Connect(err) | Kind::Timeout(err) => Some(err.as_ref()),
+            Kind::Status(_, _) => None,
+            Kind::Server(_) => None,
+            Kind::UnsupportedProduct(_) => None,
             Kind::Io(err) => Some(err),
             Kind::Json(err) => Some(err),
         }
@@ -151,7 +310,48 @@ impl fmt::Display for Error {
         match &self.kind {
             Kind::Build(err) => err.fmt(f),
             Kind::Lib(err) => err.fmt(f),
-            Kind::Http(err) => err.fmt(f),
+            Kind::Http(err) | Kind::Connect(err) | Kind::Timeout(err) => err.fmt(f),
+            Kind::Status(status_code, url) => {
+                let class = if status_code.is_client_error() {
+                    "client"
+                } else {
+                    "server"
+                };
+                write!(
+                    f,
+                    "HTTP status {} error ({}) for url ({})",
+                    class, status_code, url
+                )
+            }
+            Kind::Server(err) => {
+                let class = if err.status_code.is_client_error() {
+                    "client"
+                } else {
+                    "server"
+                };
+                write!(
+                    f,
+                    "HTTP status {} error ({}) for {} url ({})",
+                    class,
+                    err.status_code,
+                    err.method.as_str(),
+                    err.url
+                )?;
+                if let Some(ex) = &err.exception {
+                    match (ex.error().ty(), ex.error().reason()) {
+                        (Some(ty), Some(reason)) => write!(f, ": {}: {}", ty, reason)?,
+                        (Some(s), None) | (None, Some(s)) => write!(f, ": {}", s)?,
+                        (None, None) => {}
+                    }
+                }
+                Ok(())
+            }
+            Kind::UnsupportedProduct(reason) => write!(
+                f,
+                "the client noticed that the server is not Elasticsearch \
+                 and we do not support this unknown product: {}",
+                reason
+            ),
             Kind::Io(err) => err.fmt(f),
             Kind::Json(err) => err.fmt(f),
         }
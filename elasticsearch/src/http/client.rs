@@ -0,0 +1,244 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! HTTP client backends used by the [Transport](super::transport::Transport) to send requests
+//!
+//! The [Transport](super::transport::Transport) builds a backend-neutral [HttpRequest] for each
+//! API call and sends it with an [HttpClient], which returns a backend-neutral [HttpResponse].
+//! The default [HttpClient] is [ReqwestClient], enabled with the `reqwest` feature. A custom
+//! [HttpClient] can be configured with
+//! [TransportBuilder::http_client](super::transport::TransportBuilder::http_client), for example
+//! to use a different HTTP stack, custom connectors, or an in-memory backend for tests.
+use crate::{
+    error::Error,
+    http::{headers::HeaderMap, Method, StatusCode, Url},
+};
+use async_trait::async_trait;
+use bytes::Bytes;
+use dyn_clone::clone_trait_object;
+use futures_util::stream::Stream;
+use std::{fmt, fmt::Debug, pin::Pin, time::Duration};
+
+/// A stream of the chunks of a response body
+pub type BodyStream = Pin<Box<dyn Stream<Item = Result<Bytes, Error>> + Send + Sync>>;
+
+/// A HTTP request to send to a node with an [HttpClient]
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    method: Method,
+    url: Url,
+    headers: HeaderMap,
+    body: Option<Bytes>,
+    timeout: Option<Duration>,
+}
+
+impl HttpRequest {
+    /// Creates a new instance of a [HttpRequest] for the given method and url,
+    /// with no headers, body or timeout
+    pub fn new(method: Method, url: Url) -> Self {
+        Self {
+            method,
+            url,
+            headers: HeaderMap::new(),
+            body: None,
+            timeout: None,
+        }
+    }
+
+    /// Gets the request method
+    pub fn method(&self) -> Method {
+        self.method
+    }
+
+    /// Gets the request url, including the query string
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Gets a mutable reference to the request url
+    pub fn url_mut(&mut self) -> &mut Url {
+        &mut self.url
+    }
+
+    /// Gets the request headers
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Gets a mutable reference to the request headers
+    pub fn headers_mut(&mut self) -> &mut HeaderMap {
+        &mut self.headers
+    }
+
+    /// Gets the request body, if any
+    pub fn body(&self) -> Option<&Bytes> {
+        self.body.as_ref()
+    }
+
+    /// Gets a mutable reference to the request body
+    pub fn body_mut(&mut self) -> &mut Option<Bytes> {
+        &mut self.body
+    }
+
+    /// Gets the timeout for the request, if any. The timeout is applied from when
+    /// the request starts connecting until the response body has finished.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Gets a mutable reference to the timeout for the request
+    pub fn timeout_mut(&mut self) -> &mut Option<Duration> {
+        &mut self.timeout
+    }
+}
+
+/// A HTTP response returned by an [HttpClient]
+pub struct HttpResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    url: Url,
+    body: BodyStream,
+}
+
+impl HttpResponse {
+    /// Creates a new instance of a [HttpResponse]
+    pub fn new(status: StatusCode, headers: HeaderMap, url: Url, body: BodyStream) -> Self {
+        Self {
+            status,
+            headers,
+            url,
+            body,
+        }
+    }
+
+    /// Gets the HTTP status code of the response
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Gets the response headers
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Gets the url of the request that returned the response
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Consumes the response, returning the stream of the response body
+    pub fn into_body(self) -> BodyStream {
+        self.body
+    }
+
+    /// Replaces the stream of the response body with the stream returned by the function
+    pub(crate) fn map_body<F>(self, f: F) -> Self
+    where
+        F: FnOnce(BodyStream) -> BodyStream,
+    {
+        Self {
+            body: f(self.body),
+            ..self
+        }
+    }
+}
+
+impl Debug for HttpResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HttpResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .field("url", &self.url)
+            .finish()
+    }
+}
+
+/// A HTTP client that sends [HttpRequest]s to nodes.
+///
+/// Implementations report failures to connect to a node with [Error::connect] and timeouts
+/// with [Error::timeout], which the [Transport](super::transport::Transport) uses to mark
+/// nodes dead and to retry API calls.
+#[async_trait]
+pub trait HttpClient: Debug + dyn_clone::DynClone + Sync + Send {
+    /// Sends a [HttpRequest], returning the [HttpResponse] once the response headers
+    /// have been received
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error>;
+}
+
+clone_trait_object!(HttpClient);
+
+/// A [HttpClient] that sends requests with [reqwest](https://crates.io/crates/reqwest)
+#[cfg(feature = "reqwest")]
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestClient {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl ReqwestClient {
+    /// Creates a new instance of a [ReqwestClient] that sends requests
+    /// with the given [reqwest::Client]
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+
+    fn method(method: Method) -> reqwest::Method {
+        match method {
+            Method::Get => reqwest::Method::GET,
+            Method::Put => reqwest::Method::PUT,
+            Method::Post => reqwest::Method::POST,
+            Method::Delete => reqwest::Method::DELETE,
+            Method::Head => reqwest::Method::HEAD,
+        }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Client> for ReqwestClient {
+    fn from(client: reqwest::Client) -> Self {
+        Self::new(client)
+    }
+}
+
+#[cfg(feature = "reqwest")]
+#[async_trait]
+impl HttpClient for ReqwestClient {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        use futures_util::stream::TryStreamExt;
+
+        let mut request_builder = self
+            .client
+            .request(Self::method(request.method), request.url)
+            .headers(request.headers);
+
+        if let Some(t) = request.timeout {
+            request_builder = request_builder.timeout(t);
+        }
+
+        if let Some(b) = request.body {
+            request_builder = request_builder.body(b);
+        }
+
+        let response = request_builder.send().await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let url = response.url().clone();
+        let body = response.bytes_stream().map_err(Error::from);
+        Ok(HttpResponse::new(status, headers, url, Box::pin(body)))
+    }
+}
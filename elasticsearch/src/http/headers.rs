@@ -18,7 +18,7 @@
  */
 //! HTTP header names and values, including those specific to Elasticsearch
 
-pub use reqwest::header::*;
+pub use http::header::*;
 
 /// The default user-agent header value sent by the client
 pub static DEFAULT_USER_AGENT: &str = concat!("elasticsearch-rs/", env!("CARGO_PKG_VERSION"));
@@ -29,6 +29,9 @@ pub static DEFAULT_CONTENT_TYPE: &str = "application/json";
 /// The default accept header value of `application/json`
 pub static DEFAULT_ACCEPT: &str = "application/json";
 
+/// The content-type header value of `application/x-ndjson`, for newline-delimited JSON
+pub static NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
 /// The X-Opaque-Id header name, used to track certain calls, or associate
 /// certain tasks with a client that started them.
 pub static X_OPAQUE_ID: &str = "x-opaque-id";
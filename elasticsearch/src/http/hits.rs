@@ -0,0 +1,593 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Incremental deserialization of the hits of a search response
+//!
+//! [HitsStream] reads the body of a search or scroll response as it is received, and yields
+//! each of the `hits.hits` as a [Hit] as soon as it has been parsed, so that only one hit at
+//! a time is held in memory. The other fields of the response, such as `took`, `hits.total`
+//! and `aggregations`, are collected into [SearchMetadata].
+use crate::{
+    error::{self, Error},
+    http::client::BodyStream,
+};
+use futures_util::{future::poll_fn, ready, stream::Stream};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::{BTreeMap, VecDeque},
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A hit of a search response
+#[serde_with::skip_serializing_none]
+#[derive(Debug, PartialEq, Deserialize, Serialize, Clone)]
+pub struct Hit<T> {
+    #[serde(rename = "_index")]
+    index: String,
+    #[serde(rename = "_id")]
+    id: Option<String>,
+    #[serde(rename = "_score")]
+    score: Option<f64>,
+    #[serde(rename = "_source")]
+    source: Option<T>,
+    sort: Option<Vec<Value>>,
+    fields: Option<BTreeMap<String, Value>>,
+    highlight: Option<BTreeMap<String, Vec<String>>>,
+    #[serde(default = "BTreeMap::new", flatten)]
+    additional_details: BTreeMap<String, Value>,
+}
+
+impl<T> Hit<T> {
+    /// The index of the document
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /// The id of the document, if available.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// The score of the document, if available.
+    ///
+    /// Not available when sorting on a field other than `_score`
+    pub fn score(&self) -> Option<f64> {
+        self.score
+    }
+
+    /// The source of the document, if available.
+    ///
+    /// Not available when `_source` is disabled for the search
+    pub fn source(&self) -> Option<&T> {
+        self.source.as_ref()
+    }
+
+    /// Consumes the hit, returning the source of the document, if available.
+    pub fn into_source(self) -> Option<T> {
+        self.source
+    }
+
+    /// The sort values of the document, if sorted.
+    ///
+    /// The sort values of the last hit are used with `search_after` to retrieve the next page
+    pub fn sort(&self) -> Option<&[Value]> {
+        self.sort.as_deref()
+    }
+
+    /// The fields of the document requested with `fields` or `docvalue_fields`, if available.
+    pub fn fields(&self) -> Option<&BTreeMap<String, Value>> {
+        self.fields.as_ref()
+    }
+
+    /// The highlighted fragments of the document, if highlighting is requested.
+    pub fn highlight(&self) -> Option<&BTreeMap<String, Vec<String>>> {
+        self.highlight.as_ref()
+    }
+
+    /// Additional details about the hit.
+    ///
+    /// Details such as `_routing`, `_seq_no`, `inner_hits` or `matched_queries`, which do not
+    /// map to fields on [Hit], are collected here
+    pub fn additional_details(&self) -> &BTreeMap<String, Value> {
+        &self.additional_details
+    }
+}
+
+/// The relation of [Total::value] to the number of documents that match a search
+#[derive(Debug, PartialEq, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum Relation {
+    /// The value is the accurate number of matching documents
+    Eq,
+    /// The value is a lower bound of the number of matching documents
+    Gte,
+}
+
+/// The total number of documents that match a search
+#[derive(Debug, PartialEq, Serialize, Clone, Copy)]
+pub struct Total {
+    value: u64,
+    relation: Relation,
+}
+
+impl Total {
+    /// The number of matching documents
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// The relation of the value to the number of matching documents
+    pub fn relation(&self) -> Relation {
+        self.relation
+    }
+}
+
+impl<'de> Deserialize<'de> for Total {
+    /// Deserializes the total as an object, or the number returned with `rest_total_hits_as_int`
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Int(u64),
+            Object { value: u64, relation: Relation },
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Int(value) => Total {
+                value,
+                relation: Relation::Eq,
+            },
+            Repr::Object { value, relation } => Total { value, relation },
+        })
+    }
+}
+
+/// The fields of a search response other than its hits
+#[serde_with::skip_serializing_none]
+#[derive(Debug, PartialEq, Deserialize, Serialize, Clone)]
+pub struct SearchMetadata {
+    took: Option<u64>,
+    timed_out: Option<bool>,
+    #[serde(rename = "_shards")]
+    shards: Option<Value>,
+    #[serde(rename = "_scroll_id")]
+    scroll_id: Option<String>,
+    pit_id: Option<String>,
+    #[serde(default)]
+    hits: HitsMetadata,
+    aggregations: Option<Value>,
+    #[serde(default = "BTreeMap::new", flatten)]
+    additional_details: BTreeMap<String, Value>,
+}
+
+/// The fields of `hits` other than `hits.hits`
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize, Clone)]
+struct HitsMetadata {
+    total: Option<Total>,
+    max_score: Option<f64>,
+}
+
+impl SearchMetadata {
+    /// The time in milliseconds that Elasticsearch took to execute the search, if available.
+    pub fn took(&self) -> Option<u64> {
+        self.took
+    }
+
+    /// Whether the search timed out before it completed, if available.
+    pub fn timed_out(&self) -> Option<bool> {
+        self.timed_out
+    }
+
+    /// The number of shards that were searched, skipped and that failed, if available.
+    pub fn shards(&self) -> Option<&Value> {
+        self.shards.as_ref()
+    }
+
+    /// The id to retrieve the next batch of hits of a scroll, if available.
+    pub fn scroll_id(&self) -> Option<&str> {
+        self.scroll_id.as_deref()
+    }
+
+    /// The id of the point in time of the search, if available.
+    pub fn pit_id(&self) -> Option<&str> {
+        self.pit_id.as_deref()
+    }
+
+    /// The total number of documents that match the search, if tracked.
+    pub fn total(&self) -> Option<&Total> {
+        self.hits.total.as_ref()
+    }
+
+    /// The highest score of the matching documents, if available.
+    pub fn max_score(&self) -> Option<f64> {
+        self.hits.max_score
+    }
+
+    /// The aggregations of the search, if requested.
+    pub fn aggregations(&self) -> Option<&Value> {
+        self.aggregations.as_ref()
+    }
+
+    /// Additional fields of the response.
+    ///
+    /// Fields such as `suggest`, `profile` or `terminated_early`, which do not map to
+    /// fields on [SearchMetadata], are collected here
+    pub fn additional_details(&self) -> &BTreeMap<String, Value> {
+        &self.additional_details
+    }
+}
+
+/// A stream of the [Hit]s of a search response, parsed from `hits.hits` as the response body
+/// is received.
+///
+/// Once all hits have been read, the other fields of the response can be read with
+/// [HitsStream::metadata].
+pub struct HitsStream<T> {
+    body: BodyStream,
+    parser: Parser,
+    done: bool,
+    failed: bool,
+    _source: PhantomData<fn() -> T>,
+}
+
+impl<T> HitsStream<T>
+where
+    T: DeserializeOwned,
+{
+    /// Creates a new instance of [HitsStream] over a response body
+    pub(crate) fn new(body: BodyStream) -> Self {
+        Self {
+            body,
+            parser: Parser::default(),
+            done: false,
+            failed: false,
+            _source: PhantomData,
+        }
+    }
+
+    /// Reads the rest of the response body, and returns the fields of the response other
+    /// than its hits.
+    ///
+    /// Hits that have not been read from the stream are skipped.
+    pub async fn metadata(mut self) -> Result<SearchMetadata, Error> {
+        self.parser.hits.clear();
+        while !self.done {
+            poll_fn(|cx| self.poll_body(cx)).await?;
+            self.parser.hits.clear();
+        }
+
+        if self.failed {
+            return Err(error::lib(
+                "the search response could not be read, so its metadata is not available",
+            ));
+        }
+
+        Ok(serde_json::from_slice(&self.parser.rest)?)
+    }
+
+    /// Reads the next chunk of the response body into the parser
+    fn poll_body(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        let result = match ready!(self.body.as_mut().poll_next(cx)) {
+            Some(Ok(chunk)) => self.parser.push(&chunk),
+            Some(Err(err)) => Err(err),
+            None => {
+                self.done = true;
+                self.parser.finish()
+            }
+        };
+
+        if result.is_err() {
+            self.done = true;
+            self.failed = true;
+        }
+        Poll::Ready(result)
+    }
+}
+
+impl<T> Stream for HitsStream<T>
+where
+    T: DeserializeOwned,
+{
+    type Item = Result<Hit<T>, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(hit) = this.parser.hits.pop_front() {
+                return Poll::Ready(Some(serde_json::from_slice(&hit).map_err(Into::into)));
+            }
+
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            if let Err(err) = ready!(this.poll_body(cx)) {
+                return Poll::Ready(Some(Err(err)));
+            }
+        }
+    }
+}
+
+/// A container of the JSON document being parsed
+#[derive(Default)]
+struct Frame {
+    array: bool,
+    expect_key: bool,
+    key: Vec<u8>,
+}
+
+/// Splits a search response into the JSON of each of its `hits.hits`, and the JSON of
+/// the rest of the response, in which `hits.hits` is an empty array.
+///
+/// Only the structure of the document is tracked, which is validated when the hits and the
+/// rest of the response are deserialized.
+#[derive(Default)]
+struct Parser {
+    stack: Vec<Frame>,
+    in_string: bool,
+    escaped: bool,
+    in_key: bool,
+    in_hit: bool,
+    hit: Vec<u8>,
+    hits: VecDeque<Vec<u8>>,
+    rest: Vec<u8>,
+}
+
+impl Parser {
+    /// Whether the parser is directly within the `hits.hits` array, between hits
+    fn in_hits(&self) -> bool {
+        !self.in_hit
+            && self.stack.len() == 3
+            && !self.stack[0].array
+            && self.stack[0].key == b"hits"
+            && !self.stack[1].array
+            && self.stack[1].key == b"hits"
+            && self.stack[2].array
+    }
+
+    fn emit(&mut self, b: u8) {
+        if self.in_hit {
+            self.hit.push(b);
+        } else {
+            self.rest.push(b);
+        }
+    }
+
+    /// Parses a chunk of the response body
+    fn push(&mut self, chunk: &[u8]) -> Result<(), Error> {
+        for &b in chunk {
+            if self.in_string {
+                self.emit(b);
+                if self.escaped {
+                    self.escaped = false;
+                } else if b == b'\\' {
+                    self.escaped = true;
+                } else if b == b'"' {
+                    self.in_string = false;
+                }
+
+                // keys are only needed to find hits.hits
+                if self.in_key && self.in_string {
+                    if let Some(frame) = self.stack.last_mut() {
+                        frame.key.push(b);
+                    }
+                }
+                continue;
+            }
+
+            match b {
+                b'"' => {
+                    self.in_key = match self.stack.last_mut() {
+                        Some(frame) if !frame.array && frame.expect_key => {
+                            frame.key.clear();
+                            true
+                        }
+                        _ => false,
+                    } && self.stack.len() <= 2;
+                    self.in_string = true;
+                    self.emit(b);
+                }
+                b'{' | b'[' => {
+                    if self.in_hits() {
+                        if b != b'{' {
+                            return Err(error::lib("hits.hits must be an array of objects"));
+                        }
+                        self.in_hit = true;
+                    }
+                    self.emit(b);
+                    self.stack.push(Frame {
+                        array: b == b'[',
+                        expect_key: b == b'{',
+                        key: Vec::new(),
+                    });
+                }
+                b'}' | b']' => {
+                    self.emit(b);
+                    if self.stack.pop().is_none() {
+                        return Err(error::lib("unexpected end of a JSON object or array"));
+                    }
+                    if self.in_hit && self.stack.len() == 3 {
+                        self.in_hit = false;
+                        self.hits.push_back(std::mem::take(&mut self.hit));
+                    }
+                }
+                b':' => {
+                    if let Some(frame) = self.stack.last_mut() {
+                        frame.expect_key = false;
+                    }
+                    self.emit(b);
+                }
+                b',' => {
+                    if let Some(frame) = self.stack.last_mut() {
+                        frame.expect_key = !frame.array;
+                    }
+                    // hits are removed from the rest of the response, along with their separators
+                    if !self.in_hits() {
+                        self.emit(b);
+                    }
+                }
+                b' ' | b'\t' | b'\n' | b'\r' => self.emit(b),
+                _ => {
+                    if self.in_hits() {
+                        return Err(error::lib("hits.hits must be an array of objects"));
+                    }
+                    self.emit(b);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the whole response body has been parsed
+    fn finish(&self) -> Result<(), Error> {
+        if self.in_string || !self.stack.is_empty() {
+            Err(error::lib("the search response ended unexpectedly"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use futures_util::stream::{self, StreamExt};
+    use serde_json::json;
+
+    const RESPONSE: &str = r#"{
+      "took": 5,
+      "timed_out": false,
+      "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+      "hits": {
+        "total": { "value": 3, "relation": "eq" },
+        "max_score": 1.5,
+        "hits": [
+          { "_index": "posts", "_id": "1", "_score": 1.5, "_source": { "title": "{[\"quoted\"]}" } },
+          { "_index": "posts", "_id": "2", "_score": 1.0, "_source": { "title": "escaped \\\" ]}" }, "_routing": "a" },
+          { "_index": "posts", "_id": "3", "_score": null, "_source": { "title": "hits" }, "sort": [1, "a"] }
+        ]
+      },
+      "aggregations": {
+        "top": { "hits": { "total": { "value": 1, "relation": "eq" }, "hits": [ { "_index": "posts", "_id": "1" } ] } }
+      },
+      "terminated_early": false
+    }"#;
+
+    fn hits_stream(body: &str, chunk_size: usize) -> HitsStream<Value> {
+        let chunks: Vec<Result<Bytes, Error>> = body
+            .as_bytes()
+            .chunks(chunk_size)
+            .map(|c| Ok(Bytes::copy_from_slice(c)))
+            .collect();
+        HitsStream::new(Box::pin(stream::iter(chunks)))
+    }
+
+    #[tokio::test]
+    async fn yields_hits_and_collects_metadata() -> Result<(), failure::Error> {
+        for chunk_size in &[1, 7, 64, RESPONSE.len()] {
+            let mut stream = hits_stream(RESPONSE, *chunk_size);
+            let mut hits = Vec::new();
+            while let Some(hit) = stream.next().await {
+                hits.push(hit?);
+            }
+
+            assert_eq!(hits.len(), 3);
+            assert_eq!(hits[0].id(), Some("1"));
+            assert_eq!(hits[0].score(), Some(1.5));
+            assert_eq!(
+                hits[0].source(),
+                Some(&json!({ "title": "{[\"quoted\"]}" }))
+            );
+            assert_eq!(
+                hits[1].source(),
+                Some(&json!({ "title": "escaped \\\" ]}" }))
+            );
+            assert_eq!(hits[1].additional_details()["_routing"], json!("a"));
+            assert_eq!(hits[2].score(), None);
+            assert_eq!(hits[2].sort(), Some(&[json!(1), json!("a")][..]));
+
+            let metadata = stream.metadata().await?;
+            assert_eq!(metadata.took(), Some(5));
+            assert_eq!(metadata.timed_out(), Some(false));
+            assert_eq!(metadata.shards().unwrap()["successful"], json!(1));
+            assert_eq!(metadata.total().unwrap().value(), 3);
+            assert_eq!(metadata.total().unwrap().relation(), Relation::Eq);
+            assert_eq!(metadata.max_score(), Some(1.5));
+            assert_eq!(
+                metadata.aggregations().unwrap()["top"]["hits"]["hits"][0]["_id"],
+                json!("1")
+            );
+            assert_eq!(
+                metadata.additional_details().get("terminated_early"),
+                Some(&json!(false))
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn metadata_skips_unread_hits() -> Result<(), failure::Error> {
+        let mut stream = hits_stream(RESPONSE, 16);
+        let hit = stream.next().await.unwrap()?;
+        assert_eq!(hit.id(), Some("1"));
+
+        let metadata = stream.metadata().await?;
+        assert_eq!(metadata.total().unwrap().value(), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn empty_hits_and_total_as_int() -> Result<(), failure::Error> {
+        let body = r#"{"_scroll_id":"abc","hits":{"total":0,"max_score":null,"hits":[]}}"#;
+        let mut stream = hits_stream(body, 5);
+        assert!(stream.next().await.is_none());
+
+        let metadata = stream.metadata().await?;
+        assert_eq!(metadata.scroll_id(), Some("abc"));
+        assert_eq!(metadata.total().unwrap().value(), 0);
+        assert_eq!(metadata.max_score(), None);
+        assert_eq!(metadata.aggregations(), None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_responses_are_errors() {
+        let mut stream = hits_stream(r#"{"hits":{"hits":[1, 2]}}"#, 4);
+        assert!(stream.next().await.unwrap().is_err());
+        assert!(stream.next().await.is_none());
+        assert!(stream.metadata().await.is_err());
+
+        let mut stream = hits_stream(r#"{"hits":{"hits":[{"_index":"posts"}"#, 4);
+        assert!(stream.next().await.unwrap().is_ok());
+        assert!(stream.next().await.unwrap().is_err());
+        assert!(stream.metadata().await.is_err());
+
+        let mut stream = hits_stream(r#"{"hits":{"hits":[{"_id":"1"}]}}"#, 4);
+        assert!(stream.next().await.unwrap().is_err());
+    }
+}
@@ -0,0 +1,602 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Metrics for the requests sent by the [Transport](super::transport::Transport)
+//!
+//! The [Transport](super::transport::Transport) keeps metrics for each node that it sends
+//! requests to, and for each API endpoint called. Each request sent to a node, including
+//! retries, is counted against the node, whilst each API call is counted once against its
+//! endpoint, with a latency that includes retries. API calls made without an endpoint id,
+//! such as with [Elasticsearch::send](crate::Elasticsearch::send), are counted only against
+//! the nodes.
+//!
+//! A snapshot of the metrics is returned by
+//! [Transport::metrics](super::transport::Transport::metrics), which can be written in the
+//! Prometheus text exposition format with [Metrics::to_prometheus]
+//!
+//! ```rust,no_run
+//! # use elasticsearch::Elasticsearch;
+//! # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Elasticsearch::default();
+//! let response = client.ping().send().await?;
+//!
+//! let metrics = client.transport().metrics();
+//! if let Some(search) = metrics.endpoint("search") {
+//!     println!(
+//!         "p99 latency {:?}, error rate {}",
+//!         search.latency().percentile(0.99),
+//!         search.error_rate()
+//!     );
+//! }
+//! println!("{}", metrics.to_prometheus());
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    error::Error,
+    http::{response::Response, Url},
+};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// The upper bounds, in seconds, of the buckets of a latency [Histogram]
+pub const LATENCY_BUCKETS: [f64; 12] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+];
+
+/// A histogram of request latencies, using the [LATENCY_BUCKETS]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    counts: [u64; LATENCY_BUCKETS.len() + 1],
+    sum: Duration,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self {
+            counts: [0; LATENCY_BUCKETS.len() + 1],
+            sum: Duration::default(),
+        }
+    }
+}
+
+impl Histogram {
+    fn observe(&mut self, latency: Duration) {
+        let seconds = latency.as_secs_f64();
+        let bucket = LATENCY_BUCKETS
+            .iter()
+            .position(|b| seconds <= *b)
+            .unwrap_or(LATENCY_BUCKETS.len());
+        self.counts[bucket] += 1;
+        self.sum += latency;
+    }
+
+    /// The number of latencies observed
+    pub fn count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// The sum of the latencies observed
+    pub fn sum(&self) -> Duration {
+        self.sum
+    }
+
+    /// The cumulative count of latencies observed for each bucket, as pairs of the upper
+    /// bound in seconds and the count. The last bucket has an upper bound of infinity.
+    pub fn buckets(&self) -> impl Iterator<Item = (f64, u64)> + '_ {
+        LATENCY_BUCKETS
+            .iter()
+            .copied()
+            .chain(std::iter::once(f64::INFINITY))
+            .zip(self.counts.iter().scan(0, |total, count| {
+                *total += count;
+                Some(*total)
+            }))
+    }
+
+    /// Estimates the latency at the given percentile, between `0.0` and `1.0`, as the upper
+    /// bound of the bucket that it falls into. Returns `None` when no latencies have been
+    /// observed, or the percentile falls into the last bucket, which has no upper bound.
+    pub fn percentile(&self, percentile: f64) -> Option<Duration> {
+        let count = self.count();
+        if count == 0 {
+            return None;
+        }
+
+        let rank = (percentile.clamp(0.0, 1.0) * count as f64).ceil().max(1.0) as u64;
+        self.buckets()
+            .find(|(_, total)| *total >= rank)
+            .filter(|(bound, _)| bound.is_finite())
+            .map(|(bound, _)| Duration::from_secs_f64(bound))
+    }
+}
+
+/// Metrics for the requests sent to a node, or the API calls made to an endpoint
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RequestMetrics {
+    in_flight: u64,
+    requests: u64,
+    errors: u64,
+    status_classes: [u64; 5],
+    retries: u64,
+    bytes_sent: u64,
+    bytes_received: u64,
+    latency: Histogram,
+}
+
+impl RequestMetrics {
+    fn record(&mut self, response: &Result<Response, Error>, latency: Duration, bytes_sent: u64) {
+        self.requests += 1;
+        self.bytes_sent += bytes_sent;
+        self.latency.observe(latency);
+        match response {
+            Ok(r) => {
+                let class = (r.status_code().as_u16() / 100) as usize;
+                if (1..=5).contains(&class) {
+                    self.status_classes[class - 1] += 1;
+                }
+            }
+            Err(_) => self.errors += 1,
+        }
+    }
+
+    /// The number of requests currently in flight
+    pub fn in_flight(&self) -> u64 {
+        self.in_flight
+    }
+
+    /// The number of requests completed, either with a response or an error
+    pub fn requests(&self) -> u64 {
+        self.requests
+    }
+
+    /// The number of requests that failed without a response, such as when a
+    /// connection could not be established or the request timed out
+    pub fn errors(&self) -> u64 {
+        self.errors
+    }
+
+    /// The number of responses with a status code in the given class, from `1` for
+    /// `1xx` to `5` for `5xx` status codes
+    pub fn status_class(&self, class: u16) -> u64 {
+        match class {
+            1..=5 => self.status_classes[class as usize - 1],
+            _ => 0,
+        }
+    }
+
+    /// The fraction of requests that failed without a response or with a `5xx` status code
+    pub fn error_rate(&self) -> f64 {
+        if self.requests == 0 {
+            0.0
+        } else {
+            (self.errors + self.status_class(5)) as f64 / self.requests as f64
+        }
+    }
+
+    /// For an endpoint, the number of times its API calls were retried. For a node,
+    /// the number of requests sent to it that were retried.
+    pub fn retries(&self) -> u64 {
+        self.retries
+    }
+
+    /// The number of request body bytes sent
+    pub fn bytes_sent(&self) -> u64 {
+        self.bytes_sent
+    }
+
+    /// The number of response body bytes read, after any decompression. Bytes are counted
+    /// as the body of a response is read, so a response whose body is not read counts none.
+    pub fn bytes_received(&self) -> u64 {
+        self.bytes_received
+    }
+
+    /// The latency of requests
+    pub fn latency(&self) -> &Histogram {
+        &self.latency
+    }
+}
+
+/// Metrics for the requests sent to a node
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NodeMetrics {
+    requests: RequestMetrics,
+    dead_transitions: u64,
+    alive_transitions: u64,
+}
+
+impl NodeMetrics {
+    /// Metrics for the requests sent to the node
+    pub fn requests(&self) -> &RequestMetrics {
+        &self.requests
+    }
+
+    /// The number of times the node was marked dead by the connection pool
+    pub fn dead_transitions(&self) -> u64 {
+        self.dead_transitions
+    }
+
+    /// The number of times the node was marked alive again after being marked dead
+    pub fn alive_transitions(&self) -> u64 {
+        self.alive_transitions
+    }
+}
+
+/// A snapshot of the metrics kept by the [Transport](super::transport::Transport)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Metrics {
+    nodes: BTreeMap<Url, NodeMetrics>,
+    endpoints: BTreeMap<&'static str, RequestMetrics>,
+}
+
+impl Metrics {
+    /// Metrics for the node with the given url, if any requests have been sent to it
+    pub fn node(&self, url: &Url) -> Option<&NodeMetrics> {
+        self.nodes.get(url)
+    }
+
+    /// Metrics for each node that requests have been sent to
+    pub fn nodes(&self) -> impl Iterator<Item = (&Url, &NodeMetrics)> {
+        self.nodes.iter()
+    }
+
+    /// Metrics for the API endpoint with the given id, such as `search`, if it has been called
+    pub fn endpoint(&self, endpoint: &str) -> Option<&RequestMetrics> {
+        self.endpoints.get(endpoint)
+    }
+
+    /// Metrics for each API endpoint that has been called
+    pub fn endpoints(&self) -> impl Iterator<Item = (&'static str, &RequestMetrics)> {
+        self.endpoints.iter().map(|(e, m)| (*e, m))
+    }
+
+    /// Writes the metrics in the Prometheus text exposition format. Node metrics are
+    /// prefixed with `elasticsearch_node_` and labelled with `node`, and endpoint metrics
+    /// are prefixed with `elasticsearch_endpoint_` and labelled with `endpoint`.
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        let nodes: Vec<_> = self
+            .nodes
+            .iter()
+            .map(|(url, m)| (url.as_str(), &m.requests))
+            .collect();
+        write_requests(&mut out, "elasticsearch_node", "node", &nodes);
+        write_family(
+            &mut out,
+            "elasticsearch_node_dead_transitions_total",
+            "counter",
+            "Number of times the node was marked dead",
+            self.nodes
+                .iter()
+                .map(|(url, m)| (label("node", url.as_str()), m.dead_transitions)),
+        );
+        write_family(
+            &mut out,
+            "elasticsearch_node_alive_transitions_total",
+            "counter",
+            "Number of times the node was marked alive after being marked dead",
+            self.nodes
+                .iter()
+                .map(|(url, m)| (label("node", url.as_str()), m.alive_transitions)),
+        );
+
+        let endpoints: Vec<_> = self.endpoints.iter().map(|(e, m)| (*e, m)).collect();
+        write_requests(&mut out, "elasticsearch_endpoint", "endpoint", &endpoints);
+        out
+    }
+}
+
+fn label(name: &str, value: &str) -> String {
+    let value = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("{}=\"{}\"", name, value)
+}
+
+fn write_family<I>(out: &mut String, name: &str, ty: &str, help: &str, samples: I)
+where
+    I: IntoIterator<Item = (String, u64)>,
+{
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, ty);
+    for (labels, value) in samples {
+        let _ = writeln!(out, "{}{{{}}} {}", name, labels, value);
+    }
+}
+
+/// The name, type, help and value of a metric family written for [RequestMetrics]
+type Family = (
+    &'static str,
+    &'static str,
+    &'static str,
+    fn(&RequestMetrics) -> u64,
+);
+
+fn write_requests(out: &mut String, prefix: &str, key: &str, metrics: &[(&str, &RequestMetrics)]) {
+    let counters: [Family; 6] = [
+        (
+            "requests_in_flight",
+            "gauge",
+            "Number of requests in flight",
+            |m| m.in_flight,
+        ),
+        (
+            "requests_total",
+            "counter",
+            "Number of requests completed",
+            |m| m.requests,
+        ),
+        (
+            "errors_total",
+            "counter",
+            "Number of requests that failed without a response",
+            |m| m.errors,
+        ),
+        (
+            "retries_total",
+            "counter",
+            "Number of requests retried",
+            |m| m.retries,
+        ),
+        (
+            "request_bytes_total",
+            "counter",
+            "Number of request body bytes sent",
+            |m| m.bytes_sent,
+        ),
+        (
+            "response_bytes_total",
+            "counter",
+            "Number of response body bytes read",
+            |m| m.bytes_received,
+        ),
+    ];
+    for (name, ty, help, value) in counters.iter() {
+        write_family(
+            out,
+            &format!("{}_{}", prefix, name),
+            ty,
+            help,
+            metrics.iter().map(|(k, m)| (label(key, k), value(m))),
+        );
+    }
+
+    write_family(
+        out,
+        &format!("{}_responses_total", prefix),
+        "counter",
+        "Number of responses by status code class",
+        metrics.iter().flat_map(|(k, m)| {
+            (1..=5).map(move |class| {
+                (
+                    format!(
+                        "{},{}",
+                        label(key, k),
+                        label("class", &format!("{}xx", class))
+                    ),
+                    m.status_class(class),
+                )
+            })
+        }),
+    );
+
+    let name = format!("{}_request_duration_seconds", prefix);
+    let _ = writeln!(out, "# HELP {} Latency of requests", name);
+    let _ = writeln!(out, "# TYPE {} histogram", name);
+    for (k, m) in metrics {
+        let l = label(key, k);
+        for (bound, count) in m.latency.buckets() {
+            let le = if bound.is_finite() {
+                bound.to_string()
+            } else {
+                "+Inf".to_string()
+            };
+            let _ = writeln!(out, "{}_bucket{{{},le=\"{}\"}} {}", name, l, le, count);
+        }
+        let _ = writeln!(out, "{}_sum{{{}}} {}", name, l, m.latency.sum.as_secs_f64());
+        let _ = writeln!(out, "{}_count{{{}}} {}", name, l, m.latency.count());
+    }
+}
+
+/// Records the metrics kept by the [Transport](super::transport::Transport). Clones
+/// record to the same metrics.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MetricsRecorder {
+    metrics: Arc<Mutex<Metrics>>,
+}
+
+impl MetricsRecorder {
+    /// A snapshot of the metrics recorded
+    pub fn snapshot(&self) -> Metrics {
+        self.metrics.lock().unwrap().clone()
+    }
+
+    /// Starts an API call to the given endpoint, which is in flight until the
+    /// returned [InFlight] is dropped
+    pub fn start_call(&self, endpoint: Option<&'static str>) -> InFlight {
+        if let Some(e) = endpoint {
+            self.metrics
+                .lock()
+                .unwrap()
+                .endpoints
+                .entry(e)
+                .or_default()
+                .in_flight += 1;
+        }
+        InFlight {
+            metrics: self.metrics.clone(),
+            key: endpoint.map(Key::Endpoint),
+            start: Instant::now(),
+        }
+    }
+
+    /// Starts a request to the node with the given url, which is in flight until the
+    /// returned [InFlight] is dropped
+    pub fn start_request(&self, url: &Url) -> InFlight {
+        self.metrics
+            .lock()
+            .unwrap()
+            .nodes
+            .entry(url.clone())
+            .or_default()
+            .requests
+            .in_flight += 1;
+        InFlight {
+            metrics: self.metrics.clone(),
+            key: Some(Key::Node(url.clone())),
+            start: Instant::now(),
+        }
+    }
+
+    /// Records the response body bytes read for an API call to the given endpoint, sent to
+    /// the node with the given url
+    pub fn bytes_received(&self, url: &Url, endpoint: Option<&'static str>) -> BytesReceived {
+        BytesReceived {
+            metrics: self.metrics.clone(),
+            url: url.clone(),
+            endpoint,
+        }
+    }
+
+    /// Records that the node with the given url was marked dead
+    pub fn dead(&self, url: &Url) {
+        let mut metrics = self.metrics.lock().unwrap();
+        metrics
+            .nodes
+            .entry(url.clone())
+            .or_default()
+            .dead_transitions += 1;
+    }
+
+    /// Records that the node with the given url was marked alive after being marked dead
+    pub fn alive(&self, url: &Url) {
+        let mut metrics = self.metrics.lock().unwrap();
+        metrics
+            .nodes
+            .entry(url.clone())
+            .or_default()
+            .alive_transitions += 1;
+    }
+}
+
+#[derive(Debug)]
+enum Key {
+    Node(Url),
+    Endpoint(&'static str),
+}
+
+/// An API call or request to a node that is in flight
+#[derive(Debug)]
+pub(crate) struct InFlight {
+    metrics: Arc<Mutex<Metrics>>,
+    key: Option<Key>,
+    start: Instant,
+}
+
+impl InFlight {
+    fn with<F: FnOnce(&mut RequestMetrics)>(&self, f: F) {
+        let mut metrics = self.metrics.lock().unwrap();
+        match &self.key {
+            Some(Key::Node(url)) => f(&mut metrics.nodes.entry(url.clone()).or_default().requests),
+            Some(Key::Endpoint(e)) => f(metrics.endpoints.entry(e).or_default()),
+            None => {}
+        }
+    }
+
+    /// Records the response or error returned, and the number of request body bytes sent
+    pub fn finish(&self, response: &Result<Response, Error>, bytes_sent: u64) {
+        let latency = self.start.elapsed();
+        self.with(|m| m.record(response, latency, bytes_sent));
+    }
+
+    /// Records that the API call or request was retried
+    pub fn retried(&self) {
+        self.with(|m| m.retries += 1);
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.with(|m| m.in_flight -= 1);
+    }
+}
+
+/// Records the response body bytes read for an API call, to its node and endpoint
+#[derive(Debug)]
+pub(crate) struct BytesReceived {
+    metrics: Arc<Mutex<Metrics>>,
+    url: Url,
+    endpoint: Option<&'static str>,
+}
+
+impl BytesReceived {
+    /// Records that the number of response body bytes were read
+    pub fn add(&self, bytes: u64) {
+        let mut metrics = self.metrics.lock().unwrap();
+        metrics
+            .nodes
+            .entry(self.url.clone())
+            .or_default()
+            .requests
+            .bytes_received += bytes;
+        if let Some(e) = self.endpoint {
+            metrics.endpoints.entry(e).or_default().bytes_received += bytes;
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_percentile() {
+        let mut histogram = Histogram::default();
+        assert_eq!(histogram.percentile(0.5), None);
+
+        for _ in 0..90 {
+            histogram.observe(Duration::from_millis(3));
+        }
+        for _ in 0..9 {
+            histogram.observe(Duration::from_millis(80));
+        }
+        histogram.observe(Duration::from_secs(60));
+
+        assert_eq!(histogram.count(), 100);
+        assert_eq!(
+            histogram.percentile(0.5),
+            Some(Duration::from_secs_f64(0.005))
+        );
+        assert_eq!(
+            histogram.percentile(0.99),
+            Some(Duration::from_secs_f64(0.1))
+        );
+        assert_eq!(histogram.percentile(1.0), None);
+        assert_eq!(histogram.buckets().last(), Some((f64::INFINITY, 100)));
+    }
+
+    #[test]
+    fn prometheus_escapes_labels() {
+        assert_eq!(label("endpoint", "a\"b\\c"), r#"endpoint="a\"b\\c""#);
+    }
+}
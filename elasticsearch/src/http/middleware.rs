@@ -0,0 +1,169 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Middleware that runs around each request sent by the [Transport](super::transport::Transport)
+//!
+//! [Middleware] is registered with
+//! [TransportBuilder::middleware](super::transport::TransportBuilder::middleware), and runs in
+//! the order registered for each request sent to a node, including retries. Each [Middleware]
+//! can inspect and mutate the outgoing [HttpRequest], pass it to the [Next] middleware, and
+//! observe the [Response] or [Error] that it returns.
+//!
+//! ```rust,no_run
+//! # use async_trait::async_trait;
+//! # use elasticsearch::{
+//! #     Error,
+//! #     http::{
+//! #         client::HttpRequest,
+//! #         headers::HeaderValue,
+//! #         middleware::{Middleware, Next},
+//! #         response::Response,
+//! #     },
+//! # };
+//! # use std::time::Instant;
+//! #[derive(Debug, Clone)]
+//! struct Tenant(&'static str);
+//!
+//! #[async_trait]
+//! impl Middleware for Tenant {
+//!     async fn handle(&self, mut request: HttpRequest, next: Next<'_>) -> Result<Response, Error> {
+//!         request
+//!             .headers_mut()
+//!             .insert("x-tenant", HeaderValue::from_static(self.0));
+//!         let start = Instant::now();
+//!         let response = next.run(request).await;
+//!         println!("request took {:?}", start.elapsed());
+//!         response
+//!     }
+//! }
+//! ```
+use crate::{
+    error::Error,
+    http::{
+        client::{HttpClient, HttpRequest},
+        response::Response,
+    },
+};
+use async_trait::async_trait;
+use dyn_clone::clone_trait_object;
+use std::fmt::Debug;
+
+/// Middleware that runs around each request sent by the [Transport](super::transport::Transport)
+#[async_trait]
+pub trait Middleware: Debug + dyn_clone::DynClone + Sync + Send {
+    /// Handles a request, passing it to the [Next] middleware to send it and returning
+    /// the [Response]. Middleware may also return a [Response] or [Error] without
+    /// sending the request.
+    async fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<Response, Error>;
+}
+
+clone_trait_object!(Middleware);
+
+/// The remaining [Middleware] to run for a request, followed by the [HttpClient] that sends it
+pub struct Next<'a> {
+    client: &'a dyn HttpClient,
+    middleware: &'a [Box<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(client: &'a dyn HttpClient, middleware: &'a [Box<dyn Middleware>]) -> Self {
+        Self { client, middleware }
+    }
+
+    /// Runs the remaining [Middleware] for the request, then sends it
+    pub async fn run(self, request: HttpRequest) -> Result<Response, Error> {
+        match self.middleware.split_first() {
+            Some((middleware, rest)) => {
+                middleware
+                    .handle(request, Next::new(self.client, rest))
+                    .await
+            }
+            None => {
+                let method = request.method();
+                let response = self.client.send(request).await?;
+                Ok(Response::new(response, method))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::http::{
+        mock::{MockHttpClient, MockRequest, MockResponse},
+        Method, StatusCode, Url,
+    };
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone)]
+    struct Record {
+        name: &'static str,
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl Middleware for Record {
+        async fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<Response, Error> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("{} request", self.name));
+            let response = next.run(request).await;
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("{} response", self.name));
+            response
+        }
+    }
+
+    #[tokio::test]
+    async fn middleware_runs_in_order() -> Result<(), failure::Error> {
+        let client = MockHttpClient::new().response(
+            MockRequest::new(Method::Get, "/"),
+            MockResponse::new(StatusCode::OK),
+        );
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let middleware: Vec<Box<dyn Middleware>> = vec![
+            Box::new(Record {
+                name: "first",
+                calls: calls.clone(),
+            }),
+            Box::new(Record {
+                name: "second",
+                calls: calls.clone(),
+            }),
+        ];
+
+        let request = HttpRequest::new(Method::Get, Url::parse("http://localhost:9200/")?);
+        let response = Next::new(&client, &middleware).run(request).await?;
+
+        assert_eq!(response.status_code(), StatusCode::OK);
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                "first request",
+                "second request",
+                "second response",
+                "first response"
+            ]
+        );
+        Ok(())
+    }
+}
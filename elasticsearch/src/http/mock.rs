@@ -0,0 +1,639 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Mock HTTP clients to test code that uses the client without a running cluster
+//!
+//! [MockHttpClient] serves canned [MockResponse]s to requests that match a [MockRequest],
+//! by method, path, query string and body. Canned responses can be added in code, or loaded
+//! from a JSON [Cassette] file that [RecordingHttpClient] records from the requests sent to
+//! a real cluster, so that tests recorded once can later replay offline, for example in CI.
+//!
+//! Both are [HttpClient]s that are used with any [ConnectionPool](super::transport::ConnectionPool)
+//! by passing them to [TransportBuilder::http_client](super::transport::TransportBuilder::http_client)
+//!
+//! ```rust,no_run
+//! # use elasticsearch::{
+//! #     Elasticsearch, Error, SearchParts,
+//! #     http::{
+//! #         mock::{MockHttpClient, MockRequest, MockResponse},
+//! #         transport::{SingleNodeConnectionPool, TransportBuilder},
+//! #         Method, StatusCode,
+//! #     },
+//! # };
+//! # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+//! let http_client = MockHttpClient::new().response(
+//!     MockRequest::new(Method::Get, "/posts/_search").query("q=title%3Aelasticsearch"),
+//!     MockResponse::new(StatusCode::OK)
+//!         .header("X-Elastic-Product", "Elasticsearch")
+//!         .body(r#"{"hits":{"hits":[]}}"#),
+//! );
+//! let transport = TransportBuilder::new(SingleNodeConnectionPool::default())
+//!     .http_client(http_client)
+//!     .build()?;
+//! let client = Elasticsearch::new(transport);
+//! let response = client
+//!     .search(SearchParts::Index(&["posts"]))
+//!     .q("title:elasticsearch")
+//!     .send()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    error::Error,
+    http::{
+        client::{HttpClient, HttpRequest, HttpResponse},
+        headers::{HeaderMap, HeaderName, HeaderValue},
+        Method, StatusCode, Url,
+    },
+};
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::stream::TryStreamExt;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+/// The body of a [MockRequest] or [MockResponse].
+///
+/// Bodies are stored as the bytes sent or received, so that compressed and binary bodies replay
+/// unchanged. In a [Cassette], UTF-8 bodies are stored as text, and other bodies are base64
+/// encoded as `{"base64": "..."}`.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct MockBody(Bytes);
+
+impl MockBody {
+    fn new(body: impl AsRef<[u8]>) -> Self {
+        Self(Bytes::copy_from_slice(body.as_ref()))
+    }
+
+    /// Whether the bodies are the same bytes, or are equal JSON values
+    fn matches(&self, other: &MockBody) -> bool {
+        self.0 == other.0
+            || match (
+                serde_json::from_slice::<serde_json::Value>(&self.0),
+                serde_json::from_slice::<serde_json::Value>(&other.0),
+            ) {
+                (Ok(expected), Ok(actual)) => expected == actual,
+                _ => false,
+            }
+    }
+}
+
+/// The representation of a [MockBody] in a [Cassette]
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum EncodedBody {
+    Text(String),
+    Base64 { base64: String },
+}
+
+impl Serialize for MockBody {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let encoded = match std::str::from_utf8(&self.0) {
+            Ok(text) => EncodedBody::Text(text.to_string()),
+            Err(_) => EncodedBody::Base64 {
+                base64: base64::encode(&self.0),
+            },
+        };
+        encoded.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MockBody {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match EncodedBody::deserialize(deserializer)? {
+            EncodedBody::Text(text) => Ok(Self(Bytes::from(text))),
+            EncodedBody::Base64 { base64 } => {
+                let body = base64::decode(&base64).map_err(de::Error::custom)?;
+                Ok(Self(Bytes::from(body)))
+            }
+        }
+    }
+}
+
+/// A request that a [MockHttpClient] matches against the requests it is sent
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MockRequest {
+    method: Method,
+    path: String,
+    query: Option<String>,
+    body: Option<MockBody>,
+}
+
+impl MockRequest {
+    /// Creates a new instance of [MockRequest] for the given method and path,
+    /// that matches requests without a query string or body
+    pub fn new(method: Method, path: impl Into<String>) -> Self {
+        Self {
+            method,
+            path: path.into(),
+            query: None,
+            body: None,
+        }
+    }
+
+    /// Sets the url encoded query string to match
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Sets the body to match. Bodies match when they are the same bytes, or when both
+    /// are JSON and are equal as JSON values.
+    pub fn body(mut self, body: impl AsRef<[u8]>) -> Self {
+        self.body = Some(MockBody::new(body));
+        self
+    }
+
+    fn from_http_request(request: &HttpRequest) -> Self {
+        Self {
+            method: request.method(),
+            path: request.url().path().to_string(),
+            query: request.url().query().map(String::from),
+            body: request.body().map(|b| MockBody(b.clone())),
+        }
+    }
+
+    /// Whether this matches the given request
+    fn matches(&self, request: &MockRequest) -> bool {
+        self.method == request.method
+            && self.path == request.path
+            && self.query == request.query
+            && match (&self.body, &request.body) {
+                (Some(expected), Some(actual)) => expected.matches(actual),
+                (expected, actual) => expected == actual,
+            }
+    }
+}
+
+/// A canned response that a [MockHttpClient] returns for a matching [MockRequest]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MockResponse {
+    status: u16,
+    #[serde(default)]
+    headers: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    body: MockBody,
+}
+
+impl MockResponse {
+    /// Creates a new instance of [MockResponse] with the given status code,
+    /// and no headers or body
+    pub fn new(status: StatusCode) -> Self {
+        Self {
+            status: status.as_u16(),
+            headers: BTreeMap::new(),
+            body: MockBody::default(),
+        }
+    }
+
+    /// Adds a response header
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers
+            .entry(name.into().to_lowercase())
+            .or_default()
+            .push(value.into());
+        self
+    }
+
+    /// Sets the response body
+    pub fn body(mut self, body: impl AsRef<[u8]>) -> Self {
+        self.body = MockBody::new(body);
+        self
+    }
+
+    fn from_parts(status: StatusCode, headers: &HeaderMap, body: &[u8]) -> Self {
+        let mut response = Self::new(status).body(body);
+        for (name, value) in headers.iter() {
+            if let Ok(value) = value.to_str() {
+                response = response.header(name.as_str(), value);
+            }
+        }
+        response
+    }
+
+    fn to_http_response(&self, url: Url) -> Result<HttpResponse, Error> {
+        let status =
+            StatusCode::from_u16(self.status).map_err(|e| crate::error::lib(e.to_string()))?;
+        let mut headers = HeaderMap::new();
+        for (name, values) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| crate::error::lib(e.to_string()))?;
+            for value in values {
+                let value =
+                    HeaderValue::from_str(value).map_err(|e| crate::error::lib(e.to_string()))?;
+                headers.append(name.clone(), value);
+            }
+        }
+
+        let body = self.body.0.clone();
+        let stream = futures_util::stream::once(async move { Ok(body) });
+        Ok(HttpResponse::new(status, headers, url, Box::pin(stream)))
+    }
+}
+
+/// A request and the response returned for it
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Interaction {
+    request: MockRequest,
+    response: MockResponse,
+}
+
+impl Interaction {
+    /// Creates a new instance of [Interaction]
+    pub fn new(request: MockRequest, response: MockResponse) -> Self {
+        Self { request, response }
+    }
+
+    /// The request
+    pub fn request(&self) -> &MockRequest {
+        &self.request
+    }
+
+    /// The response returned for the request
+    pub fn response(&self) -> &MockResponse {
+        &self.response
+    }
+}
+
+/// A list of [Interaction]s, that can be saved to and loaded from a JSON file
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct Cassette {
+    interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Creates a new, empty instance of [Cassette]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a [Cassette] from a JSON file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let reader = BufReader::new(File::open(path)?);
+        let cassette = serde_json::from_reader(reader)?;
+        Ok(cassette)
+    }
+
+    /// Saves the [Cassette] to a JSON file, overwriting any existing file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(writer, self)?;
+        Ok(())
+    }
+
+    /// Adds an [Interaction]
+    pub fn push(&mut self, interaction: Interaction) {
+        self.interactions.push(interaction);
+    }
+
+    /// The recorded [Interaction]s
+    pub fn interactions(&self) -> &[Interaction] {
+        &self.interactions
+    }
+}
+
+/// A [HttpClient] that returns canned responses, without sending requests.
+///
+/// Each request is matched against the [MockRequest]s in the order they were added. A
+/// response is returned once for each matching [MockRequest], so that the same request
+/// can return different responses over time, after which the last matching response is
+/// returned for any further matching requests. A request that matches no [MockRequest]
+/// returns an error.
+#[derive(Debug, Clone, Default)]
+pub struct MockHttpClient {
+    interactions: Arc<Mutex<Vec<(Interaction, bool)>>>,
+}
+
+impl MockHttpClient {
+    /// Creates a new instance of [MockHttpClient] with no canned responses
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new instance of [MockHttpClient] that returns the responses in a [Cassette]
+    pub fn from_cassette(cassette: Cassette) -> Self {
+        let client = Self::new();
+        client
+            .interactions
+            .lock()
+            .unwrap()
+            .extend(cassette.interactions.into_iter().map(|i| (i, false)));
+        client
+    }
+
+    /// Creates a new instance of [MockHttpClient] that returns the responses in
+    /// a [Cassette] JSON file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Ok(Self::from_cassette(Cassette::load(path)?))
+    }
+
+    /// Adds a canned response to return for requests that match the [MockRequest]
+    pub fn response(self, request: MockRequest, response: MockResponse) -> Self {
+        self.interactions
+            .lock()
+            .unwrap()
+            .push((Interaction::new(request, response), false));
+        self
+    }
+}
+
+#[async_trait]
+impl HttpClient for MockHttpClient {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        let actual = MockRequest::from_http_request(&request);
+        let mut interactions = self.interactions.lock().unwrap();
+        let mut matching = interactions
+            .iter_mut()
+            .filter(|(i, _)| i.request.matches(&actual))
+            .peekable();
+
+        let mut last = None;
+        while let Some((interaction, replayed)) = matching.next() {
+            if !*replayed || matching.peek().is_none() {
+                *replayed = true;
+                last = Some(interaction.response.clone());
+                break;
+            }
+        }
+
+        match last {
+            Some(response) => response.to_http_response(request.url().clone()),
+            None => Err(crate::error::lib(format!(
+                "no mock response for {:?} {}",
+                actual.method,
+                request.url()
+            ))),
+        }
+    }
+}
+
+/// A [HttpClient] that records the requests sent with another [HttpClient] and their
+/// responses to a [Cassette], to replay later with a [MockHttpClient].
+///
+/// Response bodies are read in full before they are returned.
+#[derive(Debug, Clone)]
+pub struct RecordingHttpClient {
+    client: Box<dyn HttpClient>,
+    cassette: Arc<Mutex<Cassette>>,
+}
+
+impl RecordingHttpClient {
+    /// Creates a new instance of [RecordingHttpClient] that sends requests with the given
+    /// [HttpClient], such as [ReqwestClient](super::client::ReqwestClient)
+    pub fn new<C>(client: C) -> Self
+    where
+        C: HttpClient + 'static,
+    {
+        Self {
+            client: Box::new(client),
+            cassette: Arc::new(Mutex::new(Cassette::new())),
+        }
+    }
+
+    /// Gets a [Cassette] of the requests recorded so far
+    pub fn cassette(&self) -> Cassette {
+        self.cassette.lock().unwrap().clone()
+    }
+
+    /// Saves the requests recorded so far to a [Cassette] JSON file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        self.cassette().save(path)
+    }
+}
+
+#[async_trait]
+impl HttpClient for RecordingHttpClient {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        let mock_request = MockRequest::from_http_request(&request);
+        let response = self.client.send(request).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let url = response.url().clone();
+        let body: Vec<u8> = response
+            .into_body()
+            .try_fold(Vec::new(), |mut body, chunk| async move {
+                body.extend_from_slice(&chunk);
+                Ok(body)
+            })
+            .await?;
+
+        let mock_response = MockResponse::from_parts(status, &headers, &body);
+        self.cassette
+            .lock()
+            .unwrap()
+            .push(Interaction::new(mock_request, mock_response));
+
+        let body = Bytes::from(body);
+        let stream = futures_util::stream::once(async move { Ok(body) });
+        Ok(HttpResponse::new(status, headers, url, Box::pin(stream)))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::http::response::Response;
+
+    fn request(method: Method, url: &str, body: Option<&str>) -> HttpRequest {
+        let mut request = HttpRequest::new(method, Url::parse(url).unwrap());
+        *request.body_mut() = body.map(|b| Bytes::from(b.to_string()));
+        request
+    }
+
+    #[test]
+    fn mock_request_matches_method_path_query_and_body() {
+        let mock = MockRequest::new(Method::Post, "/posts/_search")
+            .query("size=1")
+            .body(r#"{"query":{"match_all":{}}}"#);
+
+        let matches = |method, url, body| {
+            mock.matches(&MockRequest::from_http_request(&request(method, url, body)))
+        };
+
+        let body = Some(r#"{ "query": { "match_all": {} } }"#);
+        assert!(matches(
+            Method::Post,
+            "http://localhost:9200/posts/_search?size=1",
+            body
+        ));
+        assert!(!matches(
+            Method::Get,
+            "http://localhost:9200/posts/_search?size=1",
+            body
+        ));
+        assert!(!matches(
+            Method::Post,
+            "http://localhost:9200/other/_search?size=1",
+            body
+        ));
+        assert!(!matches(
+            Method::Post,
+            "http://localhost:9200/posts/_search",
+            body
+        ));
+        assert!(!matches(
+            Method::Post,
+            "http://localhost:9200/posts/_search?size=1",
+            None
+        ));
+        assert!(!matches(
+            Method::Post,
+            "http://localhost:9200/posts/_search?size=1",
+            Some(r#"{"query":{"match_none":{}}}"#)
+        ));
+    }
+
+    #[tokio::test]
+    async fn mock_http_client_replays_responses_in_order() -> Result<(), failure::Error> {
+        let client = MockHttpClient::new()
+            .response(
+                MockRequest::new(Method::Get, "/"),
+                MockResponse::new(StatusCode::OK).body("first"),
+            )
+            .response(
+                MockRequest::new(Method::Get, "/"),
+                MockResponse::new(StatusCode::OK)
+                    .header("Content-Type", "text/plain")
+                    .body("second"),
+            );
+
+        let mut bodies = Vec::new();
+        for _ in 0..3 {
+            let response = client
+                .send(request(Method::Get, "http://localhost:9200/", None))
+                .await?;
+            bodies.push(Response::new(response, Method::Get).text().await?);
+        }
+        assert_eq!(bodies, vec!["first", "second", "second"]);
+
+        let response = client
+            .send(request(Method::Get, "http://localhost:9200/", None))
+            .await?;
+        assert_eq!(response.headers()["content-type"], "text/plain");
+
+        let result = client
+            .send(request(Method::Head, "http://localhost:9200/", None))
+            .await;
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn cassette_round_trips_through_json_file() -> Result<(), failure::Error> {
+        let mut cassette = Cassette::new();
+        cassette.push(Interaction::new(
+            MockRequest::new(Method::Post, "/posts/_doc").body(r#"{"title":"test"}"#),
+            MockResponse::new(StatusCode::CREATED)
+                .header("content-type", "application/json")
+                .body(r#"{"result":"created"}"#),
+        ));
+
+        let path = std::env::temp_dir().join("elasticsearch-rs-cassette-test.json");
+        cassette.save(&path)?;
+        let loaded = Cassette::load(&path)?;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(loaded, cassette);
+        Ok(())
+    }
+
+    #[test]
+    fn cassette_bodies_are_text_unless_binary() -> Result<(), failure::Error> {
+        let text = serde_json::to_value(MockBody::new(r#"{"title":"test"}"#))?;
+        assert_eq!(text, serde_json::json!(r#"{"title":"test"}"#));
+
+        let binary = serde_json::to_value(MockBody::new([0x1f, 0x8b, 0xff]))?;
+        assert_eq!(binary, serde_json::json!({ "base64": "H4v/" }));
+
+        assert_eq!(
+            serde_json::from_value::<MockBody>(binary)?,
+            MockBody::new([0x1f, 0x8b, 0xff])
+        );
+        Ok(())
+    }
+
+    /// Sends a compressed request body with the client, returning the response body
+    async fn send_compressed<C>(client: C, body: &str) -> Result<Bytes, Error>
+    where
+        C: HttpClient + 'static,
+    {
+        use crate::http::transport::{SingleNodeConnectionPool, TransportBuilder};
+
+        let transport = TransportBuilder::new(SingleNodeConnectionPool::default())
+            .http_client(client)
+            .request_body_compression(true)
+            .build()?;
+        let response = transport
+            .send(
+                Method::Post,
+                "/_search",
+                HeaderMap::new(),
+                Option::<&()>::None,
+                Some(body.as_bytes()),
+                None,
+            )
+            .await?;
+        response.bytes().await
+    }
+
+    #[tokio::test]
+    async fn recorded_binary_bodies_replay_unchanged() -> Result<(), failure::Error> {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let body = r#"{"query":{"match_all":{}}}"#;
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(body.as_bytes())?;
+        let compressed = encoder.finish()?;
+        // not valid UTF-8
+        let binary = vec![0x1f, 0x8b, 0xff, 0x00, 0xfe];
+
+        let recorder = RecordingHttpClient::new(
+            MockHttpClient::new().response(
+                MockRequest::new(Method::Post, "/_search").body(&compressed),
+                MockResponse::new(StatusCode::OK)
+                    .header("x-elastic-product", "Elasticsearch")
+                    .body(&binary),
+            ),
+        );
+        assert_eq!(send_compressed(recorder.clone(), body).await?, binary);
+
+        let path = std::env::temp_dir().join("elasticsearch-rs-binary-cassette.json");
+        recorder.save(&path)?;
+        let client = MockHttpClient::load(&path)?;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(send_compressed(client, body).await?, binary);
+        Ok(())
+    }
+}
@@ -18,16 +18,27 @@
  */
 //! HTTP components
 
+pub mod client;
 pub mod headers;
+pub mod hits;
+pub mod metrics;
+pub mod middleware;
+pub mod mock;
+mod product_check;
 pub mod request;
 pub mod response;
+mod runtime;
+mod trace;
 pub mod transport;
 
-pub use reqwest::StatusCode;
+pub use http::StatusCode;
 pub use url::Url;
 
+use serde::{Deserialize, Serialize};
+
 /// Http methods supported by Elasticsearch
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
 pub enum Method {
     /// get
     Get,
@@ -40,3 +51,16 @@ pub enum Method {
     /// head
     Head,
 }
+
+impl Method {
+    /// The name of the method, as sent in a request
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Put => "PUT",
+            Method::Post => "POST",
+            Method::Delete => "DELETE",
+            Method::Head => "HEAD",
+        }
+    }
+}
@@ -0,0 +1,224 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Verification that the server the [Transport](super::transport::Transport) sends
+//! requests to is Elasticsearch
+//!
+//! Elasticsearch 7.14 and later send a `X-Elastic-Product: Elasticsearch` header with each
+//! response. For older versions, the response of the info API is checked instead.
+use crate::{
+    error::{self, Error},
+    http::headers::HeaderMap,
+};
+use serde::Deserialize;
+use std::sync::Mutex;
+
+/// The name of the header that identifies the product of a response
+pub(crate) const X_ELASTIC_PRODUCT: &str = "x-elastic-product";
+
+/// The value of the [X_ELASTIC_PRODUCT] header sent by Elasticsearch
+const ELASTICSEARCH: &str = "Elasticsearch";
+
+/// The tagline of the info API response of Elasticsearch
+const TAGLINE: &str = "You Know, for Search";
+
+/// The result of checking a response
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Verification {
+    /// The server is Elasticsearch
+    Verified,
+    /// The server is not a supported Elasticsearch
+    Unsupported(String),
+}
+
+/// The state of the product check, cached for the lifetime of a
+/// [Transport](super::transport::Transport) and shared between its clones
+#[derive(Debug, Default)]
+pub(crate) struct ProductCheck {
+    state: Mutex<Option<Verification>>,
+    /// Held whilst the server is verified with the info API, so that it is called once
+    /// for concurrent responses
+    verifying: futures_util::lock::Mutex<()>,
+}
+
+impl ProductCheck {
+    /// Whether the server is yet to be verified
+    pub(crate) fn is_pending(&self) -> bool {
+        self.state.lock().unwrap().is_none()
+    }
+
+    /// The error for a server that could not be verified, returned for every API call
+    /// once the check has failed
+    pub(crate) fn error(&self) -> Option<Error> {
+        match &*self.state.lock().unwrap() {
+            Some(Verification::Unsupported(reason)) => {
+                Some(error::unsupported_product(reason.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Waits for any other response to finish being verified with the info API, returning a
+    /// guard to hold whilst verifying a response with it
+    pub(crate) async fn verifying(&self) -> futures_util::lock::MutexGuard<'_, ()> {
+        self.verifying.lock().await
+    }
+
+    /// The result of the check, returning the error when the server is not supported
+    pub(crate) fn result(&self) -> Result<(), Error> {
+        match self.error() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Caches the result of the check, returning the error when the server is not supported
+    pub(crate) fn finish(&self, verification: Verification) -> Result<(), Error> {
+        *self.state.lock().unwrap() = Some(verification);
+        self.result()
+    }
+}
+
+/// Checks the [X_ELASTIC_PRODUCT] header of a successful response. Returns `None` when the
+/// header is missing, in which case the server is checked with [verify_info].
+pub(crate) fn verify_headers(headers: &HeaderMap) -> Option<Verification> {
+    let product = headers.get(X_ELASTIC_PRODUCT)?;
+    if product == ELASTICSEARCH {
+        Some(Verification::Verified)
+    } else {
+        Some(Verification::Unsupported(format!(
+            "the server responded with the unknown product {:?}",
+            product
+        )))
+    }
+}
+
+/// The details of the info API response used to identify Elasticsearch
+#[derive(Debug, Deserialize)]
+pub(crate) struct Info {
+    version: Option<InfoVersion>,
+    tagline: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InfoVersion {
+    number: Option<String>,
+    build_flavor: Option<String>,
+}
+
+/// Checks the info API response of a server that did not send the [X_ELASTIC_PRODUCT]
+/// header, which is only supported for Elasticsearch versions before 7.14
+pub(crate) fn verify_info(info: &Info) -> Verification {
+    let version = info.version.as_ref();
+    let number = version.and_then(|v| v.number.as_deref());
+    let major_minor = number.and_then(|n| {
+        let mut parts = n.split('.');
+        let major = parts.next()?.parse::<u32>().ok()?;
+        let minor = parts.next()?.parse::<u32>().ok()?;
+        Some((major, minor))
+    });
+    let tagline = info.tagline.as_deref() == Some(TAGLINE);
+    let default_flavor = version.and_then(|v| v.build_flavor.as_deref()) == Some("default");
+
+    let supported = match major_minor {
+        Some((6, _)) => tagline,
+        Some((7, minor)) if minor < 14 => tagline && default_flavor,
+        _ => false,
+    };
+
+    if supported {
+        Verification::Verified
+    } else {
+        Verification::Unsupported(format!(
+            "the server did not send the {} header and reported version {}",
+            X_ELASTIC_PRODUCT,
+            number.unwrap_or("unknown")
+        ))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::http::headers::HeaderValue;
+    use serde_json::json;
+
+    fn info(value: serde_json::Value) -> Info {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn verify_product_header() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(verify_headers(&headers), None);
+
+        headers.insert(X_ELASTIC_PRODUCT, HeaderValue::from_static("Elasticsearch"));
+        assert_eq!(verify_headers(&headers), Some(Verification::Verified));
+
+        headers.insert(X_ELASTIC_PRODUCT, HeaderValue::from_static("Other"));
+        assert!(matches!(
+            verify_headers(&headers),
+            Some(Verification::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn verify_info_of_older_versions() {
+        let verified = |value| verify_info(&info(value)) == Verification::Verified;
+
+        assert!(verified(json!({
+            "version": { "number": "6.8.0" },
+            "tagline": "You Know, for Search"
+        })));
+        assert!(verified(json!({
+            "version": { "number": "7.13.4", "build_flavor": "default" },
+            "tagline": "You Know, for Search"
+        })));
+        assert!(!verified(json!({
+            "version": { "number": "7.13.4", "build_flavor": "oss" },
+            "tagline": "You Know, for Search"
+        })));
+        assert!(!verified(json!({
+            "version": { "number": "7.14.0", "build_flavor": "default" },
+            "tagline": "You Know, for Search"
+        })));
+        assert!(!verified(json!({
+            "version": { "number": "5.6.16" },
+            "tagline": "You Know, for Search"
+        })));
+        assert!(!verified(json!({
+            "version": { "number": "7.10.2", "build_flavor": "default" },
+            "tagline": "The OpenSearch Project"
+        })));
+        assert!(!verified(json!({})));
+    }
+
+    #[test]
+    fn failed_check_is_cached() {
+        let check = ProductCheck::default();
+        assert!(check.is_pending());
+        assert!(check.error().is_none());
+
+        let err = check
+            .finish(Verification::Unsupported("reason".into()))
+            .unwrap_err();
+        assert!(err.is_unsupported_product());
+        assert!(!check.is_pending());
+        assert!(check.error().unwrap().is_unsupported_product());
+    }
+}
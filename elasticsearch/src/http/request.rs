@@ -48,6 +48,12 @@ pub trait Body {
 
     /// Write to a buffer that will be written to the request stream
     fn write(&self, bytes: &mut BytesMut) -> Result<(), Error>;
+
+    /// Whether the body is newline-delimited JSON (NDJSON), which is sent with a NDJSON
+    /// content-type in REST API compatibility mode
+    fn is_ndjson(&self) -> bool {
+        false
+    }
 }
 
 impl<'a, B: ?Sized> Body for &'a B
@@ -61,6 +67,10 @@ where
     fn write(&self, bytes: &mut BytesMut) -> Result<(), Error> {
         (**self).write(bytes)
     }
+
+    fn is_ndjson(&self) -> bool {
+        (**self).is_ndjson()
+    }
 }
 
 /// A JSON body of an API call.
@@ -130,6 +140,10 @@ where
         }
         Ok(())
     }
+
+    fn is_ndjson(&self) -> bool {
+        true
+    }
 }
 
 impl Body for Bytes {
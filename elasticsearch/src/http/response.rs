@@ -19,33 +19,55 @@
 //! HTTP response components
 use crate::{
     error::Error as ClientError,
-    http::{headers::HeaderMap, Method, StatusCode, Url},
+    http::{
+        client::HttpResponse,
+        headers::{HeaderMap, CONTENT_LENGTH, CONTENT_TYPE},
+        hits::HitsStream,
+        Method, StatusCode, Url,
+    },
 };
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
+use encoding_rs::{Encoding, UTF_8};
+use futures_util::{
+    io::AsyncRead,
+    stream::{Stream, TryStreamExt},
+};
+use mime::Mime;
 use serde::{
     de,
     de::{DeserializeOwned, MapAccess, Visitor},
     Deserialize, Deserializer, Serialize,
 };
 use serde_json::Value;
-use std::{collections::BTreeMap, fmt, str::FromStr};
+use std::{collections::BTreeMap, fmt, io, str::FromStr};
 use void::Void;
 
 /// A response from Elasticsearch
 pub struct Response {
-    response: reqwest::Response,
+    response: HttpResponse,
     method: Method,
 }
 
 impl Response {
     /// Creates a new instance of an Elasticsearch response
-    pub fn new(response: reqwest::Response, method: Method) -> Self {
+    pub fn new(response: HttpResponse, method: Method) -> Self {
         Self {
             response: response,
             method: method,
         }
     }
 
+    /// Calls the function with each chunk of the response body as it is read
+    pub(crate) fn inspect_body<F>(self, f: F) -> Self
+    where
+        F: FnMut(&Bytes) + Send + Sync + 'static,
+    {
+        Self {
+            response: self.response.map_body(|body| Box::pin(body.inspect_ok(f))),
+            method: self.method,
+        }
+    }
+
     /// Get the response content-length, if known.
     ///
     /// Reasons it may not be known:
@@ -54,31 +76,56 @@ impl Response {
     /// - The response is compressed and automatically decoded (thus changing
     ///   the actual decoded length).
     pub fn content_length(&self) -> Option<u64> {
-        self.response.content_length()
+        self.response
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
     }
 
     /// Gets the response content-type.
     pub fn content_type(&self) -> &str {
         self.response
             .headers()
-            .get(crate::http::headers::CONTENT_TYPE)
+            .get(CONTENT_TYPE)
             .and_then(|value| value.to_str().ok())
             .unwrap()
     }
 
     /// Turn the response into an [Error] if Elasticsearch returned an error.
     pub fn error_for_status_code(self) -> Result<Self, ClientError> {
-        match self.response.error_for_status_ref() {
-            Ok(_) => Ok(self),
-            Err(err) => Err(err.into()),
-        }
+        self.error_for_status_code_ref()?;
+        Ok(self)
     }
 
     /// Turn the response into an [Error] if Elasticsearch returned an error.
     pub fn error_for_status_code_ref(&self) -> Result<&Self, ClientError> {
-        match self.response.error_for_status_ref() {
-            Ok(_) => Ok(self),
-            Err(err) => Err(err.into()),
+        let status_code = self.status_code();
+        if status_code.is_client_error() || status_code.is_server_error() {
+            Err(crate::error::status(status_code, self.url().clone()))
+        } else {
+            Ok(self)
+        }
+    }
+
+    /// Turn the response into an [Error](ClientError) if Elasticsearch returned an error,
+    /// reading the response body into the [Exception] of the error.
+    ///
+    /// Unlike [Response::error_for_status_code], the error holds the [Exception], as well
+    /// as the method and URL of the request, and can be inspected with predicates such as
+    /// [is_version_conflict](ClientError::is_version_conflict).
+    ///
+    /// Reading the response body of an error consumes `self`
+    pub async fn error_for_status_with_body(self) -> Result<Self, ClientError> {
+        let status_code = self.status_code();
+        if status_code.is_client_error() || status_code.is_server_error() {
+            let method = self.method();
+            let url = self.url().clone();
+            let bytes = self.bytes().await?;
+            let exception = serde_json::from_slice(&bytes).ok();
+            Err(crate::error::server(status_code, method, url, exception))
+        } else {
+            Ok(self)
         }
     }
 
@@ -102,7 +149,8 @@ impl Response {
     where
         B: DeserializeOwned,
     {
-        let body = self.response.json::<B>().await?;
+        let bytes = self.bytes().await?;
+        let body = serde_json::from_slice(&bytes)?;
         Ok(body)
     }
 
@@ -123,18 +171,143 @@ impl Response {
 
     /// Asynchronously reads the response body as plain text
     ///
+    /// The body is decoded with the charset of the `Content-Type` header, or with UTF-8 when
+    /// the header has no charset. Invalid byte sequences are replaced with
+    /// [U+FFFD REPLACEMENT CHARACTER](std::char::REPLACEMENT_CHARACTER).
+    ///
     /// Reading the response body consumes `self`
     pub async fn text(self) -> Result<String, ClientError> {
-        let body = self.response.text().await?;
-        Ok(body)
+        let encoding = self
+            .response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<Mime>().ok())
+            .and_then(|mime| {
+                mime.get_param(mime::CHARSET)
+                    .and_then(|charset| Encoding::for_label(charset.as_str().as_bytes()))
+            })
+            .unwrap_or(UTF_8);
+
+        let bytes = self.bytes().await?;
+        let (body, _, _) = encoding.decode(&bytes);
+        Ok(body.into_owned())
     }
 
     /// Asynchronously reads the response body as bytes
     ///
     /// Reading the response body consumes `self`
     pub async fn bytes(self) -> Result<Bytes, ClientError> {
-        let bytes: Bytes = self.response.bytes().await?;
-        Ok(bytes)
+        let bytes = self
+            .response
+            .into_body()
+            .try_fold(BytesMut::new(), |mut bytes, chunk| async move {
+                bytes.extend_from_slice(&chunk);
+                Ok(bytes)
+            })
+            .await?;
+        Ok(bytes.freeze())
+    }
+
+    /// Gets a stream of the response body, which yields chunks of the body as they are
+    /// received, without buffering the whole body in memory.
+    ///
+    /// Reading the response body consumes `self`
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use elasticsearch::{Elasticsearch, cat::CatIndicesParts};
+    /// # use futures::stream::StreamExt;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Elasticsearch::default();
+    /// let response = client
+    ///     .cat()
+    ///     .indices(CatIndicesParts::None)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// let mut stream = response.bytes_stream();
+    /// while let Some(chunk) = stream.next().await {
+    ///     let chunk = chunk?;
+    ///     // write the chunk to a file, or parse it incrementally
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bytes_stream(
+        self,
+    ) -> impl Stream<Item = Result<Bytes, ClientError>> + Send + Sync + Unpin {
+        self.response.into_body()
+    }
+
+    /// Gets an [AsyncRead] that reads the response body as it is received, without
+    /// buffering the whole body in memory.
+    ///
+    /// The reader implements the `AsyncRead` trait of the `futures` crate, which can be
+    /// adapted to the `AsyncRead` trait of `tokio` with the `compat` module of `tokio-util`.
+    /// An error reading the body is returned as an [io::Error] that wraps the
+    /// [Error](ClientError).
+    ///
+    /// Reading the response body consumes `self`
+    pub fn into_async_read(self) -> impl AsyncRead + Send + Sync + Unpin {
+        self.bytes_stream()
+            .map_err(|err| {
+                let kind = if err.is_timeout() {
+                    io::ErrorKind::TimedOut
+                } else {
+                    io::ErrorKind::Other
+                };
+                io::Error::new(kind, err)
+            })
+            .into_async_read()
+    }
+
+    /// Gets a [HitsStream] over the hits of a search or scroll response, which deserializes
+    /// each of `hits.hits` into a [Hit](crate::http::hits::Hit) as the response body is
+    /// received, without buffering the whole body in memory.
+    ///
+    /// The other fields of the response, such as `hits.total` and `aggregations`, are
+    /// available from [HitsStream::metadata] once the hits have been read.
+    ///
+    /// Returns an error, as [Response::error_for_status_with_body] does, if Elasticsearch
+    /// returned an error.
+    ///
+    /// Reading the response body consumes `self`
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use elasticsearch::{Elasticsearch, SearchParts};
+    /// # use futures::stream::StreamExt;
+    /// # use serde_json::{json, Value};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Elasticsearch::default();
+    /// let response = client
+    ///     .search(SearchParts::Index(&["posts"]))
+    ///     .size(10000)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// let mut hits = response.hits_stream::<Value>().await?;
+    /// while let Some(hit) = hits.next().await {
+    ///     let hit = hit?;
+    ///     println!("{:?}", hit.source());
+    /// }
+    ///
+    /// let metadata = hits.metadata().await?;
+    /// println!("{:?}", metadata.total());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn hits_stream<T>(self) -> Result<HitsStream<T>, ClientError>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self.error_for_status_with_body().await?;
+        Ok(HitsStream::new(response.response.into_body()))
     }
 
     /// Gets the request URL
@@ -0,0 +1,51 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! The timer of the async runtime that the [Transport](super::transport::Transport) waits on,
+//! between retries and for the nodes of the cluster to be discovered
+//!
+//! The timer of `async-std` is used when the `async-std` feature is enabled, and the timer
+//! of `tokio` otherwise. The runtime does not change how requests are sent: the default
+//! [HttpClient](super::client::HttpClient), `reqwest`, is built on `tokio`, and with
+//! `async-std` runs on the `tokio` compatibility runtime of `async-std`.
+use futures_util::future::{self, Either};
+use std::{future::Future, time::Duration};
+
+/// Waits until the duration has elapsed
+#[cfg(feature = "async-std")]
+pub(crate) async fn sleep(duration: Duration) {
+    async_std::task::sleep(duration).await
+}
+
+/// Waits until the duration has elapsed
+#[cfg(not(feature = "async-std"))]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await
+}
+
+/// Waits for the future to complete, returning `None` if it does not complete within
+/// the duration
+pub(crate) async fn timeout<F: Future>(duration: Duration, future: F) -> Option<F::Output> {
+    futures_util::pin_mut!(future);
+    let sleep = sleep(duration);
+    futures_util::pin_mut!(sleep);
+    match future::select(future, sleep).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
+}
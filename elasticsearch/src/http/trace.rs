@@ -0,0 +1,242 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Tracing of API calls sent by the [Transport](super::transport::Transport)
+//!
+//! With the `tracing` feature enabled, each API call is wrapped in an `elasticsearch.request`
+//! span whose fields follow the OpenTelemetry semantic conventions for Elasticsearch:
+//!
+//! - `db.system`: always `elasticsearch`
+//! - `db.operation`: the endpoint id of the API, such as `search` or `indices.create`
+//! - `http.request.method` and `url.path`: the method and path of the API call
+//! - `url.full` and `server.address`: the url sent to, and the node that handled, the last attempt
+//! - `http.response.status_code` and `http.response.body.size`: the status code of the last
+//!   response, and the number of its body bytes read. The span is open until the body has been
+//!   read or dropped
+//! - `http.request.resend_count`: the number of times the request was retried
+//! - `duration_ms`: the time taken by the API call, including retries
+//!
+//! Without the feature, [RequestSpan] does nothing.
+use crate::{
+    error::Error,
+    http::{client::HttpRequest, response::Response, Method},
+};
+use std::future::Future;
+
+/// A span for an API call, over all of its attempts
+#[cfg(feature = "tracing")]
+pub(crate) struct RequestSpan {
+    span: tracing::Span,
+    start: std::time::Instant,
+}
+
+#[cfg(feature = "tracing")]
+impl RequestSpan {
+    /// Creates a new span for an API call to the given endpoint
+    pub fn new(method: Method, path: &str, endpoint: Option<&'static str>) -> Self {
+        let method = method.as_str();
+        let span = tracing::info_span!(
+            "elasticsearch.request",
+            otel.name = endpoint.unwrap_or(method),
+            otel.kind = "client",
+            db.system = "elasticsearch",
+            db.operation = endpoint,
+            http.request.method = method,
+            url.path = path,
+            url.full = tracing::field::Empty,
+            server.address = tracing::field::Empty,
+            http.response.status_code = tracing::field::Empty,
+            http.response.body.size = tracing::field::Empty,
+            http.request.resend_count = tracing::field::Empty,
+            duration_ms = tracing::field::Empty,
+            error = tracing::field::Empty,
+        );
+        Self {
+            span,
+            start: std::time::Instant::now(),
+        }
+    }
+
+    /// Records the url and node of an attempt
+    pub fn request(&self, request: &HttpRequest) {
+        let url = request.url();
+        self.span
+            .record("url.full", tracing::field::display(url))
+            .record(
+                "server.address",
+                tracing::field::display(url.host_str().unwrap_or_default()),
+            );
+    }
+
+    /// Runs the future of an attempt inside the span
+    pub fn instrument<F: Future>(&self, future: F) -> tracing::instrument::Instrumented<F> {
+        tracing::Instrument::instrument(future, self.span.clone())
+    }
+
+    /// Records the outcome of an attempt, after the given number of retries
+    pub fn response(&self, response: &Result<Response, Error>, retries: usize) {
+        match response {
+            Ok(r) => {
+                self.span
+                    .record("http.response.status_code", r.status_code().as_u16());
+            }
+            Err(e) => {
+                self.span.record("error", tracing::field::display(e));
+            }
+        }
+        self.span
+            .record("http.request.resend_count", retries as u64)
+            .record("duration_ms", self.start.elapsed().as_millis() as u64);
+    }
+
+    /// Returns a function that records the number of response body bytes read so far
+    pub fn body_read(&self) -> impl Fn(u64) + Send + Sync + 'static {
+        let span = self.span.clone();
+        move |size| {
+            span.record("http.response.body.size", size);
+        }
+    }
+}
+
+/// A span for an API call, which does nothing without the `tracing` feature
+#[cfg(not(feature = "tracing"))]
+pub(crate) struct RequestSpan;
+
+#[cfg(not(feature = "tracing"))]
+impl RequestSpan {
+    pub fn new(_method: Method, _path: &str, _endpoint: Option<&'static str>) -> Self {
+        Self
+    }
+
+    pub fn request(&self, _request: &HttpRequest) {}
+
+    pub fn instrument<F: Future>(&self, future: F) -> F {
+        future
+    }
+
+    pub fn response(&self, _response: &Result<Response, Error>, _retries: usize) {}
+
+    pub fn body_read(&self) -> impl Fn(u64) + Send + Sync + 'static {
+        |_| {}
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+pub mod tests {
+    use crate::http::{
+        headers::HeaderMap,
+        mock::{MockHttpClient, MockRequest, MockResponse},
+        transport::{RequestOptions, SingleNodeConnectionPool, TransportBuilder},
+        Method, StatusCode,
+    };
+    use std::{
+        collections::BTreeMap,
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    };
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        Event, Metadata, Subscriber,
+    };
+
+    /// Collects the fields recorded on spans
+    #[derive(Clone, Default)]
+    struct Fields(Arc<Mutex<BTreeMap<String, String>>>);
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0
+                .lock()
+                .unwrap()
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0
+                .lock()
+                .unwrap()
+                .insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    impl Subscriber for Fields {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            span.record(&mut self.clone());
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, values: &Record<'_>) {
+            values.record(&mut self.clone());
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event<'_>) {}
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[tokio::test]
+    async fn records_request_span() -> Result<(), failure::Error> {
+        let client = MockHttpClient::new().response(
+            MockRequest::new(Method::Get, "/_search"),
+            MockResponse::new(StatusCode::OK)
+                .header("x-elastic-product", "Elasticsearch")
+                .body("{}"),
+        );
+        let transport = TransportBuilder::new(SingleNodeConnectionPool::default())
+            .http_client(client)
+            .build()?;
+
+        let fields = Fields::default();
+        let _guard = tracing::subscriber::set_default(fields.clone());
+        let response = transport
+            .send_with_options(
+                Method::Get,
+                "/_search",
+                HeaderMap::new(),
+                Option::<&()>::None,
+                Option::<()>::None,
+                None,
+                RequestOptions::new().endpoint("search"),
+            )
+            .await?;
+        response.bytes().await?;
+
+        let fields = fields.0.lock().unwrap();
+        assert_eq!(fields["otel.name"], "search");
+        assert_eq!(fields["db.system"], "elasticsearch");
+        assert_eq!(fields["db.operation"], "search");
+        assert_eq!(fields["http.request.method"], "GET");
+        assert_eq!(fields["url.path"], "/_search");
+        assert_eq!(fields["url.full"], "http://localhost:9200/_search");
+        assert_eq!(fields["server.address"], "localhost");
+        assert_eq!(fields["http.response.status_code"], "200");
+        assert_eq!(fields["http.response.body.size"], "2");
+        assert_eq!(fields["http.request.resend_count"], "0");
+        assert!(fields.contains_key("duration_ms"));
+        Ok(())
+    }
+}
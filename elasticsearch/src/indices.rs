@@ -37,7 +37,7 @@ use crate::{
         headers::{HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE},
         request::{Body, JsonBody, NdBody, PARTS_ENCODED},
         response::Response,
-        transport::Transport,
+        transport::{NodeSelector, RequestOptions, Transport},
         Method,
     },
     params::*,
@@ -86,6 +86,7 @@ pub struct IndicesAddBlock<'a, 'b, B> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -110,6 +111,7 @@ where
             human: None,
             ignore_unavailable: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -138,6 +140,7 @@ where
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -179,6 +182,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -237,9 +245,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.add_block")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -280,6 +299,7 @@ pub struct IndicesAnalyze<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     index: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -300,6 +320,7 @@ where
             filter_path: None,
             human: None,
             index: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -319,6 +340,7 @@ where
             headers: self.headers,
             human: self.human,
             index: self.index,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -349,6 +371,11 @@ where
         self.index = Some(index);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -396,9 +423,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.analyze")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -445,6 +483,7 @@ pub struct IndicesClearCache<'a, 'b, B> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     index: Option<&'b [&'b str]>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     query: Option<bool>,
     request: Option<bool>,
@@ -472,6 +511,7 @@ where
             human: None,
             ignore_unavailable: None,
             index: None,
+            node_selector: None,
             pretty: None,
             query: None,
             request: None,
@@ -503,6 +543,7 @@ where
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
             index: self.index,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             query: self.query,
             request: self.request,
@@ -555,6 +596,11 @@ where
         self.index = Some(index);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -626,9 +672,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.clear_cache")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -670,6 +727,7 @@ pub struct IndicesClone<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -692,6 +750,7 @@ where
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -713,6 +772,7 @@ where
             headers: self.headers,
             human: self.human,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -745,6 +805,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -803,9 +868,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.clone")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -847,6 +923,7 @@ pub struct IndicesClose<'a, 'b, B> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -872,6 +949,7 @@ where
             human: None,
             ignore_unavailable: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -901,6 +979,7 @@ where
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -943,6 +1022,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1008,9 +1092,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.close")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1047,6 +1142,7 @@ pub struct IndicesCreate<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1069,6 +1165,7 @@ where
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1090,6 +1187,7 @@ where
             headers: self.headers,
             human: self.human,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -1122,6 +1220,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1180,9 +1283,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.create")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1217,6 +1331,7 @@ pub struct IndicesCreateDataStream<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1236,6 +1351,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1254,6 +1370,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -1279,6 +1396,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1321,9 +1443,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.create_data_stream")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1363,6 +1496,7 @@ pub struct IndicesDataStreamsStats<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1378,6 +1512,7 @@ impl<'a, 'b> IndicesDataStreamsStats<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1403,6 +1538,11 @@ impl<'a, 'b> IndicesDataStreamsStats<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1445,9 +1585,20 @@ impl<'a, 'b> IndicesDataStreamsStats<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.data_streams_stats")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1487,6 +1638,7 @@ pub struct IndicesDelete<'a, 'b> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1507,6 +1659,7 @@ impl<'a, 'b> IndicesDelete<'a, 'b> {
             human: None,
             ignore_unavailable: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1553,6 +1706,11 @@ impl<'a, 'b> IndicesDelete<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1611,9 +1769,20 @@ impl<'a, 'b> IndicesDelete<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.delete")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1656,6 +1825,7 @@ pub struct IndicesDeleteAlias<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1673,6 +1843,7 @@ impl<'a, 'b> IndicesDeleteAlias<'a, 'b> {
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1704,6 +1875,11 @@ impl<'a, 'b> IndicesDeleteAlias<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1755,9 +1931,20 @@ impl<'a, 'b> IndicesDeleteAlias<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.delete_alias")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1794,6 +1981,7 @@ pub struct IndicesDeleteDataStream<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1810,6 +1998,7 @@ impl<'a, 'b> IndicesDeleteDataStream<'a, 'b> {
             expand_wildcards: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1840,6 +2029,11 @@ impl<'a, 'b> IndicesDeleteDataStream<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1885,9 +2079,20 @@ impl<'a, 'b> IndicesDeleteDataStream<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.delete_data_stream")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1922,6 +2127,7 @@ pub struct IndicesDeleteIndexTemplate<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1939,6 +2145,7 @@ impl<'a, 'b> IndicesDeleteIndexTemplate<'a, 'b> {
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -1970,6 +2177,11 @@ impl<'a, 'b> IndicesDeleteIndexTemplate<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2021,9 +2233,20 @@ impl<'a, 'b> IndicesDeleteIndexTemplate<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.delete_index_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2058,6 +2281,7 @@ pub struct IndicesDeleteTemplate<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -2075,6 +2299,7 @@ impl<'a, 'b> IndicesDeleteTemplate<'a, 'b> {
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -2106,6 +2331,11 @@ impl<'a, 'b> IndicesDeleteTemplate<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2157,9 +2387,20 @@ impl<'a, 'b> IndicesDeleteTemplate<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.delete_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2204,6 +2445,7 @@ pub struct IndicesDiskUsage<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     run_expensive_tasks: Option<bool>,
@@ -2229,6 +2471,7 @@ where
             flush: None,
             human: None,
             ignore_unavailable: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             run_expensive_tasks: None,
@@ -2257,6 +2500,7 @@ where
             headers: self.headers,
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             run_expensive_tasks: self.run_expensive_tasks,
@@ -2298,6 +2542,11 @@ where
         self.ignore_unavailable = Some(ignore_unavailable);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2356,9 +2605,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.disk_usage")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2400,6 +2660,7 @@ pub struct IndicesExists<'a, 'b> {
     ignore_unavailable: Option<bool>,
     include_defaults: Option<bool>,
     local: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -2421,6 +2682,7 @@ impl<'a, 'b> IndicesExists<'a, 'b> {
             ignore_unavailable: None,
             include_defaults: None,
             local: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -2476,6 +2738,11 @@ impl<'a, 'b> IndicesExists<'a, 'b> {
         self.local = Some(local);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2531,9 +2798,20 @@ impl<'a, 'b> IndicesExists<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.exists")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2590,6 +2868,7 @@ pub struct IndicesExistsAlias<'a, 'b> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     local: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -2609,6 +2888,7 @@ impl<'a, 'b> IndicesExistsAlias<'a, 'b> {
             human: None,
             ignore_unavailable: None,
             local: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -2654,6 +2934,11 @@ impl<'a, 'b> IndicesExistsAlias<'a, 'b> {
         self.local = Some(local);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2705,9 +2990,20 @@ impl<'a, 'b> IndicesExistsAlias<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.exists_alias")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2744,6 +3040,7 @@ pub struct IndicesExistsIndexTemplate<'a, 'b> {
     human: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -2762,6 +3059,7 @@ impl<'a, 'b> IndicesExistsIndexTemplate<'a, 'b> {
             human: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -2802,6 +3100,11 @@ impl<'a, 'b> IndicesExistsIndexTemplate<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2850,9 +3153,20 @@ impl<'a, 'b> IndicesExistsIndexTemplate<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.exists_index_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2891,6 +3205,7 @@ pub struct IndicesExistsTemplate<'a, 'b> {
     human: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -2909,6 +3224,7 @@ impl<'a, 'b> IndicesExistsTemplate<'a, 'b> {
             human: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -2949,6 +3265,11 @@ impl<'a, 'b> IndicesExistsTemplate<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2997,9 +3318,20 @@ impl<'a, 'b> IndicesExistsTemplate<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.exists_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3043,6 +3375,7 @@ pub struct IndicesExistsType<'a, 'b> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     local: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -3062,6 +3395,7 @@ impl<'a, 'b> IndicesExistsType<'a, 'b> {
             human: None,
             ignore_unavailable: None,
             local: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -3107,6 +3441,11 @@ impl<'a, 'b> IndicesExistsType<'a, 'b> {
         self.local = Some(local);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -3158,9 +3497,20 @@ impl<'a, 'b> IndicesExistsType<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.exists_type")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3204,6 +3554,7 @@ pub struct IndicesFieldUsageStats<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -3224,6 +3575,7 @@ impl<'a, 'b> IndicesFieldUsageStats<'a, 'b> {
             filter_path: None,
             human: None,
             ignore_unavailable: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -3269,6 +3621,11 @@ impl<'a, 'b> IndicesFieldUsageStats<'a, 'b> {
         self.ignore_unavailable = Some(ignore_unavailable);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -3321,9 +3678,20 @@ impl<'a, 'b> IndicesFieldUsageStats<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.field_usage_stats")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3368,6 +3736,7 @@ pub struct IndicesFlush<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -3392,6 +3761,7 @@ where
             force: None,
             human: None,
             ignore_unavailable: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -3420,6 +3790,7 @@ where
             headers: self.headers,
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -3461,6 +3832,11 @@ where
         self.ignore_unavailable = Some(ignore_unavailable);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -3522,9 +3898,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.flush")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3570,6 +3957,7 @@ pub struct IndicesForcemerge<'a, 'b, B> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     max_num_segments: Option<i64>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     only_expunge_deletes: Option<bool>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
@@ -3595,6 +3983,7 @@ where
             human: None,
             ignore_unavailable: None,
             max_num_segments: None,
+            node_selector: None,
             only_expunge_deletes: None,
             pretty: None,
             request_timeout: None,
@@ -3624,6 +4013,7 @@ where
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
             max_num_segments: self.max_num_segments,
+            node_selector: self.node_selector,
             only_expunge_deletes: self.only_expunge_deletes,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
@@ -3670,6 +4060,11 @@ where
         self.max_num_segments = Some(max_num_segments);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Specify whether the operation should only expunge deleted documents"]
     pub fn only_expunge_deletes(mut self, only_expunge_deletes: bool) -> Self {
         self.only_expunge_deletes = Some(only_expunge_deletes);
@@ -3730,9 +4125,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.forcemerge")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3773,6 +4179,7 @@ pub struct IndicesFreeze<'a, 'b, B> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -3798,6 +4205,7 @@ where
             human: None,
             ignore_unavailable: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -3827,6 +4235,7 @@ where
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -3869,6 +4278,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -3934,9 +4348,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.freeze")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3979,6 +4404,7 @@ pub struct IndicesGet<'a, 'b> {
     include_defaults: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -4001,6 +4427,7 @@ impl<'a, 'b> IndicesGet<'a, 'b> {
             include_defaults: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -4061,6 +4488,11 @@ impl<'a, 'b> IndicesGet<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -4118,9 +4550,20 @@ impl<'a, 'b> IndicesGet<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.get")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -4192,6 +4635,7 @@ pub struct IndicesGetAlias<'a, 'b> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     local: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -4211,6 +4655,7 @@ impl<'a, 'b> IndicesGetAlias<'a, 'b> {
             human: None,
             ignore_unavailable: None,
             local: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -4256,6 +4701,11 @@ impl<'a, 'b> IndicesGetAlias<'a, 'b> {
         self.local = Some(local);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -4307,9 +4757,20 @@ impl<'a, 'b> IndicesGetAlias<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.get_alias")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -4349,6 +4810,7 @@ pub struct IndicesGetDataStream<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -4365,6 +4827,7 @@ impl<'a, 'b> IndicesGetDataStream<'a, 'b> {
             expand_wildcards: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -4395,6 +4858,11 @@ impl<'a, 'b> IndicesGetDataStream<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -4440,9 +4908,20 @@ impl<'a, 'b> IndicesGetDataStream<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.get_data_stream")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -4500,6 +4979,7 @@ pub struct IndicesGetFieldMapping<'a, 'b> {
     ignore_unavailable: Option<bool>,
     include_defaults: Option<bool>,
     local: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -4520,6 +5000,7 @@ impl<'a, 'b> IndicesGetFieldMapping<'a, 'b> {
             ignore_unavailable: None,
             include_defaults: None,
             local: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -4570,6 +5051,11 @@ impl<'a, 'b> IndicesGetFieldMapping<'a, 'b> {
         self.local = Some(local);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -4623,9 +5109,20 @@ impl<'a, 'b> IndicesGetFieldMapping<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.get_field_mapping")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -4667,6 +5164,7 @@ pub struct IndicesGetIndexTemplate<'a, 'b> {
     human: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -4685,6 +5183,7 @@ impl<'a, 'b> IndicesGetIndexTemplate<'a, 'b> {
             human: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -4725,6 +5224,11 @@ impl<'a, 'b> IndicesGetIndexTemplate<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -4773,9 +5277,20 @@ impl<'a, 'b> IndicesGetIndexTemplate<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.get_index_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -4820,6 +5335,7 @@ pub struct IndicesGetMapping<'a, 'b> {
     ignore_unavailable: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -4840,6 +5356,7 @@ impl<'a, 'b> IndicesGetMapping<'a, 'b> {
             ignore_unavailable: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -4890,6 +5407,11 @@ impl<'a, 'b> IndicesGetMapping<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -4943,9 +5465,20 @@ impl<'a, 'b> IndicesGetMapping<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.get_mapping")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -5020,6 +5553,7 @@ pub struct IndicesGetSettings<'a, 'b> {
     include_defaults: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -5042,6 +5576,7 @@ impl<'a, 'b> IndicesGetSettings<'a, 'b> {
             include_defaults: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -5102,6 +5637,11 @@ impl<'a, 'b> IndicesGetSettings<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -5159,9 +5699,20 @@ impl<'a, 'b> IndicesGetSettings<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.get_settings")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -5203,6 +5754,7 @@ pub struct IndicesGetTemplate<'a, 'b> {
     human: Option<bool>,
     local: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -5221,6 +5773,7 @@ impl<'a, 'b> IndicesGetTemplate<'a, 'b> {
             human: None,
             local: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -5261,6 +5814,11 @@ impl<'a, 'b> IndicesGetTemplate<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -5309,9 +5867,20 @@ impl<'a, 'b> IndicesGetTemplate<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.get_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -5346,6 +5915,7 @@ pub struct IndicesMigrateToDataStream<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -5365,6 +5935,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -5383,6 +5954,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -5408,6 +5980,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -5450,9 +6027,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.migrate_to_data_stream")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -5494,6 +6082,7 @@ pub struct IndicesOpen<'a, 'b, B> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -5519,6 +6108,7 @@ where
             human: None,
             ignore_unavailable: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -5548,6 +6138,7 @@ where
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -5590,6 +6181,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -5655,9 +6251,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.open")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -5692,6 +6299,7 @@ pub struct IndicesPromoteDataStream<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -5711,6 +6319,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -5729,6 +6338,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -5754,6 +6364,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -5796,9 +6411,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.promote_data_stream")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -5840,6 +6466,7 @@ pub struct IndicesPutAlias<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -5861,6 +6488,7 @@ where
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -5881,6 +6509,7 @@ where
             headers: self.headers,
             human: self.human,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -5912,6 +6541,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -5963,9 +6597,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.put_alias")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -6003,6 +6648,7 @@ pub struct IndicesPutIndexTemplate<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -6025,6 +6671,7 @@ where
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -6046,6 +6693,7 @@ where
             headers: self.headers,
             human: self.human,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -6086,6 +6734,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -6134,9 +6787,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.put_index_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -6178,6 +6842,7 @@ pub struct IndicesPutMapping<'a, 'b, B> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -6203,6 +6868,7 @@ where
             human: None,
             ignore_unavailable: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -6232,6 +6898,7 @@ where
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -6274,6 +6941,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -6339,9 +7011,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.put_mapping")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -6387,6 +7070,7 @@ pub struct IndicesPutSettings<'a, 'b, B> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     preserve_existing: Option<bool>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
@@ -6413,6 +7097,7 @@ where
             human: None,
             ignore_unavailable: None,
             master_timeout: None,
+            node_selector: None,
             preserve_existing: None,
             pretty: None,
             request_timeout: None,
@@ -6443,6 +7128,7 @@ where
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             preserve_existing: self.preserve_existing,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
@@ -6490,6 +7176,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Whether to update existing settings. If set to `true` existing settings on an index remain unchanged, the default is `false`"]
     pub fn preserve_existing(mut self, preserve_existing: bool) -> Self {
         self.preserve_existing = Some(preserve_existing);
@@ -6557,9 +7248,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.put_settings")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -6596,6 +7298,7 @@ pub struct IndicesPutTemplate<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     order: Option<i64>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
@@ -6618,6 +7321,7 @@ where
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             order: None,
             pretty: None,
             request_timeout: None,
@@ -6639,6 +7343,7 @@ where
             headers: self.headers,
             human: self.human,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             order: self.order,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
@@ -6675,6 +7380,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "The order for this template when merging multiple matching ones (higher numbers are merged later, overriding the lower numbers)"]
     pub fn order(mut self, order: i64) -> Self {
         self.order = Some(order);
@@ -6728,9 +7438,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.put_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -6772,6 +7493,7 @@ pub struct IndicesRecovery<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -6789,6 +7511,7 @@ impl<'a, 'b> IndicesRecovery<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -6824,6 +7547,11 @@ impl<'a, 'b> IndicesRecovery<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -6870,9 +7598,20 @@ impl<'a, 'b> IndicesRecovery<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.recovery")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -6916,6 +7655,7 @@ pub struct IndicesRefresh<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -6938,6 +7678,7 @@ where
             filter_path: None,
             human: None,
             ignore_unavailable: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -6964,6 +7705,7 @@ where
             headers: self.headers,
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -6999,6 +7741,11 @@ where
         self.ignore_unavailable = Some(ignore_unavailable);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -7051,9 +7798,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.refresh")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -7094,6 +7852,7 @@ pub struct IndicesReloadSearchAnalyzers<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -7116,6 +7875,7 @@ where
             filter_path: None,
             human: None,
             ignore_unavailable: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -7142,6 +7902,7 @@ where
             headers: self.headers,
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -7177,6 +7938,11 @@ where
         self.ignore_unavailable = Some(ignore_unavailable);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -7229,9 +7995,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.reload_search_analyzers")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -7272,6 +8049,7 @@ pub struct IndicesResolveIndex<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -7289,6 +8067,7 @@ impl<'a, 'b> IndicesResolveIndex<'a, 'b> {
             expand_wildcards: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -7319,6 +8098,11 @@ impl<'a, 'b> IndicesResolveIndex<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -7364,9 +8148,20 @@ impl<'a, 'b> IndicesResolveIndex<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.resolve_index")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -7420,6 +8215,7 @@ pub struct IndicesRollover<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -7443,6 +8239,7 @@ where
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -7465,6 +8262,7 @@ where
             headers: self.headers,
             human: self.human,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -7502,6 +8300,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -7562,9 +8365,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.rollover")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -7607,6 +8421,7 @@ pub struct IndicesSegments<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -7626,6 +8441,7 @@ impl<'a, 'b> IndicesSegments<'a, 'b> {
             filter_path: None,
             human: None,
             ignore_unavailable: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -7667,6 +8483,11 @@ impl<'a, 'b> IndicesSegments<'a, 'b> {
         self.ignore_unavailable = Some(ignore_unavailable);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -7723,9 +8544,20 @@ impl<'a, 'b> IndicesSegments<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.segments")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -7768,6 +8600,7 @@ pub struct IndicesShardStores<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -7787,6 +8620,7 @@ impl<'a, 'b> IndicesShardStores<'a, 'b> {
             filter_path: None,
             human: None,
             ignore_unavailable: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -7828,6 +8662,11 @@ impl<'a, 'b> IndicesShardStores<'a, 'b> {
         self.ignore_unavailable = Some(ignore_unavailable);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -7885,9 +8724,20 @@ impl<'a, 'b> IndicesShardStores<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.shard_stores")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -7929,6 +8779,7 @@ pub struct IndicesShrink<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -7951,6 +8802,7 @@ where
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -7972,6 +8824,7 @@ where
             headers: self.headers,
             human: self.human,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -8004,6 +8857,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -8062,9 +8920,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.shrink")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -8102,6 +8971,7 @@ pub struct IndicesSimulateIndexTemplate<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -8124,6 +8994,7 @@ where
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -8145,6 +9016,7 @@ where
             headers: self.headers,
             human: self.human,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -8185,6 +9057,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -8233,9 +9110,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.simulate_index_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -8276,6 +9164,7 @@ pub struct IndicesSimulateTemplate<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -8298,6 +9187,7 @@ where
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -8319,6 +9209,7 @@ where
             headers: self.headers,
             human: self.human,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -8359,6 +9250,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -8407,9 +9303,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.simulate_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -8451,6 +9358,7 @@ pub struct IndicesSplit<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -8473,6 +9381,7 @@ where
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -8494,6 +9403,7 @@ where
             headers: self.headers,
             human: self.human,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -8526,6 +9436,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -8584,9 +9499,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.split")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -8663,6 +9589,7 @@ pub struct IndicesStats<'a, 'b> {
     include_segment_file_sizes: Option<bool>,
     include_unloaded_segments: Option<bool>,
     level: Option<Level>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -8688,6 +9615,7 @@ impl<'a, 'b> IndicesStats<'a, 'b> {
             include_segment_file_sizes: None,
             include_unloaded_segments: None,
             level: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -8759,6 +9687,11 @@ impl<'a, 'b> IndicesStats<'a, 'b> {
         self.level = Some(level);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -8832,9 +9765,20 @@ impl<'a, 'b> IndicesStats<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("indices.stats")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -8875,6 +9819,7 @@ pub struct IndicesUnfreeze<'a, 'b, B> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -8900,6 +9845,7 @@ where
             human: None,
             ignore_unavailable: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -8929,6 +9875,7 @@ where
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -8971,6 +9918,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -9036,9 +9988,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.unfreeze")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -9068,6 +10031,7 @@ pub struct IndicesUpdateAliases<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -9089,6 +10053,7 @@ where
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -9109,6 +10074,7 @@ where
             headers: self.headers,
             human: self.human,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -9140,6 +10106,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -9191,9 +10162,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.update_aliases")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -9260,6 +10242,7 @@ pub struct IndicesValidateQuery<'a, 'b, B> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     lenient: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     q: Option<&'b str>,
     request_timeout: Option<Duration>,
@@ -9291,6 +10274,7 @@ where
             human: None,
             ignore_unavailable: None,
             lenient: None,
+            node_selector: None,
             pretty: None,
             q: None,
             request_timeout: None,
@@ -9341,6 +10325,7 @@ where
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
             lenient: self.lenient,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             q: self.q,
             request_timeout: self.request_timeout,
@@ -9398,6 +10383,11 @@ where
         self.lenient = Some(lenient);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -9478,9 +10468,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("indices.validate_query")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
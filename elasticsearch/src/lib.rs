@@ -51,8 +51,25 @@
 //!
 //! The following are a list of Cargo features that can be enabled or disabled:
 //!
-//! - **native-tls** *(enabled by default)*: Enables TLS functionality provided by `native-tls`.
-//! - **rustls-tls**: Enables TLS functionality provided by `rustls`.
+//! - **reqwest** *(enabled by default)*: Sends requests with [`reqwest`](https://crates.io/crates/reqwest),
+//!   the default [HttpClient](http::client::HttpClient). When disabled, an `HttpClient` implementation
+//!   must be configured with [TransportBuilder::http_client](http::transport::TransportBuilder::http_client).
+//! - **native-tls** *(enabled by default)*: Enables TLS functionality provided by `native-tls`. Also enables `reqwest`.
+//!   Certificate fingerprint validation is not supported with `native-tls`.
+//! - **rustls-tls**: Enables TLS functionality provided by `rustls`. Also enables `reqwest`. Required for
+//!   [CertificateValidation::Fingerprint](cert::CertificateValidation::Fingerprint), including when pinning
+//!   the CA certificate of an enrollment token or the `ES_CA_FINGERPRINT` environment variable.
+//! - **tokio** *(enabled by default)*: Waits between retries with the timer of the
+//!   [`tokio`](https://crates.io/crates/tokio) runtime. This is used whenever `async-std` is not
+//!   enabled, so disabling this feature alone has no effect.
+//! - **async-std**: Waits between retries with the timer of the [`async-std`](https://crates.io/crates/async-std)
+//!   runtime instead, and sends requests with `reqwest` on the `tokio` compatibility runtime of `async-std`.
+//!   `reqwest` still performs I/O with `tokio`.
+//! - **aws-auth**: Enables signing requests with AWS Signature Version 4, for Amazon OpenSearch Service.
+//! - **blocking**: Enables the [blocking](blocking) client, whose API calls block the current thread
+//!   until the response is returned.
+//! - **tracing**: Emits a [`tracing`](https://crates.io/crates/tracing) span for each API call sent
+//!   by the [Transport](http::transport::Transport), following the OpenTelemetry conventions for Elasticsearch.
 //! - **beta-apis**: Enables beta APIs. Beta APIs are on track to become stable and permanent features. Use them with
 //!    caution because it is possible that breaking changes are made to these APIs in a minor version.
 //! - **experimental-apis**: Enables experimental APIs. Experimental APIs are just that - an experiment. An experimental
@@ -106,6 +123,20 @@
 //! }
 //! ```
 //!
+//! ### Async support with async-std
+//!
+//! To use the client from [`async-std`](https://crates.io/crates/async-std) or
+//! [`smol`](https://crates.io/crates/smol), disable the default features and enable the `async-std`
+//! feature, along with a TLS feature
+//!
+//! ```toml,no_run
+//! elasticsearch = { version = "*", default-features = false, features = ["async-std", "native-tls"] }
+//! ```
+//!
+//! The same API calls can then be awaited within an `async-std` task. The `async-std` timer is used to
+//! wait between retries, whilst requests sent by `reqwest` still run on `tokio`, within the `tokio`
+//! compatibility runtime that `async-std` starts, so no `tokio` runtime needs to be started.
+//!
 //! ## Create a client
 //!
 //! To create a client to make API calls to Elasticsearch running on `http://localhost:9200`
@@ -370,6 +401,10 @@ mod readme {
 extern crate dyn_clone;
 
 pub mod auth;
+#[cfg(feature = "aws-auth")]
+pub mod aws;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod cert;
 pub mod http;
 pub mod params;
@@ -32,7 +32,7 @@ use crate::{
         headers::{HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE},
         request::{Body, JsonBody, NdBody, PARTS_ENCODED},
         response::Response,
-        transport::Transport,
+        transport::{NodeSelector, RequestOptions, Transport},
         Method,
     },
     params::*,
@@ -92,6 +92,7 @@ pub struct Bulk<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pipeline: Option<&'b str>,
     pretty: Option<bool>,
     refresh: Option<Refresh>,
@@ -121,6 +122,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pipeline: None,
             pretty: None,
             refresh: None,
@@ -164,6 +166,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pipeline: self.pipeline,
             pretty: self.pretty,
             refresh: self.refresh,
@@ -196,6 +199,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "The pipeline id to preprocess incoming documents with"]
     pub fn pipeline(mut self, pipeline: &'b str) -> Self {
         self.pipeline = Some(pipeline);
@@ -297,9 +305,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("bulk")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -339,6 +358,7 @@ pub struct ClearScroll<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -358,6 +378,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -376,6 +397,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -401,6 +423,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -443,9 +470,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("clear_scroll")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -474,6 +512,7 @@ pub struct ClosePointInTime<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -493,6 +532,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -511,6 +551,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -536,6 +577,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -578,9 +624,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("close_point_in_time")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -631,6 +688,7 @@ pub struct Count<'a, 'b, B> {
     ignore_unavailable: Option<bool>,
     lenient: Option<bool>,
     min_score: Option<i64>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     preference: Option<&'b str>,
     pretty: Option<bool>,
     q: Option<&'b str>,
@@ -664,6 +722,7 @@ where
             ignore_unavailable: None,
             lenient: None,
             min_score: None,
+            node_selector: None,
             preference: None,
             pretty: None,
             q: None,
@@ -711,6 +770,7 @@ where
             ignore_unavailable: self.ignore_unavailable,
             lenient: self.lenient,
             min_score: self.min_score,
+            node_selector: self.node_selector,
             preference: self.preference,
             pretty: self.pretty,
             q: self.q,
@@ -775,6 +835,11 @@ where
         self.min_score = Some(min_score);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
     pub fn preference(mut self, preference: &'b str) -> Self {
         self.preference = Some(preference);
@@ -870,9 +935,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("count")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -930,6 +1006,7 @@ pub struct Create<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pipeline: Option<&'b str>,
     pretty: Option<bool>,
     refresh: Option<Refresh>,
@@ -956,6 +1033,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pipeline: None,
             pretty: None,
             refresh: None,
@@ -981,6 +1059,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pipeline: self.pipeline,
             pretty: self.pretty,
             refresh: self.refresh,
@@ -1013,6 +1092,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "The pipeline id to preprocess incoming documents with"]
     pub fn pipeline(mut self, pipeline: &'b str) -> Self {
         self.pipeline = Some(pipeline);
@@ -1104,9 +1188,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("create")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1164,6 +1259,7 @@ pub struct Delete<'a, 'b> {
     human: Option<bool>,
     if_primary_term: Option<i64>,
     if_seq_no: Option<i64>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     refresh: Option<Refresh>,
     request_timeout: Option<Duration>,
@@ -1187,6 +1283,7 @@ impl<'a, 'b> Delete<'a, 'b> {
             human: None,
             if_primary_term: None,
             if_seq_no: None,
+            node_selector: None,
             pretty: None,
             refresh: None,
             request_timeout: None,
@@ -1228,6 +1325,11 @@ impl<'a, 'b> Delete<'a, 'b> {
         self.if_seq_no = Some(if_seq_no);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1316,9 +1418,20 @@ impl<'a, 'b> Delete<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("delete")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1370,6 +1483,7 @@ pub struct DeleteByQuery<'a, 'b, B> {
     ignore_unavailable: Option<bool>,
     lenient: Option<bool>,
     max_docs: Option<i64>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     preference: Option<&'b str>,
     pretty: Option<bool>,
     q: Option<&'b str>,
@@ -1421,6 +1535,7 @@ where
             ignore_unavailable: None,
             lenient: None,
             max_docs: None,
+            node_selector: None,
             preference: None,
             pretty: None,
             q: None,
@@ -1501,6 +1616,7 @@ where
             ignore_unavailable: self.ignore_unavailable,
             lenient: self.lenient,
             max_docs: self.max_docs,
+            node_selector: self.node_selector,
             preference: self.preference,
             pretty: self.pretty,
             q: self.q,
@@ -1584,6 +1700,11 @@ where
         self.max_docs = Some(max_docs);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
     pub fn preference(mut self, preference: &'b str) -> Self {
         self.preference = Some(preference);
@@ -1787,9 +1908,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("delete_by_query")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1826,6 +1958,7 @@ pub struct DeleteByQueryRethrottle<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     requests_per_second: Option<i64>,
@@ -1846,6 +1979,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             requests_per_second: None,
@@ -1865,6 +1999,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             requests_per_second: self.requests_per_second,
@@ -1891,6 +2026,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -1940,9 +2080,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("delete_by_query_rethrottle")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -1977,6 +2128,7 @@ pub struct DeleteScript<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -1994,6 +2146,7 @@ impl<'a, 'b> DeleteScript<'a, 'b> {
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -2025,6 +2178,11 @@ impl<'a, 'b> DeleteScript<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2076,9 +2234,20 @@ impl<'a, 'b> DeleteScript<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("delete_script")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2119,6 +2288,7 @@ pub struct Exists<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     preference: Option<&'b str>,
     pretty: Option<bool>,
     realtime: Option<bool>,
@@ -2144,6 +2314,7 @@ impl<'a, 'b> Exists<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             preference: None,
             pretty: None,
             realtime: None,
@@ -2191,6 +2362,11 @@ impl<'a, 'b> Exists<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
     pub fn preference(mut self, preference: &'b str) -> Self {
         self.preference = Some(preference);
@@ -2292,9 +2468,20 @@ impl<'a, 'b> Exists<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("exists")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2354,6 +2541,7 @@ pub struct ExistsSource<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     preference: Option<&'b str>,
     pretty: Option<bool>,
     realtime: Option<bool>,
@@ -2378,6 +2566,7 @@ impl<'a, 'b> ExistsSource<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             preference: None,
             pretty: None,
             realtime: None,
@@ -2424,6 +2613,11 @@ impl<'a, 'b> ExistsSource<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
     pub fn preference(mut self, preference: &'b str) -> Self {
         self.preference = Some(preference);
@@ -2517,9 +2711,20 @@ impl<'a, 'b> ExistsSource<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("exists_source")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2566,6 +2771,7 @@ pub struct Explain<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     lenient: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     preference: Option<&'b str>,
     pretty: Option<bool>,
     q: Option<&'b str>,
@@ -2597,6 +2803,7 @@ where
             filter_path: None,
             human: None,
             lenient: None,
+            node_selector: None,
             preference: None,
             pretty: None,
             q: None,
@@ -2652,6 +2859,7 @@ where
             headers: self.headers,
             human: self.human,
             lenient: self.lenient,
+            node_selector: self.node_selector,
             preference: self.preference,
             pretty: self.pretty,
             q: self.q,
@@ -2696,6 +2904,11 @@ where
         self.lenient = Some(lenient);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
     pub fn preference(mut self, preference: &'b str) -> Self {
         self.preference = Some(preference);
@@ -2789,9 +3002,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("explain")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -2837,6 +3061,7 @@ pub struct FieldCaps<'a, 'b, B> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     include_unmapped: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -2861,6 +3086,7 @@ where
             human: None,
             ignore_unavailable: None,
             include_unmapped: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -2889,6 +3115,7 @@ where
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
             include_unmapped: self.include_unmapped,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -2934,6 +3161,11 @@ where
         self.include_unmapped = Some(include_unmapped);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -2991,9 +3223,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("field_caps")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3034,6 +3277,7 @@ pub struct Get<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     preference: Option<&'b str>,
     pretty: Option<bool>,
     realtime: Option<bool>,
@@ -3059,6 +3303,7 @@ impl<'a, 'b> Get<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             preference: None,
             pretty: None,
             realtime: None,
@@ -3106,6 +3351,11 @@ impl<'a, 'b> Get<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
     pub fn preference(mut self, preference: &'b str) -> Self {
         self.preference = Some(preference);
@@ -3207,9 +3457,20 @@ impl<'a, 'b> Get<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("get")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3244,6 +3505,7 @@ pub struct GetScript<'a, 'b> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -3260,6 +3522,7 @@ impl<'a, 'b> GetScript<'a, 'b> {
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -3290,6 +3553,11 @@ impl<'a, 'b> GetScript<'a, 'b> {
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -3334,9 +3602,20 @@ impl<'a, 'b> GetScript<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("get_script")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3368,6 +3647,7 @@ pub struct GetScriptContext<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -3384,6 +3664,7 @@ impl<'a, 'b> GetScriptContext<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -3409,6 +3690,11 @@ impl<'a, 'b> GetScriptContext<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -3451,9 +3737,20 @@ impl<'a, 'b> GetScriptContext<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("get_script_context")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3485,6 +3782,7 @@ pub struct GetScriptLanguages<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -3501,6 +3799,7 @@ impl<'a, 'b> GetScriptLanguages<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -3526,6 +3825,11 @@ impl<'a, 'b> GetScriptLanguages<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -3568,9 +3872,20 @@ impl<'a, 'b> GetScriptLanguages<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("get_script_languages")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3611,6 +3926,7 @@ pub struct GetSource<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     preference: Option<&'b str>,
     pretty: Option<bool>,
     realtime: Option<bool>,
@@ -3635,6 +3951,7 @@ impl<'a, 'b> GetSource<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             preference: None,
             pretty: None,
             realtime: None,
@@ -3681,6 +3998,11 @@ impl<'a, 'b> GetSource<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
     pub fn preference(mut self, preference: &'b str) -> Self {
         self.preference = Some(preference);
@@ -3774,9 +4096,20 @@ impl<'a, 'b> GetSource<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("get_source")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -3828,6 +4161,7 @@ pub struct Index<'a, 'b, B> {
     human: Option<bool>,
     if_primary_term: Option<i64>,
     if_seq_no: Option<i64>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     op_type: Option<OpType>,
     pipeline: Option<&'b str>,
     pretty: Option<bool>,
@@ -3858,6 +4192,7 @@ where
             human: None,
             if_primary_term: None,
             if_seq_no: None,
+            node_selector: None,
             op_type: None,
             pipeline: None,
             pretty: None,
@@ -3887,6 +4222,7 @@ where
             human: self.human,
             if_primary_term: self.if_primary_term,
             if_seq_no: self.if_seq_no,
+            node_selector: self.node_selector,
             op_type: self.op_type,
             pipeline: self.pipeline,
             pretty: self.pretty,
@@ -3931,6 +4267,11 @@ where
         self.if_seq_no = Some(if_seq_no);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Explicit operation type. Defaults to `index` for requests with an explicit document ID, and to `create`for requests without an explicit document ID"]
     pub fn op_type(mut self, op_type: OpType) -> Self {
         self.op_type = Some(op_type);
@@ -4040,9 +4381,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("index")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -4070,6 +4422,7 @@ pub struct Info<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -4085,6 +4438,7 @@ impl<'a, 'b> Info<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -4110,6 +4464,11 @@ impl<'a, 'b> Info<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -4152,9 +4511,20 @@ impl<'a, 'b> Info<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("info")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -4197,6 +4567,7 @@ pub struct Mget<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     preference: Option<&'b str>,
     pretty: Option<bool>,
     realtime: Option<bool>,
@@ -4224,6 +4595,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             preference: None,
             pretty: None,
             realtime: None,
@@ -4265,6 +4637,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             preference: self.preference,
             pretty: self.pretty,
             realtime: self.realtime,
@@ -4295,6 +4668,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
     pub fn preference(mut self, preference: &'b str) -> Self {
         self.preference = Some(preference);
@@ -4385,9 +4763,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("mget")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -4431,6 +4820,7 @@ pub struct Msearch<'a, 'b, B> {
     human: Option<bool>,
     max_concurrent_searches: Option<i64>,
     max_concurrent_shard_requests: Option<i64>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pre_filter_shard_size: Option<i64>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
@@ -4457,6 +4847,7 @@ where
             human: None,
             max_concurrent_searches: None,
             max_concurrent_shard_requests: None,
+            node_selector: None,
             pre_filter_shard_size: None,
             pretty: None,
             request_timeout: None,
@@ -4482,6 +4873,7 @@ where
             human: self.human,
             max_concurrent_searches: self.max_concurrent_searches,
             max_concurrent_shard_requests: self.max_concurrent_shard_requests,
+            node_selector: self.node_selector,
             pre_filter_shard_size: self.pre_filter_shard_size,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
@@ -4526,6 +4918,11 @@ where
         self.max_concurrent_shard_requests = Some(max_concurrent_shard_requests);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "A threshold that enforces a pre-filter roundtrip to prefilter search shards based on query rewriting if the\u{a0}number of shards the search request expands to exceeds the threshold. This filter roundtrip can limit the number of shards significantly if for instance a shard can not match any documents based on its rewrite method ie. if date filters are mandatory to match but the shard bounds and the query are disjoint."]
     pub fn pre_filter_shard_size(mut self, pre_filter_shard_size: i64) -> Self {
         self.pre_filter_shard_size = Some(pre_filter_shard_size);
@@ -4605,9 +5002,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("msearch")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -4650,6 +5058,7 @@ pub struct MsearchTemplate<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     max_concurrent_searches: Option<i64>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     rest_total_hits_as_int: Option<bool>,
@@ -4674,6 +5083,7 @@ where
             filter_path: None,
             human: None,
             max_concurrent_searches: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             rest_total_hits_as_int: None,
@@ -4697,6 +5107,7 @@ where
             headers: self.headers,
             human: self.human,
             max_concurrent_searches: self.max_concurrent_searches,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             rest_total_hits_as_int: self.rest_total_hits_as_int,
@@ -4735,6 +5146,11 @@ where
         self.max_concurrent_searches = Some(max_concurrent_searches);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -4805,9 +5221,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("msearch_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -4850,6 +5277,7 @@ pub struct Mtermvectors<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     ids: Option<&'b [&'b str]>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     offsets: Option<bool>,
     payloads: Option<bool>,
     positions: Option<bool>,
@@ -4881,6 +5309,7 @@ where
             filter_path: None,
             human: None,
             ids: None,
+            node_selector: None,
             offsets: None,
             payloads: None,
             positions: None,
@@ -4911,6 +5340,7 @@ where
             headers: self.headers,
             human: self.human,
             ids: self.ids,
+            node_selector: self.node_selector,
             offsets: self.offsets,
             payloads: self.payloads,
             positions: self.positions,
@@ -4960,6 +5390,11 @@ where
         self.ids = Some(ids);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Specifies if term offsets should be returned. Applies to all returned documents unless otherwise specified in body \"params\" or \"docs\"."]
     pub fn offsets(mut self, offsets: bool) -> Self {
         self.offsets = Some(offsets);
@@ -5076,9 +5511,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("mtermvectors")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -5122,6 +5568,7 @@ pub struct OpenPointInTime<'a, 'b, B> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     keep_alive: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     preference: Option<&'b str>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
@@ -5146,6 +5593,7 @@ where
             human: None,
             ignore_unavailable: None,
             keep_alive: None,
+            node_selector: None,
             preference: None,
             pretty: None,
             request_timeout: None,
@@ -5169,6 +5617,7 @@ where
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
             keep_alive: self.keep_alive,
+            node_selector: self.node_selector,
             preference: self.preference,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
@@ -5211,6 +5660,11 @@ where
         self.keep_alive = Some(keep_alive);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
     pub fn preference(mut self, preference: &'b str) -> Self {
         self.preference = Some(preference);
@@ -5274,9 +5728,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("open_point_in_time")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -5304,6 +5769,7 @@ pub struct Ping<'a, 'b> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -5319,6 +5785,7 @@ impl<'a, 'b> Ping<'a, 'b> {
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -5344,6 +5811,11 @@ impl<'a, 'b> Ping<'a, 'b> {
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -5386,9 +5858,20 @@ impl<'a, 'b> Ping<'a, 'b> {
             Some(query_params)
         };
         let body = Option::<()>::None;
+        let options = RequestOptions::new()
+            .endpoint("ping")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -5439,6 +5922,7 @@ pub struct PutScript<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     master_timeout: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -5461,6 +5945,7 @@ where
             filter_path: None,
             human: None,
             master_timeout: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -5482,6 +5967,7 @@ where
             headers: self.headers,
             human: self.human,
             master_timeout: self.master_timeout,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -5518,6 +6004,11 @@ where
         self.master_timeout = Some(master_timeout);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -5571,9 +6062,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("put_script")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -5621,6 +6123,7 @@ pub struct RankEval<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     search_type: Option<SearchType>,
@@ -5645,6 +6148,7 @@ where
             filter_path: None,
             human: None,
             ignore_unavailable: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             search_type: None,
@@ -5672,6 +6176,7 @@ where
             headers: self.headers,
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             search_type: self.search_type,
@@ -5708,6 +6213,11 @@ where
         self.ignore_unavailable = Some(ignore_unavailable);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -5767,9 +6277,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("rank_eval")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -5799,6 +6320,7 @@ pub struct Reindex<'a, 'b, B> {
     headers: HeaderMap,
     human: Option<bool>,
     max_docs: Option<i64>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     refresh: Option<bool>,
     request_timeout: Option<Duration>,
@@ -5826,6 +6348,7 @@ where
             filter_path: None,
             human: None,
             max_docs: None,
+            node_selector: None,
             pretty: None,
             refresh: None,
             request_timeout: None,
@@ -5852,6 +6375,7 @@ where
             headers: self.headers,
             human: self.human,
             max_docs: self.max_docs,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             refresh: self.refresh,
             request_timeout: self.request_timeout,
@@ -5889,6 +6413,11 @@ where
         self.max_docs = Some(max_docs);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -5982,9 +6511,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("reindex")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -6021,6 +6561,7 @@ pub struct ReindexRethrottle<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     requests_per_second: Option<i64>,
@@ -6041,6 +6582,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             requests_per_second: None,
@@ -6060,6 +6602,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             requests_per_second: self.requests_per_second,
@@ -6086,6 +6629,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -6135,9 +6683,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("reindex_rethrottle")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -6175,6 +6734,7 @@ pub struct RenderSearchTemplate<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -6194,6 +6754,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -6212,6 +6773,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -6237,6 +6799,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -6282,9 +6849,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("render_search_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -6317,6 +6895,7 @@ pub struct ScriptsPainlessExecute<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -6337,6 +6916,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -6355,6 +6935,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -6380,6 +6961,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -6425,9 +7011,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("scripts_painless_execute")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -6466,6 +7063,7 @@ pub struct Scroll<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     rest_total_hits_as_int: Option<bool>,
@@ -6488,6 +7086,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             rest_total_hits_as_int: None,
@@ -6509,6 +7108,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             rest_total_hits_as_int: self.rest_total_hits_as_int,
@@ -6537,6 +7137,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -6603,9 +7208,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("scroll")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -6666,6 +7282,7 @@ pub struct Search<'a, 'b, B> {
     lenient: Option<bool>,
     max_concurrent_shard_requests: Option<i64>,
     min_compatible_shard_node: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pre_filter_shard_size: Option<i64>,
     preference: Option<&'b str>,
     pretty: Option<bool>,
@@ -6728,6 +7345,7 @@ where
             lenient: None,
             max_concurrent_shard_requests: None,
             min_compatible_shard_node: None,
+            node_selector: None,
             pre_filter_shard_size: None,
             preference: None,
             pretty: None,
@@ -6829,6 +7447,7 @@ where
             lenient: self.lenient,
             max_concurrent_shard_requests: self.max_concurrent_shard_requests,
             min_compatible_shard_node: self.min_compatible_shard_node,
+            node_selector: self.node_selector,
             pre_filter_shard_size: self.pre_filter_shard_size,
             preference: self.preference,
             pretty: self.pretty,
@@ -6937,6 +7556,11 @@ where
         self.min_compatible_shard_node = Some(min_compatible_shard_node);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "A threshold that enforces a pre-filter roundtrip to prefilter search shards based on query rewriting if the\u{a0}number of shards the search request expands to exceeds the threshold. This filter roundtrip can limit the number of shards significantly if for instance a shard can not match any documents based on its rewrite method ie. if date filters are mandatory to match but the shard bounds and the query are disjoint."]
     pub fn pre_filter_shard_size(mut self, pre_filter_shard_size: i64) -> Self {
         self.pre_filter_shard_size = Some(pre_filter_shard_size);
@@ -7192,9 +7816,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("search")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -7263,6 +7898,7 @@ pub struct SearchMvt<'a, 'b, B> {
     grid_type: Option<GridType>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     size: Option<i32>,
@@ -7288,6 +7924,7 @@ where
             grid_precision: None,
             grid_type: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             size: None,
@@ -7311,6 +7948,7 @@ where
             grid_type: self.grid_type,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             size: self.size,
@@ -7357,6 +7995,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -7417,9 +8060,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("search_mvt")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -7464,6 +8118,7 @@ pub struct SearchShards<'a, 'b, B> {
     human: Option<bool>,
     ignore_unavailable: Option<bool>,
     local: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     preference: Option<&'b str>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
@@ -7489,6 +8144,7 @@ where
             human: None,
             ignore_unavailable: None,
             local: None,
+            node_selector: None,
             preference: None,
             pretty: None,
             request_timeout: None,
@@ -7518,6 +8174,7 @@ where
             human: self.human,
             ignore_unavailable: self.ignore_unavailable,
             local: self.local,
+            node_selector: self.node_selector,
             preference: self.preference,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
@@ -7560,6 +8217,11 @@ where
         self.local = Some(local);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
     pub fn preference(mut self, preference: &'b str) -> Self {
         self.preference = Some(preference);
@@ -7628,9 +8290,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("search_shards")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -7677,6 +8350,7 @@ pub struct SearchTemplate<'a, 'b, B> {
     human: Option<bool>,
     ignore_throttled: Option<bool>,
     ignore_unavailable: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     preference: Option<&'b str>,
     pretty: Option<bool>,
     profile: Option<bool>,
@@ -7709,6 +8383,7 @@ where
             human: None,
             ignore_throttled: None,
             ignore_unavailable: None,
+            node_selector: None,
             preference: None,
             pretty: None,
             profile: None,
@@ -7745,6 +8420,7 @@ where
             human: self.human,
             ignore_throttled: self.ignore_throttled,
             ignore_unavailable: self.ignore_unavailable,
+            node_selector: self.node_selector,
             preference: self.preference,
             pretty: self.pretty,
             profile: self.profile,
@@ -7802,6 +8478,11 @@ where
         self.ignore_unavailable = Some(ignore_unavailable);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Specify the node or shard the operation should be performed on (default: random)"]
     pub fn preference(mut self, preference: &'b str) -> Self {
         self.preference = Some(preference);
@@ -7910,9 +8591,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("search_template")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -7954,6 +8646,7 @@ pub struct TermsEnum<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     source: Option<&'b str>,
@@ -7974,6 +8667,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             source: None,
@@ -7992,6 +8686,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             source: self.source,
@@ -8017,6 +8712,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -8062,9 +8762,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("terms_enum")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -8116,6 +8827,7 @@ pub struct Termvectors<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     offsets: Option<bool>,
     payloads: Option<bool>,
     positions: Option<bool>,
@@ -8146,6 +8858,7 @@ where
             fields: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             offsets: None,
             payloads: None,
             positions: None,
@@ -8175,6 +8888,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             offsets: self.offsets,
             payloads: self.payloads,
             positions: self.positions,
@@ -8219,6 +8933,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Specifies if term offsets should be returned."]
     pub fn offsets(mut self, offsets: bool) -> Self {
         self.offsets = Some(offsets);
@@ -8332,9 +9051,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("termvectors")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -8398,6 +9128,7 @@ pub struct Update<'a, 'b, B> {
     if_primary_term: Option<i64>,
     if_seq_no: Option<i64>,
     lang: Option<&'b str>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     refresh: Option<Refresh>,
     request_timeout: Option<Duration>,
@@ -8429,6 +9160,7 @@ where
             if_primary_term: None,
             if_seq_no: None,
             lang: None,
+            node_selector: None,
             pretty: None,
             refresh: None,
             request_timeout: None,
@@ -8474,6 +9206,7 @@ where
             if_primary_term: self.if_primary_term,
             if_seq_no: self.if_seq_no,
             lang: self.lang,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             refresh: self.refresh,
             request_timeout: self.request_timeout,
@@ -8520,6 +9253,11 @@ where
         self.lang = Some(lang);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -8619,9 +9357,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("update")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -8673,6 +9422,7 @@ pub struct UpdateByQuery<'a, 'b, B> {
     ignore_unavailable: Option<bool>,
     lenient: Option<bool>,
     max_docs: Option<i64>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pipeline: Option<&'b str>,
     preference: Option<&'b str>,
     pretty: Option<bool>,
@@ -8726,6 +9476,7 @@ where
             ignore_unavailable: None,
             lenient: None,
             max_docs: None,
+            node_selector: None,
             pipeline: None,
             preference: None,
             pretty: None,
@@ -8808,6 +9559,7 @@ where
             ignore_unavailable: self.ignore_unavailable,
             lenient: self.lenient,
             max_docs: self.max_docs,
+            node_selector: self.node_selector,
             pipeline: self.pipeline,
             preference: self.preference,
             pretty: self.pretty,
@@ -8893,6 +9645,11 @@ where
         self.max_docs = Some(max_docs);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Ingest pipeline to set on index requests made by this action. (default: none)"]
     pub fn pipeline(mut self, pipeline: &'b str) -> Self {
         self.pipeline = Some(pipeline);
@@ -9110,9 +9867,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("update_by_query")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -9149,6 +9917,7 @@ pub struct UpdateByQueryRethrottle<'a, 'b, B> {
     filter_path: Option<&'b [&'b str]>,
     headers: HeaderMap,
     human: Option<bool>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     request_timeout: Option<Duration>,
     requests_per_second: Option<i64>,
@@ -9169,6 +9938,7 @@ where
             error_trace: None,
             filter_path: None,
             human: None,
+            node_selector: None,
             pretty: None,
             request_timeout: None,
             requests_per_second: None,
@@ -9188,6 +9958,7 @@ where
             filter_path: self.filter_path,
             headers: self.headers,
             human: self.human,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             request_timeout: self.request_timeout,
             requests_per_second: self.requests_per_second,
@@ -9214,6 +9985,11 @@ where
         self.human = Some(human);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -9263,9 +10039,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("update_by_query_rethrottle")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -37,7 +37,7 @@ use crate::{
         headers::{HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE},
         request::{Body, JsonBody, NdBody, PARTS_ENCODED},
         response::Response,
-        transport::Transport,
+        transport::{NodeSelector, RequestOptions, Transport},
         Method,
     },
     params::*,
@@ -78,6 +78,7 @@ pub struct TextStructureFindStructure<'a, 'b, B> {
     human: Option<bool>,
     line_merge_size_limit: Option<i32>,
     lines_to_sample: Option<i32>,
+    node_selector: Option<Box<dyn NodeSelector>>,
     pretty: Option<bool>,
     quote: Option<&'b str>,
     request_timeout: Option<Duration>,
@@ -111,6 +112,7 @@ where
             human: None,
             line_merge_size_limit: None,
             lines_to_sample: None,
+            node_selector: None,
             pretty: None,
             quote: None,
             request_timeout: None,
@@ -143,6 +145,7 @@ where
             human: self.human,
             line_merge_size_limit: self.line_merge_size_limit,
             lines_to_sample: self.lines_to_sample,
+            node_selector: self.node_selector,
             pretty: self.pretty,
             quote: self.quote,
             request_timeout: self.request_timeout,
@@ -218,6 +221,11 @@ where
         self.lines_to_sample = Some(lines_to_sample);
         self
     }
+    #[doc = "Sets a [NodeSelector] that determines the nodes to which this API call can be sent, overriding any selector set on the [Transport]. Requires a connection pool that knows about the nodes in the cluster"]
+    pub fn node_selector<S: NodeSelector + 'static>(mut self, selector: S) -> Self {
+        self.node_selector = Some(Box::new(selector));
+        self
+    }
     #[doc = "Pretty format the returned JSON response."]
     pub fn pretty(mut self, pretty: bool) -> Self {
         self.pretty = Some(pretty);
@@ -314,9 +322,20 @@ where
             Some(query_params)
         };
         let body = self.body;
+        let options = RequestOptions::new()
+            .endpoint("text_structure.find_structure")
+            .with_node_selector(self.node_selector);
         let response = self
             .transport
-            .send(method, &path, headers, query_string.as_ref(), body, timeout)
+            .send_with_options(
+                method,
+                &path,
+                headers,
+                query_string.as_ref(),
+                body,
+                timeout,
+                options,
+            )
             .await?;
         Ok(response)
     }
@@ -19,14 +19,18 @@
 pub mod common;
 use common::*;
 
-use elasticsearch::auth::Credentials;
+use elasticsearch::auth::{Credentials, OAuth2TokenProvider};
 
 use base64::{self, write::EncoderWriter as Base64Encoder};
+use serde_json::{json, Value};
 // use std::fs::File;
 // use std::io::Read;
-use std::io::Write;
+use std::{
+    io::Write,
+    sync::{Arc, Mutex},
+};
 
-#[tokio::test]
+#[async_test]
 async fn basic_auth_header() -> Result<(), failure::Error> {
     let server = server::http(move |req| async move {
         let mut header_value = b"Basic ".to_vec();
@@ -51,7 +55,7 @@ async fn basic_auth_header() -> Result<(), failure::Error> {
     Ok(())
 }
 
-#[tokio::test]
+#[async_test]
 async fn api_key_header() -> Result<(), failure::Error> {
     let server = server::http(move |req| async move {
         let mut header_value = b"ApiKey ".to_vec();
@@ -76,7 +80,7 @@ async fn api_key_header() -> Result<(), failure::Error> {
     Ok(())
 }
 
-#[tokio::test]
+#[async_test]
 async fn bearer_header() -> Result<(), failure::Error> {
     let server = server::http(move |req| async move {
         assert_eq!(req.headers()["authorization"], "Bearer access_token");
@@ -92,8 +96,170 @@ async fn bearer_header() -> Result<(), failure::Error> {
     Ok(())
 }
 
+#[async_test]
+async fn oauth2_token_refreshed_on_unauthorized() -> Result<(), failure::Error> {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let server = {
+        let requests = requests.clone();
+        server::http(move |req| {
+            let requests = requests.clone();
+            async move {
+                let authorization = req.headers()["authorization"].to_str().unwrap().to_string();
+                if req.uri().path() == "/_security/oauth2/token" {
+                    let body = hyper::body::to_bytes(req.into_body()).await.unwrap();
+                    let body: Value = serde_json::from_slice(&body).unwrap();
+                    requests
+                        .lock()
+                        .unwrap()
+                        .push(format!("token {}", body["grant_type"].as_str().unwrap()));
+
+                    let access_token = match body["grant_type"].as_str() {
+                        Some("password") => "first",
+                        _ => "second",
+                    };
+                    let token = json!({
+                        "access_token": access_token,
+                        "type": "Bearer",
+                        "expires_in": 1200,
+                        "refresh_token": "refresh"
+                    });
+                    server::elasticsearch()
+                        .body(token.to_string().into())
+                        .unwrap()
+                } else {
+                    requests.lock().unwrap().push(authorization.clone());
+                    match authorization.as_str() {
+                        "Bearer first" => http::Response::builder()
+                            .status(401)
+                            .body("".into())
+                            .unwrap(),
+                        _ => server::elasticsearch().body("".into()).unwrap(),
+                    }
+                }
+            }
+        })
+    };
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .credentials_provider(OAuth2TokenProvider::new("username", "password"));
+
+    let client = client::create(builder);
+    let response = client.ping().send().await?;
+
+    assert_eq!(response.status_code(), 200);
+    assert_eq!(
+        *requests.lock().unwrap(),
+        vec![
+            "token password",
+            "Bearer first",
+            "token refresh_token",
+            "Bearer second"
+        ]
+    );
+
+    Ok(())
+}
+
+#[async_test]
+async fn oauth2_token_obtained_once_for_concurrent_requests() -> Result<(), failure::Error> {
+    let token_requests = Arc::new(Mutex::new(0));
+    let server = {
+        let token_requests = token_requests.clone();
+        server::http(move |req| {
+            let token_requests = token_requests.clone();
+            async move {
+                if req.uri().path() == "/_security/oauth2/token" {
+                    *token_requests.lock().unwrap() += 1;
+                    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                    let token = json!({
+                        "access_token": "token",
+                        "type": "Bearer",
+                        "expires_in": 1200
+                    });
+                    server::elasticsearch()
+                        .body(token.to_string().into())
+                        .unwrap()
+                } else {
+                    assert_eq!(req.headers()["authorization"], "Bearer token");
+                    server::elasticsearch().body("".into()).unwrap()
+                }
+            }
+        })
+    };
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .credentials_provider(OAuth2TokenProvider::new("username", "password"));
+
+    let client = client::create(builder);
+    let responses =
+        futures::future::join_all((0..5).map(|_| async { client.ping().send().await })).await;
+
+    for response in responses {
+        assert_eq!(response?.status_code(), 200);
+    }
+    assert_eq!(*token_requests.lock().unwrap(), 1);
+
+    Ok(())
+}
+
+#[async_test]
+async fn oauth2_token_obtained_from_server_without_product_header() -> Result<(), failure::Error> {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let server = {
+        let requests = requests.clone();
+        server::http(move |req| {
+            let requests = requests.clone();
+            async move {
+                let authorization = req.headers()["authorization"].to_str().unwrap();
+                requests.lock().unwrap().push(format!(
+                    "{} {} {}",
+                    req.method(),
+                    req.uri().path(),
+                    authorization
+                ));
+                match req.uri().path() {
+                    "/_security/oauth2/token" => {
+                        let token = json!({
+                            "access_token": "token",
+                            "type": "Bearer",
+                            "expires_in": 1200
+                        });
+                        http::Response::new(token.to_string().into())
+                    }
+                    "/" if req.method() == http::Method::GET => {
+                        let info = json!({
+                            "version": { "number": "7.10.2", "build_flavor": "default" },
+                            "tagline": "You Know, for Search"
+                        });
+                        http::Response::new(info.to_string().into())
+                    }
+                    _ => http::Response::default(),
+                }
+            }
+        })
+    };
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .credentials_provider(OAuth2TokenProvider::new("username", "password"));
+    let client = client::create(builder);
+    let response = client.ping().send().await?;
+    assert_eq!(response.status_code(), 200);
+
+    // the server is verified with the credentials of the get token API call
+    assert_eq!(
+        *requests.lock().unwrap(),
+        vec![
+            "POST /_security/oauth2/token Basic dXNlcm5hbWU6cGFzc3dvcmQ=",
+            "GET / Basic dXNlcm5hbWU6cGFzc3dvcmQ=",
+            "HEAD / Bearer token",
+        ]
+    );
+
+    Ok(())
+}
+
 // TODO: test PKI authentication. Could configure a HttpsConnector, maybe using https://github.com/sfackler/hyper-openssl?, or send to PKI configured Elasticsearch.
-//#[tokio::test]
+//#[async_test]
 //async fn client_certificate() -> Result<(), failure::Error> {
 //    let server = server::http(move |req| {
 //        async move {
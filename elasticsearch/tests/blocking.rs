@@ -0,0 +1,110 @@
+/*
+ * Licensed to Elasticsearch B.V. under one or more contributor
+ * license agreements. See the NOTICE file distributed with
+ * this work for additional information regarding copyright
+ * ownership. Elasticsearch B.V. licenses this file to you under
+ * the Apache License, Version 2.0 (the "License"); you may
+ * not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *	http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+#![cfg(feature = "blocking")]
+pub mod common;
+use common::*;
+
+use elasticsearch::{
+    blocking::{cat::CatIndicesParts, Elasticsearch, SearchParts},
+    http::{headers::HeaderMap, Method, StatusCode},
+};
+
+use serde_json::{json, Value};
+
+fn create_for_url(url: &str) -> Elasticsearch {
+    let transport = client::create_builder(url).build().unwrap();
+    Elasticsearch::new(transport)
+}
+
+#[test]
+fn blocking_send_with_body() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert_eq!(req.method(), hyper::Method::POST);
+        assert_eq!(req.uri().path(), "/posts/_search");
+        assert_eq!(req.uri().query(), Some("allow_no_indices=true"));
+        let body = hyper::body::to_bytes(req.into_body()).await.unwrap();
+        assert_eq!(body.as_ref(), br#"{"query":{"match_all":{}}}"#);
+        http::Response::builder()
+            .header("content-type", "application/json")
+            .body(r#"{"took":1}"#.into())
+            .unwrap()
+    });
+
+    let client = create_for_url(format!("http://{}", server.addr()).as_ref());
+    let response = client
+        .search(SearchParts::Index(&["posts"]))
+        .allow_no_indices(true)
+        .body(json!({ "query": { "match_all": {} } }))
+        .send()?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.method(), Method::Post);
+    let response_body = response.json::<Value>()?;
+    assert_eq!(response_body["took"], 1);
+
+    Ok(())
+}
+
+#[test]
+fn blocking_namespace_client() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert_eq!(req.method(), hyper::Method::GET);
+        assert_eq!(req.uri().path(), "/_cat/indices");
+        assert_eq!(req.uri().query(), Some("format=json"));
+        http::Response::new("[]".into())
+    });
+
+    let client = create_for_url(format!("http://{}", server.addr()).as_ref());
+    let response = client
+        .cat()
+        .indices(CatIndicesParts::None)
+        .format("json")
+        .send()?;
+
+    assert_eq!(response.text()?, "[]");
+
+    Ok(())
+}
+
+#[test]
+fn blocking_send_error_for_status_code() -> Result<(), failure::Error> {
+    let server = server::http(move |_| async move {
+        http::Response::builder()
+            .status(404)
+            .body(r#"{"error":{"type":"index_not_found_exception","reason":"no such index"},"status":404}"#.into())
+            .unwrap()
+    });
+
+    let client = create_for_url(format!("http://{}", server.addr()).as_ref());
+    let response = client.send(
+        Method::Get,
+        "/missing/_doc/1",
+        HeaderMap::new(),
+        Option::<&()>::None,
+        Option::<()>::None,
+        None,
+    )?;
+
+    assert!(response.error_for_status_code_ref().is_err());
+    let exception = response.exception()?.unwrap();
+    assert_eq!(exception.status(), Some(404));
+    assert_eq!(exception.error().ty(), Some("index_not_found_exception"));
+
+    Ok(())
+}
@@ -38,6 +38,16 @@ static CA_CHAIN_CERT: &[u8] = include_bytes!("../../.ci/certs/ca-chain.crt");
 static TESTNODE_CERT: &[u8] = include_bytes!("../../.ci/certs/testnode.crt");
 static TESTNODE_NO_SAN_CERT: &[u8] = include_bytes!("../../.ci/certs/testnode_no_san.crt");
 
+/// SHA-256 fingerprint of .ci/certs/testnode.crt
+#[cfg(feature = "rustls-tls")]
+static TESTNODE_FINGERPRINT: &str =
+    "7A:3A:60:31:CD:09:7D:A0:EE:84:D6:51:37:91:2A:84:57:6B:50:19:40:45:B4:1F:4F:4B:8A:C1:A9:81:16:BE";
+
+/// SHA-256 fingerprint of .ci/certs/ca.crt, sent by the server along with testnode.crt
+#[cfg(feature = "rustls-tls")]
+static CA_FINGERPRINT: &str =
+    "2D:42:9D:63:DE:8B:1A:74:B4:47:8D:3F:52:4B:33:2C:83:2E:1E:FE:43:99:8C:DA:6B:1A:35:1C:76:87:C7:B5";
+
 fn expected_error_message() -> String {
     if cfg!(windows) {
         "terminated in a root certificate which is not trusted by the trust provider".to_string()
@@ -51,7 +61,7 @@ fn expected_error_message() -> String {
 }
 
 /// Default certificate validation with a self signed certificate
-#[tokio::test]
+#[async_test]
 #[cfg(feature = "native-tls")]
 async fn default_certificate_validation() -> Result<(), failure::Error> {
     let builder = client::create_default_builder().cert_validation(CertificateValidation::Default);
@@ -78,7 +88,7 @@ async fn default_certificate_validation() -> Result<(), failure::Error> {
 }
 
 /// Default certificate validation with a self signed certificate and rustls-tls
-#[tokio::test]
+#[async_test]
 #[cfg(all(feature = "rustls-tls", not(feature = "native-tls")))]
 async fn default_certificate_validation_rustls_tls() -> Result<(), failure::Error> {
     let builder = client::create_default_builder().cert_validation(CertificateValidation::Default);
@@ -105,7 +115,7 @@ async fn default_certificate_validation_rustls_tls() -> Result<(), failure::Erro
 }
 
 /// Allows any certificate through
-#[tokio::test]
+#[async_test]
 async fn none_certificate_validation() -> Result<(), failure::Error> {
     let builder = client::create_default_builder().cert_validation(CertificateValidation::None);
     let client = client::create(builder);
@@ -115,7 +125,7 @@ async fn none_certificate_validation() -> Result<(), failure::Error> {
 
 /// Certificate provided by the server contains the one given to the client
 /// within the authority chain, and hostname matches
-#[tokio::test]
+#[async_test]
 #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
 async fn full_certificate_ca_validation() -> Result<(), failure::Error> {
     let cert = Certificate::from_pem(CA_CERT)?;
@@ -127,7 +137,7 @@ async fn full_certificate_ca_validation() -> Result<(), failure::Error> {
 }
 
 /// Try to load a certificate chain.
-#[tokio::test]
+#[async_test]
 #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
 async fn full_certificate_ca_chain_validation() -> Result<(), failure::Error> {
     let mut cert = Certificate::from_pem(CA_CHAIN_CERT)?;
@@ -141,7 +151,7 @@ async fn full_certificate_ca_chain_validation() -> Result<(), failure::Error> {
 }
 
 /// Certificate provided by the server is the one given to the client and hostname matches
-#[tokio::test]
+#[async_test]
 #[cfg(all(windows, feature = "native-tls"))]
 async fn full_certificate_validation() -> Result<(), failure::Error> {
     let cert = Certificate::from_pem(TESTNODE_CERT)?;
@@ -153,7 +163,7 @@ async fn full_certificate_validation() -> Result<(), failure::Error> {
 }
 
 /// Certificate provided by the server is the one given to the client and hostname matches, using rustls-tls
-#[tokio::test]
+#[async_test]
 #[cfg(feature = "rustls-tls")]
 async fn full_certificate_validation_rustls_tls() -> Result<(), failure::Error> {
     let mut chain: Vec<u8> = Vec::with_capacity(TESTNODE_CERT.len() + CA_CERT.len());
@@ -170,7 +180,7 @@ async fn full_certificate_validation_rustls_tls() -> Result<(), failure::Error>
 
 /// Certificate provided by the server is the one given to the client. This fails on Linux because
 /// it appears that it also needs the CA for the cert
-#[tokio::test]
+#[async_test]
 #[cfg(all(unix, any(feature = "native-tls", feature = "rustls-tls")))]
 async fn full_certificate_validation() -> Result<(), failure::Error> {
     let cert = Certificate::from_pem(TESTNODE_CERT)?;
@@ -205,7 +215,7 @@ async fn full_certificate_validation() -> Result<(), failure::Error> {
 }
 
 /// Certificate provided by the server is the one given to the client
-#[tokio::test]
+#[async_test]
 #[cfg(all(windows, feature = "native-tls"))]
 async fn certificate_certificate_validation() -> Result<(), failure::Error> {
     let cert = Certificate::from_pem(TESTNODE_CERT)?;
@@ -218,7 +228,7 @@ async fn certificate_certificate_validation() -> Result<(), failure::Error> {
 
 /// Certificate provided by the server is the one given to the client. This fails on Linux because
 /// it appears that it also needs the CA for the cert
-#[tokio::test]
+#[async_test]
 #[cfg(all(unix, feature = "native-tls"))]
 async fn certificate_certificate_validation() -> Result<(), failure::Error> {
     let cert = Certificate::from_pem(TESTNODE_CERT)?;
@@ -252,10 +262,40 @@ async fn certificate_certificate_validation() -> Result<(), failure::Error> {
     }
 }
 
+/// Certificate provided by the server is the one given to the client, using rustls-tls. As with
+/// native-tls on Linux, the CA for the cert is also needed
+#[async_test]
+#[cfg(all(feature = "rustls-tls", not(feature = "native-tls")))]
+async fn certificate_certificate_validation_rustls_tls() -> Result<(), failure::Error> {
+    let cert = Certificate::from_pem(TESTNODE_CERT)?;
+    let builder =
+        client::create_default_builder().cert_validation(CertificateValidation::Certificate(cert));
+    let client = client::create(builder);
+    let result = client.ping().send().await;
+
+    match result {
+        Ok(response) => Err(failure::err_msg(format!(
+            "Expected error but response was {}",
+            response.status_code()
+        ))),
+        Err(e) => {
+            let expected = "UnknownIssuer";
+            let actual = e.to_string();
+            assert!(
+                actual.contains(&expected),
+                "Expected error message to contain '{}' but was '{}'",
+                expected,
+                actual
+            );
+            Ok(())
+        }
+    }
+}
+
 /// Certificate provided by the server contains the one given to the client
 /// within the authority chain
-#[tokio::test]
-#[cfg(feature = "native-tls")]
+#[async_test]
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
 async fn certificate_certificate_ca_validation() -> Result<(), failure::Error> {
     let cert = Certificate::from_pem(CA_CERT)?;
     let builder =
@@ -265,8 +305,34 @@ async fn certificate_certificate_ca_validation() -> Result<(), failure::Error> {
     Ok(())
 }
 
+/// Certificate provided by the server contains the one given to the client within the
+/// authority chain, but the hostname does not match
+#[async_test]
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+async fn certificate_certificate_ca_validation_skips_hostname() -> Result<(), failure::Error> {
+    // 127.0.0.2 is a loopback address that is not in the certificate of the node
+    let mut url = url::Url::parse(&client::cluster_addr())?;
+    if url.scheme() != "https" {
+        return Ok(());
+    }
+    url.set_host(Some("127.0.0.2"))?;
+
+    let cert = Certificate::from_pem(CA_CERT)?;
+    let builder = client::create_builder(url.as_str())
+        .cert_validation(CertificateValidation::Certificate(cert));
+    let client = client::create(builder);
+    let _response = client.ping().send().await?;
+
+    let cert = Certificate::from_pem(CA_CERT)?;
+    let builder =
+        client::create_builder(url.as_str()).cert_validation(CertificateValidation::Full(cert));
+    let client = client::create(builder);
+    assert!(client.ping().send().await.is_err());
+    Ok(())
+}
+
 /// Certificate provided by the server does not match the one given to the client
-#[tokio::test]
+#[async_test]
 #[cfg(feature = "native-tls")]
 async fn fail_certificate_certificate_validation() -> Result<(), failure::Error> {
     let cert = Certificate::from_pem(TESTNODE_NO_SAN_CERT)?;
@@ -294,3 +360,73 @@ async fn fail_certificate_certificate_validation() -> Result<(), failure::Error>
         }
     }
 }
+
+/// Certificate provided by the server does not match the one given to the client, using rustls-tls
+#[async_test]
+#[cfg(all(feature = "rustls-tls", not(feature = "native-tls")))]
+async fn fail_certificate_certificate_validation_rustls_tls() -> Result<(), failure::Error> {
+    let cert = Certificate::from_pem(TESTNODE_NO_SAN_CERT)?;
+    let builder =
+        client::create_default_builder().cert_validation(CertificateValidation::Certificate(cert));
+
+    let client = client::create(builder);
+    let result = client.ping().send().await;
+
+    match result {
+        Ok(response) => Err(failure::err_msg(format!(
+            "Expected error but response was {}",
+            response.status_code()
+        ))),
+        Err(e) => {
+            let expected = "UnknownIssuer";
+            let actual = e.to_string();
+            assert!(
+                actual.contains(&expected),
+                "Expected error message to contain '{}' but was '{}'",
+                expected,
+                actual
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Certificate provided by the server has the fingerprint given to the client
+#[async_test]
+#[cfg(feature = "rustls-tls")]
+async fn fingerprint_certificate_validation() -> Result<(), failure::Error> {
+    let validation = CertificateValidation::fingerprint_from_hex(TESTNODE_FINGERPRINT)?;
+    let builder = client::create_default_builder().cert_validation(validation);
+    let client = client::create(builder);
+    let _response = client.ping().send().await?;
+    Ok(())
+}
+
+/// CA certificate in the chain provided by the server has the fingerprint given to the client
+#[async_test]
+#[cfg(feature = "rustls-tls")]
+async fn fingerprint_ca_certificate_validation() -> Result<(), failure::Error> {
+    let validation = CertificateValidation::fingerprint_from_hex(CA_FINGERPRINT)?;
+    let builder = client::create_default_builder().cert_validation(validation);
+    let client = client::create(builder);
+    let _response = client.ping().send().await?;
+    Ok(())
+}
+
+/// No certificate provided by the server has the fingerprint given to the client
+#[async_test]
+#[cfg(feature = "rustls-tls")]
+async fn fail_fingerprint_certificate_validation() -> Result<(), failure::Error> {
+    let builder = client::create_default_builder()
+        .cert_validation(CertificateValidation::Fingerprint([0; 32]));
+    let client = client::create(builder);
+    let result = client.ping().send().await;
+
+    match result {
+        Ok(response) => Err(failure::err_msg(format!(
+            "Expected error but response was {}",
+            response.status_code()
+        ))),
+        Err(_) => Ok(()),
+    }
+}
@@ -20,24 +20,46 @@ pub mod common;
 use common::*;
 
 use elasticsearch::{
+    auth::Credentials,
+    cat::CatIndicesParts,
     http::{
+        client::{HttpClient, HttpRequest, HttpResponse, ReqwestClient},
         headers::{
             HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE, DEFAULT_ACCEPT,
             DEFAULT_CONTENT_TYPE, X_OPAQUE_ID,
         },
+        middleware::{Middleware, Next},
+        mock::{MockHttpClient, MockRequest, MockResponse, RecordingHttpClient},
+        request::JsonBody,
+        response::Response,
+        transport::{
+            ConnectionPool, RetryPolicy, RoleSelector, SingleNodeConnectionPool,
+            SniffingConnectionPool, StaticNodeListConnectionPool, TransportBuilder,
+            VersionSelector,
+        },
         StatusCode,
     },
     params::TrackTotalHits,
-    SearchParts,
+    BulkParts, Elasticsearch, SearchParts,
 };
 
 use crate::common::client::index_documents;
+use async_trait::async_trait;
 use bytes::Bytes;
+use flate2::read::GzDecoder;
 use hyper::Method;
 use serde_json::{json, Value};
-use std::time::Duration;
+use std::{
+    io::Read,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use url::Url;
 
-#[tokio::test]
+#[async_test]
 async fn default_user_agent_content_type_accept_headers() -> Result<(), failure::Error> {
     let server = server::http(move |req| async move {
         assert_eq!(req.headers()["user-agent"], DEFAULT_USER_AGENT);
@@ -52,7 +74,7 @@ async fn default_user_agent_content_type_accept_headers() -> Result<(), failure:
     Ok(())
 }
 
-#[tokio::test]
+#[async_test]
 async fn default_header() -> Result<(), failure::Error> {
     let server = server::http(move |req| async move {
         assert_eq!(req.headers()["x-opaque-id"], "foo");
@@ -70,7 +92,7 @@ async fn default_header() -> Result<(), failure::Error> {
     Ok(())
 }
 
-#[tokio::test]
+#[async_test]
 async fn override_default_header() -> Result<(), failure::Error> {
     let server = server::http(move |req| async move {
         assert_eq!(req.headers()["x-opaque-id"], "bar");
@@ -95,7 +117,7 @@ async fn override_default_header() -> Result<(), failure::Error> {
     Ok(())
 }
 
-#[tokio::test]
+#[async_test]
 async fn x_opaque_id_header() -> Result<(), failure::Error> {
     let server = server::http(move |req| async move {
         assert_eq!(req.headers()["x-opaque-id"], "foo");
@@ -115,7 +137,218 @@ async fn x_opaque_id_header() -> Result<(), failure::Error> {
     Ok(())
 }
 
-#[tokio::test]
+#[async_test]
+async fn compatibility_mode_headers() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        let json = "application/vnd.elasticsearch+json;compatible-with=7";
+        let ndjson = "application/vnd.elasticsearch+x-ndjson;compatible-with=7";
+        match req.uri().path() {
+            "/_bulk" => {
+                assert_eq!(req.headers()["accept"], json);
+                assert_eq!(req.headers()["content-type"], ndjson);
+            }
+            "/_cat/health" => {
+                assert_eq!(req.headers()["accept"], "text/plain");
+                assert_eq!(req.headers()["content-type"], "text/plain");
+            }
+            _ => {
+                assert_eq!(req.headers()["accept"], json);
+                assert_eq!(req.headers()["content-type"], json);
+            }
+        }
+        http::Response::default()
+    });
+
+    let builder =
+        client::create_builder(format!("http://{}", server.addr()).as_ref()).compatibility_mode(7);
+    let client = client::create(builder);
+
+    let _response = client.ping().send().await?;
+    let _response = client.cat().health().send().await?;
+    let _response = client
+        .bulk(BulkParts::None)
+        .body(vec![
+            JsonBody::new(json!({ "index": { "_index": "posts", "_id": "1" } })),
+            JsonBody::new(json!({ "title": "Elasticsearch" })),
+        ])
+        .send()
+        .await?;
+
+    Ok(())
+}
+
+#[async_test]
+async fn product_check_fails_for_unknown_product() -> Result<(), failure::Error> {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let server = {
+        let requests = requests.clone();
+        server::http(move |_| {
+            requests.fetch_add(1, Ordering::SeqCst);
+            async move {
+                http::Response::builder()
+                    .header("x-elastic-product", "Other")
+                    .body("".into())
+                    .unwrap()
+            }
+        })
+    };
+
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+    let error = client.ping().send().await.unwrap_err();
+    assert!(error.is_unsupported_product());
+
+    // the failed check is cached, and no further requests are sent
+    let error = client.ping().send().await.unwrap_err();
+    assert!(error.is_unsupported_product());
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    // the API call is recorded as failed, not as a successful response
+    let metrics = client.transport().metrics();
+    let ping = metrics.endpoint("ping").unwrap();
+    assert_eq!(ping.requests(), 1);
+    assert_eq!(ping.errors(), 1);
+    assert_eq!(ping.status_class(2), 0);
+
+    Ok(())
+}
+
+#[async_test]
+async fn product_check_falls_back_to_info_api_with_credentials() -> Result<(), failure::Error> {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let server = {
+        let requests = requests.clone();
+        server::http(move |req| {
+            requests.lock().unwrap().push(format!(
+                "{} {}",
+                req.method(),
+                req.headers()["authorization"].to_str().unwrap()
+            ));
+            async move {
+                match *req.method() {
+                    Method::GET => {
+                        let info = json!({
+                            "version": { "number": "7.10.2", "build_flavor": "default" },
+                            "tagline": "You Know, for Search"
+                        });
+                        http::Response::new(info.to_string().into())
+                    }
+                    _ => http::Response::default(),
+                }
+            }
+        })
+    };
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .credentials_provider(Credentials::Bearer("access_token".into()));
+    let client = client::create(builder);
+    for _ in 0..2 {
+        let response = client.ping().send().await?;
+        assert_eq!(response.status_code(), StatusCode::OK);
+    }
+
+    // the info API is called once with the credentials, to verify the first response
+    assert_eq!(
+        *requests.lock().unwrap(),
+        vec![
+            "HEAD Bearer access_token",
+            "GET Bearer access_token",
+            "HEAD Bearer access_token"
+        ]
+    );
+
+    Ok(())
+}
+
+#[async_test]
+async fn product_check_falls_back_to_info_api() -> Result<(), failure::Error> {
+    let info = json!({
+        "version": { "number": "7.10.2", "build_flavor": "default" },
+        "tagline": "You Know, for Search"
+    });
+    let ping = MockRequest::new(elasticsearch::http::Method::Head, "/");
+    let info_request = MockRequest::new(elasticsearch::http::Method::Get, "/");
+    let http_client = MockHttpClient::new()
+        .response(ping.clone(), MockResponse::new(StatusCode::OK))
+        .response(
+            info_request.clone(),
+            MockResponse::new(StatusCode::OK).body(info.to_string()),
+        );
+    let recorder = RecordingHttpClient::new(http_client);
+    let transport = TransportBuilder::new(SingleNodeConnectionPool::default())
+        .http_client(recorder.clone())
+        .build()?;
+    let client = Elasticsearch::new(transport);
+
+    for _ in 0..2 {
+        let response = client.ping().send().await?;
+        assert_eq!(response.status_code(), StatusCode::OK);
+    }
+
+    // the info API is called once, to verify the first response
+    let requests: Vec<MockRequest> = recorder
+        .cassette()
+        .interactions()
+        .iter()
+        .map(|i| i.request().clone())
+        .collect();
+    assert_eq!(requests, vec![ping.clone(), info_request, ping]);
+
+    Ok(())
+}
+
+/// A [HttpClient] that waits before sending requests with another [HttpClient]
+#[derive(Debug, Clone)]
+struct DelayedClient<C>(C);
+
+#[async_trait]
+impl<C: HttpClient + Clone + 'static> HttpClient for DelayedClient<C> {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, elasticsearch::Error> {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        self.0.send(request).await
+    }
+}
+
+#[async_test]
+async fn product_check_calls_info_api_once_for_concurrent_responses() -> Result<(), failure::Error>
+{
+    let info = json!({
+        "version": { "number": "7.10.2", "build_flavor": "default" },
+        "tagline": "You Know, for Search"
+    });
+    let info_request = MockRequest::new(elasticsearch::http::Method::Get, "/");
+    let http_client = MockHttpClient::new()
+        .response(
+            MockRequest::new(elasticsearch::http::Method::Head, "/"),
+            MockResponse::new(StatusCode::OK),
+        )
+        .response(
+            info_request.clone(),
+            MockResponse::new(StatusCode::OK).body(info.to_string()),
+        );
+    let recorder = RecordingHttpClient::new(DelayedClient(http_client));
+    let transport = TransportBuilder::new(SingleNodeConnectionPool::default())
+        .http_client(recorder.clone())
+        .build()?;
+    let client = Elasticsearch::new(transport);
+
+    let responses =
+        futures::future::join_all((0..5).map(|_| async { client.ping().send().await })).await;
+    for response in responses {
+        assert_eq!(response?.status_code(), StatusCode::OK);
+    }
+
+    let info_requests = recorder
+        .cassette()
+        .interactions()
+        .iter()
+        .filter(|i| i.request() == &info_request)
+        .count();
+    assert_eq!(info_requests, 1);
+
+    Ok(())
+}
+
+#[async_test]
 async fn uses_global_request_timeout() {
     let server = server::http(move |_| async move {
         std::thread::sleep(Duration::from_secs(1));
@@ -134,7 +367,7 @@ async fn uses_global_request_timeout() {
     }
 }
 
-#[tokio::test]
+#[async_test]
 async fn uses_call_request_timeout() {
     let server = server::http(move |_| async move {
         std::thread::sleep(Duration::from_secs(1));
@@ -157,7 +390,7 @@ async fn uses_call_request_timeout() {
     }
 }
 
-#[tokio::test]
+#[async_test]
 async fn call_request_timeout_supersedes_global_timeout() {
     let server = server::http(move |_| async move {
         std::thread::sleep(Duration::from_secs(1));
@@ -180,7 +413,537 @@ async fn call_request_timeout_supersedes_global_timeout() {
     }
 }
 
-#[tokio::test]
+#[async_test]
+async fn sniffing_connection_pool_sends_to_discovered_nodes() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        let host = req.headers()["host"].to_str().unwrap().to_string();
+        if req.uri().path() == "/_nodes/http" {
+            assert!(host.starts_with("localhost:"));
+            let port = host.rsplit(':').next().unwrap();
+            let body = json!({
+                "nodes": {
+                    "node-1": {
+                        "name": "es-1",
+                        "version": "7.14.0",
+                        "roles": ["data", "master"],
+                        "http": { "publish_address": format!("127.0.0.1:{}", port) }
+                    }
+                }
+            });
+            http::Response::new(body.to_string().into())
+        } else {
+            assert!(host.starts_with("127.0.0.1:"));
+            http::Response::default()
+        }
+    });
+
+    let url = Url::parse(format!("http://localhost:{}", server.addr().port()).as_ref())?;
+    let conn_pool = SniffingConnectionPool::round_robin(vec![url])?;
+    let client = client::create(TransportBuilder::new(conn_pool));
+    let response = client.ping().send().await?;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    Ok(())
+}
+
+#[async_test]
+async fn sniffing_connection_pool_sniff_times_out() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        if req.uri().path() == "/_nodes/http" {
+            tokio::time::sleep(Duration::from_secs(2)).await;
+        }
+        http::Response::default()
+    });
+
+    let url = Url::parse(format!("http://{}", server.addr()).as_ref())?;
+    let conn_pool =
+        SniffingConnectionPool::round_robin(vec![url])?.sniff_timeout(Duration::from_millis(100));
+    let client = client::create(TransportBuilder::new(conn_pool));
+
+    let start = std::time::Instant::now();
+    let response = client.ping().send().await?;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert!(start.elapsed() < Duration::from_secs(1));
+
+    Ok(())
+}
+
+#[async_test]
+async fn node_selector_selects_discovered_nodes() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        if req.uri().path() == "/_nodes/http" {
+            let host = req.headers()["host"].to_str().unwrap().to_string();
+            let port = host.rsplit(':').next().unwrap();
+            let body = json!({
+                "nodes": {
+                    "node-1": {
+                        "name": "es-1",
+                        "version": "7.14.0",
+                        "roles": ["data", "ingest"],
+                        "http": { "publish_address": format!("127.0.0.1:{}", port) }
+                    }
+                }
+            });
+            http::Response::new(body.to_string().into())
+        } else {
+            http::Response::default()
+        }
+    });
+
+    let url = Url::parse(format!("http://localhost:{}", server.addr().port()).as_ref())?;
+    let conn_pool = SniffingConnectionPool::round_robin(vec![url])?;
+    let builder = TransportBuilder::new(conn_pool).node_selector(RoleSelector::new(vec!["ml"]));
+    let client = client::create(builder);
+
+    let error = client.ping().send().await.unwrap_err();
+    assert!(error.to_string().contains("node selector"));
+
+    let response = client
+        .ping()
+        .node_selector(VersionSelector::new(Some("7.0.0"), None)?)
+        .send()
+        .await?;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    Ok(())
+}
+
+#[async_test]
+async fn node_selector_requires_pool_that_selects_nodes() -> Result<(), failure::Error> {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let server = {
+        let requests = requests.clone();
+        server::http(move |_| {
+            requests.fetch_add(1, Ordering::SeqCst);
+            async move { http::Response::default() }
+        })
+    };
+
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+    let error = client
+        .ping()
+        .node_selector(RoleSelector::new(vec!["ml"]))
+        .send()
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("node selector"));
+    assert_eq!(requests.load(Ordering::SeqCst), 0);
+
+    Ok(())
+}
+
+#[async_test]
+async fn dead_node_is_not_selected() -> Result<(), failure::Error> {
+    let server = server::http(move |_| async move {
+        http::Response::builder()
+            .status(StatusCode::SERVICE_UNAVAILABLE.as_u16())
+            .body("".into())
+            .unwrap()
+    });
+    let live_server = server::http(move |_| async move { http::Response::default() });
+
+    let urls = vec![
+        Url::parse(format!("http://{}", server.addr()).as_ref())?,
+        Url::parse(format!("http://{}", live_server.addr()).as_ref())?,
+    ];
+    let conn_pool = StaticNodeListConnectionPool::round_robin(urls)?;
+    let client = client::create(TransportBuilder::new(conn_pool));
+
+    let response = client.ping().send().await?;
+    assert_eq!(response.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+    for _ in 0..3 {
+        let response = client.ping().send().await?;
+        assert_eq!(response.status_code(), StatusCode::OK);
+    }
+
+    Ok(())
+}
+
+#[async_test]
+async fn dead_node_is_pinged_before_resurrecting() -> Result<(), failure::Error> {
+    let methods = Arc::new(Mutex::new(Vec::new()));
+    let server_methods = methods.clone();
+    let server = server::http(move |req| {
+        let methods = server_methods.clone();
+        async move {
+            methods.lock().unwrap().push(req.method().clone());
+            server::elasticsearch().body("".into()).unwrap()
+        }
+    });
+
+    let url = Url::parse(format!("http://{}", server.addr()).as_ref())?;
+    let conn_pool = StaticNodeListConnectionPool::round_robin(vec![url])?
+        .dead_timeout(Duration::from_millis(0));
+    conn_pool.mark_dead(&conn_pool.next());
+
+    let transport = TransportBuilder::new(conn_pool.clone())
+        .resurrect_with_ping(true)
+        .build()?;
+    let client = Elasticsearch::new(transport);
+    let _ = client.info().send().await?;
+
+    assert_eq!(*methods.lock().unwrap(), vec![Method::HEAD, Method::GET]);
+    assert!(!conn_pool.is_dead(&conn_pool.next()));
+
+    Ok(())
+}
+
+#[async_test]
+async fn retries_on_retryable_status_code_with_same_body() -> Result<(), failure::Error> {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let server_requests = requests.clone();
+    let server = server::http(move |req| {
+        let requests = server_requests.clone();
+        async move {
+            let body = hyper::body::to_bytes(req.into_body()).await.unwrap();
+            assert_eq!(&body[..], br#"{"query":{"match_all":{}}}"#);
+            if requests.fetch_add(1, Ordering::SeqCst) == 0 {
+                http::Response::builder()
+                    .status(StatusCode::TOO_MANY_REQUESTS.as_u16())
+                    .body("".into())
+                    .unwrap()
+            } else {
+                http::Response::default()
+            }
+        }
+    });
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .retry_policy(RetryPolicy::default());
+    let client = client::create(builder);
+    let response = client
+        .search(SearchParts::None)
+        .body(json!({"query":{"match_all":{}}}))
+        .send()
+        .await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+#[async_test]
+async fn retries_connection_error_on_next_node() -> Result<(), failure::Error> {
+    let server = server::http(move |_| async move { http::Response::default() });
+
+    // bind to a port and drop the listener, so that connections to it are refused
+    let unused_addr = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+    let urls = vec![
+        Url::parse(format!("http://{}", unused_addr).as_ref())?,
+        Url::parse(format!("http://{}", server.addr()).as_ref())?,
+    ];
+    let conn_pool = StaticNodeListConnectionPool::round_robin(urls)?;
+    let builder = TransportBuilder::new(conn_pool).retry_policy(RetryPolicy::default());
+    let client = client::create(builder);
+
+    let response = client.ping().send().await?;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    Ok(())
+}
+
+#[async_test]
+async fn does_not_retry_without_retry_policy() -> Result<(), failure::Error> {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let server_requests = requests.clone();
+    let server = server::http(move |_| {
+        let requests = server_requests.clone();
+        async move {
+            requests.fetch_add(1, Ordering::SeqCst);
+            http::Response::builder()
+                .status(StatusCode::SERVICE_UNAVAILABLE.as_u16())
+                .body("".into())
+                .unwrap()
+        }
+    });
+
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+    let response = client.ping().send().await?;
+
+    assert_eq!(response.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+#[async_test]
+async fn compresses_request_body() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert_eq!(req.headers()["content-encoding"], "gzip");
+        let body = hyper::body::to_bytes(req.into_body()).await.unwrap();
+        let mut decoder = GzDecoder::new(&body[..]);
+        let mut decompressed = String::new();
+        decoder.read_to_string(&mut decompressed).unwrap();
+        assert_eq!(decompressed, r#"{"query":{"match_all":{}}}"#);
+        http::Response::default()
+    });
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .request_body_compression(true);
+    let client = client::create(builder);
+    let _response = client
+        .search(SearchParts::None)
+        .body(json!({"query":{"match_all":{}}}))
+        .send()
+        .await?;
+
+    Ok(())
+}
+
+#[async_test]
+async fn does_not_compress_request_body_below_threshold() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert!(req.headers().get("content-encoding").is_none());
+        let body = hyper::body::to_bytes(req.into_body()).await.unwrap();
+        assert_eq!(&body[..], br#"{"query":{"match_all":{}}}"#);
+        http::Response::default()
+    });
+
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .request_body_compression(true)
+        .request_body_compression_threshold(1024);
+    let client = client::create(builder);
+    let _response = client
+        .search(SearchParts::None)
+        .body(json!({"query":{"match_all":{}}}))
+        .send()
+        .await?;
+
+    Ok(())
+}
+
+/// A [HttpClient] that records requests and responds without sending them
+#[derive(Debug, Clone, Default)]
+struct InMemoryClient {
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+#[async_trait]
+impl HttpClient for InMemoryClient {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, elasticsearch::Error> {
+        let url = request.url().clone();
+        self.requests.lock().unwrap().push(request);
+        let body = Bytes::from_static(br#"{"hits":{"total":{"value":0},"hits":[]}}"#);
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static("x-elastic-product"),
+            HeaderValue::from_static("Elasticsearch"),
+        );
+        Ok(HttpResponse::new(
+            StatusCode::OK,
+            headers,
+            url,
+            Box::pin(futures::stream::once(async move { Ok(body) })),
+        ))
+    }
+}
+
+#[async_test]
+async fn custom_http_client_sends_requests() -> Result<(), failure::Error> {
+    let http_client = InMemoryClient::default();
+    let transport = TransportBuilder::new(SingleNodeConnectionPool::default())
+        .http_client(http_client.clone())
+        .build()?;
+    let client = Elasticsearch::new(transport);
+
+    let response = client
+        .search(SearchParts::Index(&["posts"]))
+        .q("title:elasticsearch")
+        .send()
+        .await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body = response.json::<Value>().await?;
+    assert_eq!(body["hits"]["total"]["value"], 0);
+
+    let requests = http_client.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method(), elasticsearch::http::Method::Get);
+    assert_eq!(
+        requests[0].url().as_str(),
+        "http://localhost:9200/posts/_search?q=title%3Aelasticsearch"
+    );
+    assert_eq!(requests[0].headers()[ACCEPT], DEFAULT_ACCEPT);
+
+    Ok(())
+}
+
+#[async_test]
+async fn recorded_cassette_replays_offline() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        assert_eq!(req.uri(), "/posts/_search?size=1");
+        http::Response::builder()
+            .header("content-type", "application/json")
+            .body(r#"{"hits":{"total":{"value":1},"hits":[]}}"#.into())
+            .unwrap()
+    });
+
+    let url = Url::parse(format!("http://{}", server.addr()).as_ref())?;
+    let recorder = RecordingHttpClient::new(ReqwestClient::default());
+    let transport = TransportBuilder::new(SingleNodeConnectionPool::new(url.clone()))
+        .http_client(recorder.clone())
+        .build()?;
+    let client = Elasticsearch::new(transport);
+    let search = || {
+        client
+            .search(SearchParts::Index(&["posts"]))
+            .size(1)
+            .body(json!({"query":{"match_all":{}}}))
+            .send()
+    };
+    let recorded = search().await?.json::<Value>().await?;
+
+    let path = std::env::temp_dir().join("elasticsearch-rs-recorded-cassette.json");
+    recorder.save(&path)?;
+    drop(server);
+
+    let transport = TransportBuilder::new(SingleNodeConnectionPool::new(url))
+        .http_client(MockHttpClient::load(&path)?)
+        .build()?;
+    let client = Elasticsearch::new(transport);
+    let response = client
+        .search(SearchParts::Index(&["posts"]))
+        .size(1)
+        .body(json!({"query":{"match_all":{}}}))
+        .send()
+        .await?;
+    std::fs::remove_file(&path)?;
+
+    assert_eq!(response.content_type(), "application/json");
+    assert_eq!(response.json::<Value>().await?, recorded);
+
+    Ok(())
+}
+
+/// Middleware that adds a tenant header to requests and records response status codes
+#[derive(Debug, Clone, Default)]
+struct TenantMiddleware {
+    status_codes: Arc<Mutex<Vec<StatusCode>>>,
+}
+
+#[async_trait]
+impl Middleware for TenantMiddleware {
+    async fn handle(
+        &self,
+        mut request: HttpRequest,
+        next: Next<'_>,
+    ) -> Result<Response, elasticsearch::Error> {
+        request
+            .headers_mut()
+            .insert("x-tenant", HeaderValue::from_static("tenant-1"));
+        let response = next.run(request).await?;
+        self.status_codes
+            .lock()
+            .unwrap()
+            .push(response.status_code());
+        Ok(response)
+    }
+}
+
+#[async_test]
+async fn middleware_runs_around_each_request() -> Result<(), failure::Error> {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let server_requests = requests.clone();
+    let server = server::http(move |req| {
+        let requests = server_requests.clone();
+        async move {
+            assert_eq!(req.headers()["x-tenant"], "tenant-1");
+            if requests.fetch_add(1, Ordering::SeqCst) == 0 {
+                http::Response::builder()
+                    .status(StatusCode::SERVICE_UNAVAILABLE.as_u16())
+                    .body("".into())
+                    .unwrap()
+            } else {
+                server::elasticsearch().body("".into()).unwrap()
+            }
+        }
+    });
+
+    let middleware = TenantMiddleware::default();
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .retry_policy(RetryPolicy::default())
+        .middleware(middleware.clone());
+    let client = client::create(builder);
+    let response = client.ping().send().await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        *middleware.status_codes.lock().unwrap(),
+        vec![StatusCode::SERVICE_UNAVAILABLE, StatusCode::OK]
+    );
+
+    Ok(())
+}
+
+#[async_test]
+async fn records_metrics_per_node_and_endpoint() -> Result<(), failure::Error> {
+    // a chunked body, without a content length
+    let server = server::http(move |_| async move {
+        let chunks: Vec<Result<&str, std::io::Error>> = vec![Ok("{"), Ok("}")];
+        server::elasticsearch()
+            .body(hyper::Body::wrap_stream(futures::stream::iter(chunks)))
+            .unwrap()
+    });
+
+    let url = format!("http://{}/", server.addr());
+    let client = client::create_for_url(&url);
+    let response = client
+        .search(SearchParts::None)
+        .body(json!({"query":{"match_all":{}}}))
+        .send()
+        .await?;
+    assert_eq!(response.content_length(), None);
+    response.text().await?;
+
+    let metrics = client.transport().metrics();
+    let search = metrics.endpoint("search").unwrap();
+    assert_eq!(search.requests(), 1);
+    assert_eq!(search.in_flight(), 0);
+    assert_eq!(search.status_class(2), 1);
+    assert_eq!(search.bytes_sent(), 26);
+    assert_eq!(search.bytes_received(), 2);
+    assert_eq!(search.latency().count(), 1);
+
+    let node = metrics.node(&Url::parse(&url)?).unwrap();
+    assert_eq!(node.requests().requests(), 1);
+    assert_eq!(node.dead_transitions(), 0);
+
+    let prometheus = metrics.to_prometheus();
+    assert!(prometheus.contains(r#"elasticsearch_endpoint_requests_total{endpoint="search"} 1"#));
+    assert!(prometheus.contains(&format!(
+        r#"elasticsearch_node_responses_total{{node="{}",class="2xx"}} 1"#,
+        url
+    )));
+
+    Ok(())
+}
+
+#[cfg(feature = "aws-auth")]
+#[async_test]
+async fn aws_sigv4_signs_requests() -> Result<(), failure::Error> {
+    use elasticsearch::aws::{AwsCredentials, AwsSigV4};
+
+    let server = server::http(move |req| async move {
+        let authorization = req.headers()["authorization"].to_str().unwrap();
+        assert!(authorization.starts_with("AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/"));
+        assert!(authorization.contains("/us-east-1/es/aws4_request"));
+        assert!(req.headers().contains_key("x-amz-date"));
+        assert_eq!(req.headers()["x-amz-security-token"], "token");
+        http::Response::default()
+    });
+
+    let credentials = AwsCredentials::new("AKIDEXAMPLE", "secret").session_token("token");
+    let builder = client::create_builder(format!("http://{}", server.addr()).as_ref())
+        .auth(AwsSigV4::new(credentials, "us-east-1").into());
+    let client = client::create(builder);
+    let response = client.ping().send().await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    Ok(())
+}
+
+#[async_test]
 async fn deprecation_warning_headers() -> Result<(), failure::Error> {
     let client = client::create_default();
     let _ = index_documents(&client).await?;
@@ -218,7 +981,105 @@ async fn deprecation_warning_headers() -> Result<(), failure::Error> {
     Ok(())
 }
 
-#[tokio::test]
+/// A server that responds with a cat indices body sent in many chunks
+fn chunked_body_server() -> (server::Server, String) {
+    let line = |i| format!("green open index-{} 1 1 0 0 208b 208b\n", i);
+    let body = (0..100).map(line).collect();
+    let server = server::http(move |_| async move {
+        let chunks = (0..100).map(move |i| Ok::<_, std::io::Error>(line(i)));
+        http::Response::new(hyper::Body::wrap_stream(futures::stream::iter(chunks)))
+    });
+    (server, body)
+}
+
+#[async_test]
+async fn read_response_as_bytes_stream() -> Result<(), failure::Error> {
+    use futures::stream::StreamExt;
+
+    let (server, expected) = chunked_body_server();
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+    let response = client.cat().indices(CatIndicesParts::None).send().await?;
+
+    let mut stream = response.bytes_stream();
+    let mut body = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        body.extend_from_slice(&chunk?);
+    }
+
+    assert_eq!(String::from_utf8(body)?, expected);
+    Ok(())
+}
+
+#[async_test]
+async fn read_response_with_async_read() -> Result<(), failure::Error> {
+    use futures::io::AsyncReadExt;
+
+    let (server, expected) = chunked_body_server();
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+    let response = client.cat().indices(CatIndicesParts::None).send().await?;
+
+    let mut body = String::new();
+    response.into_async_read().read_to_string(&mut body).await?;
+
+    assert_eq!(body, expected);
+    Ok(())
+}
+
+#[async_test]
+async fn read_response_as_hits_stream() -> Result<(), failure::Error> {
+    use futures::stream::StreamExt;
+
+    let server = server::http(move |req| async move {
+        let body = match req.uri().path() {
+            "/posts/_search" => json!({
+                "took": 1,
+                "hits": {
+                    "total": { "value": 2, "relation": "eq" },
+                    "hits": [
+                        { "_index": "posts", "_id": "1", "_source": { "title": "first" } },
+                        { "_index": "posts", "_id": "2", "_source": { "title": "second" } }
+                    ]
+                }
+            }),
+            _ => {
+                let body = json!({
+                    "error": {
+                        "type": "index_not_found_exception",
+                        "reason": "no such index [missing]"
+                    },
+                    "status": 404
+                });
+                return http::Response::builder()
+                    .status(404)
+                    .body(body.to_string().into())
+                    .unwrap();
+            }
+        };
+        http::Response::new(body.to_string().into())
+    });
+
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+    let response = client.search(SearchParts::Index(&["posts"])).send().await?;
+    let mut hits = response.hits_stream::<Value>().await?;
+    let mut ids = Vec::new();
+    while let Some(hit) = hits.next().await {
+        ids.push(hit?.id().map(String::from));
+    }
+    assert_eq!(ids, vec![Some("1".to_string()), Some("2".to_string())]);
+
+    let response = client
+        .search(SearchParts::Index(&["missing"]))
+        .send()
+        .await?;
+    match response.hits_stream::<Value>().await {
+        Ok(_) => panic!("expected an error for a 404 response"),
+        Err(e) => assert!(e.is_index_not_found(), "unexpected error {:?}", e),
+    }
+
+    Ok(())
+}
+
+#[async_test]
 async fn serialize_querystring() -> Result<(), failure::Error> {
     let server = server::http(move |req| async move {
         assert_eq!(req.method(), Method::GET);
@@ -243,7 +1104,7 @@ async fn serialize_querystring() -> Result<(), failure::Error> {
     Ok(())
 }
 
-#[tokio::test]
+#[async_test]
 async fn search_with_body() -> Result<(), failure::Error> {
     let client = client::create_default();
     let _ = index_documents(&client).await?;
@@ -287,7 +1148,7 @@ async fn search_with_body() -> Result<(), failure::Error> {
     Ok(())
 }
 
-#[tokio::test]
+#[async_test]
 async fn search_with_no_body() -> Result<(), failure::Error> {
     let client = client::create_default();
     let _ = index_documents(&client).await?;
@@ -310,7 +1171,43 @@ async fn search_with_no_body() -> Result<(), failure::Error> {
     Ok(())
 }
 
-#[tokio::test]
+#[async_test]
+async fn read_response_as_text_with_charset() -> Result<(), failure::Error> {
+    let server = server::http(move |req| async move {
+        let (body, content_type): (&[u8], _) = match req.uri().path() {
+            "/latin1" => (b"caf\xe9", "text/plain; charset=ISO-8859-1"),
+            "/invalid" => (b"caf\xe9", "text/plain"),
+            _ => ("café".as_bytes(), "text/plain"),
+        };
+        http::Response::builder()
+            .header("content-type", content_type)
+            .body(body.into())
+            .unwrap()
+    });
+
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+    for (path, expected) in &[
+        ("/latin1", "café"),
+        ("/utf8", "café"),
+        ("/invalid", "caf\u{FFFD}"),
+    ] {
+        let response = client
+            .send(
+                elasticsearch::http::Method::Get,
+                path,
+                HeaderMap::new(),
+                Option::<&Value>::None,
+                Option::<&[u8]>::None,
+                None,
+            )
+            .await?;
+        assert_eq!(response.text().await?, *expected);
+    }
+
+    Ok(())
+}
+
+#[async_test]
 async fn read_response_as_bytes() -> Result<(), failure::Error> {
     let client = client::create_default();
     let _ = index_documents(&client).await?;
@@ -336,7 +1233,7 @@ async fn read_response_as_bytes() -> Result<(), failure::Error> {
     Ok(())
 }
 
-#[tokio::test]
+#[async_test]
 async fn cat_health_format_json() -> Result<(), failure::Error> {
     let client = client::create_default();
     let response = client
@@ -360,7 +1257,7 @@ async fn cat_health_format_json() -> Result<(), failure::Error> {
     Ok(())
 }
 
-#[tokio::test]
+#[async_test]
 async fn cat_health_header_json() -> Result<(), failure::Error> {
     let client = client::create_default();
     let response = client
@@ -384,7 +1281,7 @@ async fn cat_health_header_json() -> Result<(), failure::Error> {
     Ok(())
 }
 
-#[tokio::test]
+#[async_test]
 async fn cat_health_text() -> Result<(), failure::Error> {
     let client = client::create_default();
     let response = client.cat().health().pretty(true).send().await?;
@@ -402,7 +1299,7 @@ async fn cat_health_text() -> Result<(), failure::Error> {
     Ok(())
 }
 
-#[tokio::test]
+#[async_test]
 async fn clone_search_with_body() -> Result<(), failure::Error> {
     let client = client::create_default();
     let _ = index_documents(&client).await?;
@@ -428,7 +1325,7 @@ async fn clone_search_with_body() -> Result<(), failure::Error> {
     Ok(())
 }
 
-#[tokio::test]
+#[async_test]
 async fn byte_slice_body() -> Result<(), failure::Error> {
     let client = client::create_default();
     let body = b"{\"query\":{\"match_all\":{}}}";
@@ -20,10 +20,7 @@
 use elasticsearch::cert::CertificateValidation;
 use elasticsearch::{
     auth::Credentials,
-    http::{
-        response::Response,
-        transport::{SingleNodeConnectionPool, TransportBuilder},
-    },
+    http::{response::Response, transport::TransportBuilder},
     indices::IndicesExistsParts,
     params::Refresh,
     BulkOperation, BulkParts, Elasticsearch, Error, DEFAULT_ADDRESS,
@@ -54,12 +51,14 @@ pub fn create_default_builder() -> TransportBuilder {
 
 pub fn create_builder(addr: &str) -> TransportBuilder {
     let url = Url::parse(addr).unwrap();
-    let conn_pool = SingleNodeConnectionPool::new(url.clone());
-    let mut builder = TransportBuilder::new(conn_pool);
+    let mut builder = TransportBuilder::from_url(addr).unwrap();
     // assume if we're running with HTTPS then authentication is also enabled and disable
-    // certificate validation - we'll change this for tests that need to.
+    // certificate validation - we'll change this for tests that need to. Credentials in
+    // the url take precedence over the default credentials.
     if url.scheme() == "https" {
-        builder = builder.auth(Credentials::Basic("elastic".into(), "changeme".into()));
+        if url.username().is_empty() {
+            builder = builder.auth(Credentials::Basic("elastic".into(), "changeme".into()));
+        }
 
         #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
         {
@@ -19,6 +19,13 @@
 pub mod client;
 pub mod server;
 
+/// Runs an async test on the runtime that drives the transport
+#[cfg(feature = "async-std")]
+pub use async_std::test as async_test;
+/// Runs an async test on the runtime that drives the transport
+#[cfg(not(feature = "async-std"))]
+pub use tokio::test as async_test;
+
 #[allow(unused)]
 pub static DEFAULT_USER_AGENT: &'static str =
     concat!("elasticsearch-rs/", env!("CARGO_PKG_VERSION"));
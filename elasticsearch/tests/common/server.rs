@@ -55,6 +55,12 @@ impl Drop for Server {
     }
 }
 
+/// A response builder that identifies the server as Elasticsearch to the product check of
+/// the client, for tests that do not expect the client to call the info API
+pub fn elasticsearch() -> http::response::Builder {
+    http::Response::builder().header("x-elastic-product", "Elasticsearch")
+}
+
 pub fn http<F, Fut>(func: F) -> Server
 where
     F: Fn(http::Request<hyper::Body>) -> Fut + Clone + Send + 'static,
@@ -82,9 +88,14 @@ where
 
         let addr = srv.local_addr();
         let (shutdown_tx, shutdown_rx) = oneshot::channel();
-        let srv = srv.with_graceful_shutdown(async move {
-            let _ = shutdown_rx.await;
-        });
+        // shut down without waiting for the client to close its connections, which it
+        // cannot do whilst the test is blocked on dropping the server
+        let srv = async move {
+            tokio::select! {
+                res = srv => res,
+                _ = shutdown_rx => Ok(()),
+            }
+        };
 
         let (panic_tx, panic_rx) = std_mpsc::channel();
         let tname = format!(
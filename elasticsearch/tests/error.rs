@@ -19,12 +19,12 @@
 pub mod common;
 use common::*;
 
-use elasticsearch::ExplainParts;
+use elasticsearch::{http::Method, ExplainParts, GetParts, IndexParts};
 use reqwest::StatusCode;
 use serde_json::{json, Value};
 
 /// Responses in the range 400-599 return Response body
-#[tokio::test]
+#[async_test]
 async fn bad_request_returns_response() -> Result<(), failure::Error> {
     let client = client::create_default();
     let response = client
@@ -49,7 +49,7 @@ async fn bad_request_returns_response() -> Result<(), failure::Error> {
     Ok(())
 }
 
-#[tokio::test]
+#[async_test]
 async fn deserialize_exception() -> Result<(), failure::Error> {
     let client = client::create_default();
     let response = client
@@ -76,3 +76,123 @@ async fn deserialize_exception() -> Result<(), failure::Error> {
     assert_eq!(error.reason(), error.root_cause().first().unwrap().reason());
     Ok(())
 }
+
+/// Creates a client for a server that responds to each request with the status code and body
+fn create_for_status(
+    status_code: u16,
+    body: &'static str,
+) -> (server::Server, elasticsearch::Elasticsearch) {
+    let server = server::http(move |_| async move {
+        server::elasticsearch()
+            .status(status_code)
+            .header("content-type", "application/json")
+            .body(body.into())
+            .unwrap()
+    });
+    let client = client::create_for_url(format!("http://{}", server.addr()).as_ref());
+    (server, client)
+}
+
+#[async_test]
+async fn error_for_status_with_body_is_ok_for_success() -> Result<(), failure::Error> {
+    let (_server, client) = create_for_status(200, "{}");
+    let response = client
+        .get(GetParts::IndexId("posts", "1"))
+        .send()
+        .await?
+        .error_for_status_with_body()
+        .await?;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    Ok(())
+}
+
+#[async_test]
+async fn error_for_status_with_body_holds_exception() -> Result<(), failure::Error> {
+    let (_server, client) = create_for_status(
+        404,
+        r#"{
+          "error": {
+            "root_cause": [{
+              "type": "index_not_found_exception",
+              "reason": "no such index [missing]"
+            }],
+            "type": "index_not_found_exception",
+            "reason": "no such index [missing]"
+          },
+          "status": 404
+        }"#,
+    );
+    let err = client
+        .get(GetParts::IndexId("missing", "1"))
+        .send()
+        .await?
+        .error_for_status_with_body()
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.status_code(), Some(StatusCode::NOT_FOUND));
+    assert_eq!(err.method(), Some(Method::Get));
+    assert_eq!(err.url().unwrap().path(), "/missing/_doc/1");
+    assert!(err.is_not_found());
+    assert!(err.is_index_not_found());
+    assert!(!err.is_conflict());
+
+    let ex = err.exception().unwrap();
+    assert_eq!(ex.status(), Some(404));
+    assert_eq!(ex.error().reason(), Some("no such index [missing]"));
+    assert!(err
+        .to_string()
+        .ends_with("index_not_found_exception: no such index [missing]"));
+    Ok(())
+}
+
+#[async_test]
+async fn error_for_status_with_body_version_conflict() -> Result<(), failure::Error> {
+    let (_server, client) = create_for_status(
+        409,
+        r#"{
+          "error": {
+            "root_cause": [{
+              "type": "version_conflict_engine_exception",
+              "reason": "[1]: version conflict, document already exists (current version [1])"
+            }],
+            "type": "version_conflict_engine_exception",
+            "reason": "[1]: version conflict, document already exists (current version [1])"
+          },
+          "status": 409
+        }"#,
+    );
+    let err = client
+        .index(IndexParts::IndexId("posts", "1"))
+        .body(json!({}))
+        .send()
+        .await?
+        .error_for_status_with_body()
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.method(), Some(Method::Post));
+    assert!(err.is_conflict());
+    assert!(err.is_version_conflict());
+    assert!(!err.is_not_found());
+    assert!(!err.is_index_not_found());
+    Ok(())
+}
+
+#[async_test]
+async fn error_for_status_with_body_without_exception() -> Result<(), failure::Error> {
+    let (_server, client) = create_for_status(429, "Too Many Requests");
+    let err = client
+        .get(GetParts::IndexId("posts", "1"))
+        .send()
+        .await?
+        .error_for_status_with_body()
+        .await
+        .unwrap_err();
+
+    assert!(err.is_too_many_requests());
+    assert!(err.exception().is_none());
+    assert!(!err.is_version_conflict());
+    Ok(())
+}
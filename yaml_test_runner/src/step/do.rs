@@ -145,6 +145,7 @@ impl Do {
         let mut warnings: Vec<String> = Vec::new();
         let mut allowed_warnings: Vec<String> = Vec::new();
         let mut catch = None;
+        let mut node_selector = None;
 
         fn to_string_vec(v: &Yaml) -> Vec<String> {
             v.as_vec()
@@ -179,7 +180,10 @@ impl Do {
                         catch = v.as_str().map(|s| Catch(s.to_string()));
                         Ok(())
                     }
-                    "node_selector" => Ok(()),
+                    "node_selector" => {
+                        node_selector = Some(v);
+                        Ok(())
+                    }
                     "warnings" => {
                         warnings = to_string_vec(v);
                         Ok(())
@@ -202,7 +206,7 @@ impl Do {
         let endpoint = api
             .endpoint_for_api_call(call)
             .ok_or_else(|| failure::err_msg(format!(r#"no API found for "{}""#, call)))?;
-        let api_call = ApiCall::try_from(api, endpoint, value, headers)?;
+        let api_call = ApiCall::try_from(api, endpoint, value, headers, node_selector)?;
 
         Ok(Do {
             api_call,
@@ -224,6 +228,7 @@ pub struct ApiCall {
     parts: Option<Tokens>,
     params: Option<Tokens>,
     headers: BTreeMap<String, String>,
+    node_selector: Option<Tokens>,
     body: Option<Tokens>,
     ignore: Option<u16>,
 }
@@ -233,6 +238,7 @@ impl ToTokens for ApiCall {
         let function = &self.function;
         let parts = &self.parts;
         let params = &self.params;
+        let node_selector = &self.node_selector;
         let body = &self.body;
 
         let headers: Vec<Tokens> = self
@@ -262,6 +268,7 @@ impl ToTokens for ApiCall {
         tokens.append(quote! {
             let response = client.#function(#parts)
                 #(#headers)*
+                #node_selector
                 #params
                 #body
                 .send()
@@ -277,6 +284,7 @@ impl ApiCall {
         endpoint: &ApiEndpoint,
         yaml: &Yaml,
         headers: BTreeMap<String, String>,
+        node_selector: Option<&Yaml>,
     ) -> Result<ApiCall, failure::Error> {
         let hash = yaml
             .as_hash()
@@ -309,6 +317,10 @@ impl ApiCall {
         let api_call = endpoint.full_name.as_ref().unwrap();
         let parts = Self::generate_parts(api_call, endpoint, &parts)?;
         let params = Self::generate_params(api, endpoint, &params)?;
+        let node_selector = match node_selector {
+            Some(v) => Some(Self::generate_node_selector(v)?),
+            None => None,
+        };
         let function = syn::Ident::from(api_call.replace(".", "()."));
         let namespace: Option<String> = if api_call.contains('.') {
             let namespaces: Vec<&str> = api_call.splitn(2, '.').collect();
@@ -323,11 +335,50 @@ impl ApiCall {
             parts,
             params,
             headers,
+            node_selector,
             body,
             ignore,
         })
     }
 
+    /// Generates the node selector for the API call. Selects nodes by version range
+    /// or by a single attribute.
+    fn generate_node_selector(v: &Yaml) -> Result<Tokens, failure::Error> {
+        let hash = v
+            .as_hash()
+            .ok_or_else(|| failure::err_msg(format!("expected hash but found {:?}", v)))?;
+
+        if hash.len() != 1 {
+            return Err(failure::err_msg(format!(
+                "unsupported node_selector {:?}",
+                v
+            )));
+        }
+
+        let (key, value) = hash.iter().next().unwrap();
+        match (key.as_str(), value) {
+            (Some("version"), Yaml::String(range)) => Ok(quote! {
+                .node_selector(#range.parse::<elasticsearch::http::transport::VersionSelector>()?)
+            }),
+            (Some("attribute"), Yaml::Hash(attributes)) if attributes.len() == 1 => {
+                let (name, value) = attributes.iter().next().unwrap();
+                match (name.as_str(), value.as_str()) {
+                    (Some(name), Some(value)) => Ok(quote! {
+                        .node_selector(elasticsearch::http::transport::AttributeSelector::new(#name, #value))
+                    }),
+                    _ => Err(failure::err_msg(format!(
+                        "unsupported node_selector attribute {:?}",
+                        attributes
+                    ))),
+                }
+            }
+            _ => Err(failure::err_msg(format!(
+                "unsupported node_selector {:?}",
+                hash
+            ))),
+        }
+    }
+
     fn generate_enum(
         enum_name: &str,
         variant: &str,